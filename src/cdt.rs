@@ -1,7 +1,13 @@
+use alloc::collections::BTreeSet;
 use alloc::vec::Vec;
 use core::fmt::Formatter;
 
-use num_traits::{Float, NumCast};
+#[cfg(not(feature = "std"))]
+use hashbrown::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use num_traits::{zero, Float, NumCast};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -14,14 +20,16 @@ use crate::{
 };
 use crate::{handles::*, intersection_iterator::Intersection};
 use crate::{
-    mitigate_underflow, DelaunayTriangulation, HasPosition, HintGenerator, InsertionError,
-    LastUsedVertexHintGenerator, Point2, Triangulation, TriangulationExt,
+    mitigate_underflow, validate_vertex, DelaunayTriangulation, HandleRemapping, HasPosition,
+    HasPositionMut, HintGenerator, InsertionError, LastUsedVertexHintGenerator, MoveVertexError,
+    Point2, Triangulation, TriangulationExt,
 };
 
 /// Undirected edge type of a [ConstrainedDelaunayTriangulation] (CDT).
 ///
-/// CDTs need to store if an undirected edge is a constrained edge. To do so, CDTs don't use
-/// the configured undirected edge type directly but wrap it into `CdtEdge<UE>` first.
+/// CDTs need to store if an undirected edge is a constrained edge and, optionally, the ID of the
+/// constraint it belongs to. To do so, CDTs don't use the configured undirected edge type directly
+/// but wrap it into `CdtEdge<UE>` first.
 ///
 /// This type will only be relevant if the triangulation's undirected edge type is being
 /// overwritten.
@@ -29,12 +37,170 @@ use crate::{
 /// # Type parameters
 /// UE: The user configurable undirected edge type.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Deserialize), serde(crate = "serde"))]
+pub struct CdtEdge<UE>(
+    bool,
+    UE,
+    // Missing in triangulations serialized before constraint IDs were introduced and omitted by
+    // self-describing formats if the edge has no constraint ID
+    #[cfg_attr(feature = "serde", serde(default))] Option<ConstraintOrigin>,
+);
+
+/// Identifies the input constraint that a constraint edge is part of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(
     feature = "serde",
     derive(Serialize, Deserialize),
     serde(crate = "serde")
 )]
-pub struct CdtEdge<UE>(bool, UE);
+struct ConstraintOrigin {
+    id: u32,
+    /// `true` if the input constraint points in the opposite direction of the edge's normalized
+    /// directed edge.
+    reversed: bool,
+}
+
+/// An error returned by the non-panicking constraint methods of a
+/// [ConstrainedDelaunayTriangulation].
+///
+/// *See [ConstrainedDelaunayTriangulation::add_constraint_checked],
+/// [ConstrainedDelaunayTriangulation::bulk_load_cdt_checked],
+/// [ConstrainedDelaunayTriangulation::move_vertex_checked] and
+/// [ConstrainedDelaunayTriangulation::remove_and_heal]*
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum ConstraintError {
+    /// The new constraint edge intersects the given, already existing constraint edge.
+    IntersectingConstraint(FixedUndirectedEdgeHandle),
+
+    /// Two input constraint edges of a bulk load intersect. Contains the indices of both edges.
+    IntersectingInputEdges([usize; 2]),
+
+    /// A vertex index or vertex handle is out of range or refers to a removed vertex.
+    InvalidIndex(usize),
+
+    /// The constraint edge connects two vertices at the same position.
+    ZeroLengthEdge,
+
+    /// The vertex is not adjacent to exactly two constraint edges. Contains the number of
+    /// adjacent constraint edges.
+    InvalidConstraintDegree(usize),
+
+    /// Another vertex is already located at the target position of a moved vertex. Contains the
+    /// handle of that vertex.
+    OccupiedPosition(FixedVertexHandle),
+
+    /// A vertex of the constraint edge could not be inserted.
+    InsertionError(InsertionError),
+}
+
+impl core::fmt::Display for ConstraintError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        <Self as core::fmt::Debug>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConstraintError {}
+
+impl From<InsertionError> for ConstraintError {
+    fn from(error: InsertionError) -> Self {
+        ConstraintError::InsertionError(error)
+    }
+}
+
+/// A grid to which [ConstrainedDelaunayTriangulation::add_constraint_and_snap] rounds the
+/// vertices it creates at constraint intersections.
+///
+/// The grid points are all integer multiples of the cell size. Every grid point is the center
+/// of a square cell (its _hot pixel_).
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SnapGrid {
+    /// The inverse of the cell size.
+    scale: f64,
+}
+
+impl SnapGrid {
+    /// Creates a grid with the given cell size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cell_size` is not positive or not finite.
+    pub fn new(cell_size: f64) -> Self {
+        assert!(
+            cell_size > 0.0 && cell_size.is_finite(),
+            "The cell size must be positive and finite"
+        );
+        Self {
+            scale: 1.0 / cell_size,
+        }
+    }
+
+    /// Creates a grid of all integer coordinates.
+    pub fn integer() -> Self {
+        Self { scale: 1.0 }
+    }
+
+    /// Creates a grid of all coordinates with the given number of decimal places.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decimals` is too large for the resulting cell size to be representable.
+    pub fn decimals(decimals: i32) -> Self {
+        let scale = 10.0.powi(decimals);
+        assert!(
+            scale > 0.0 && scale.is_finite(),
+            "The number of decimal places is too large"
+        );
+        Self { scale }
+    }
+
+    /// Returns the side length of a grid cell.
+    pub fn cell_size(&self) -> f64 {
+        1.0 / self.scale
+    }
+
+    /// Rounds a position to the closest grid point.
+    pub fn snap(&self, position: Point2<f64>) -> Point2<f64> {
+        let [x, y] = [position.x, position.y].map(|s| (s * self.scale).round() / self.scale);
+        Point2::new(x, y)
+    }
+
+    /// Returns `true` if `position` lies within the closed hot pixel around `center`.
+    fn pixel_contains(&self, center: Point2<f64>, position: Point2<f64>) -> bool {
+        let half_size = 0.5 * self.cell_size();
+        (position.x - center.x).abs() <= half_size && (position.y - center.y).abs() <= half_size
+    }
+
+    /// Returns `true` if the segment from `from` to `to` intersects the closed hot pixel around
+    /// `center`.
+    fn segment_intersects_pixel(
+        &self,
+        from: Point2<f64>,
+        to: Point2<f64>,
+        center: Point2<f64>,
+    ) -> bool {
+        // Clips the segment's parameter range [0, 1] against both slabs of the pixel.
+        let half_size = 0.5 * self.cell_size();
+        let mut range = (0.0, 1.0);
+        for (start, delta, center) in [
+            (from.x, to.x - from.x, center.x),
+            (from.y, to.y - from.y, center.y),
+        ] {
+            let [min, max] = [center - half_size - start, center + half_size - start];
+            if delta == 0.0 {
+                if min > 0.0 || max < 0.0 {
+                    return false;
+                }
+                continue;
+            }
+            let [t0, t1] = [min / delta, max / delta];
+            let [t0, t1] = if t0 <= t1 { [t0, t1] } else { [t1, t0] };
+            range = (range.0.max(t0), range.1.min(t1));
+        }
+        range.0 <= range.1
+    }
+}
 
 impl<UE> CdtEdge<UE> {
     /// Returns `true` if this edge is a constraint edge.
@@ -50,6 +216,17 @@ impl<UE> CdtEdge<UE> {
     fn unmake_constraint_edge(&mut self) {
         assert!(self.is_constraint_edge());
         self.0 = false;
+        self.2 = None;
+    }
+
+    /// Returns the ID of the constraint this edge belongs to.
+    ///
+    /// Returns `None` if this is not a constraint edge or if its constraint was added without an
+    /// ID.
+    ///
+    /// *See [ConstrainedDelaunayTriangulation::add_constraint_with_id]*
+    pub fn constraint_id(&self) -> Option<u32> {
+        self.2.map(|origin| origin.id)
     }
 
     /// Returns the wrapped undirected edge data type.
@@ -65,7 +242,28 @@ impl<UE> CdtEdge<UE> {
 
 impl<UE: Default> Default for CdtEdge<UE> {
     fn default() -> Self {
-        CdtEdge(false, UE::default())
+        CdtEdge(false, UE::default(), None)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<UE: Serialize> Serialize for CdtEdge<UE> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeTupleStruct;
+
+        // Self-describing formats omit the constraint origin of edges without constraint ID.
+        // This keeps their output unchanged for triangulations without constraint IDs.
+        // Other formats rely on a fixed number of fields and always contain the origin.
+        let skip_origin = serializer.is_human_readable() && self.2.is_none();
+
+        let mut state =
+            serializer.serialize_tuple_struct("CdtEdge", if skip_origin { 2 } else { 3 })?;
+        state.serialize_field(&self.0)?;
+        state.serialize_field(&self.1)?;
+        if !skip_origin {
+            state.serialize_field(&self.2)?;
+        }
+        state.end()
     }
 }
 
@@ -139,11 +337,7 @@ impl<UE> AsMut<UE> for CdtEdge<UE> {
 /// information about using Delaunay triangulations.
 #[doc(alias = "CDT")]
 #[derive(Clone)]
-#[cfg_attr(
-    feature = "serde",
-    derive(Serialize, Deserialize),
-    serde(crate = "serde")
-)]
+#[cfg_attr(feature = "serde", derive(Deserialize), serde(crate = "serde"))]
 pub struct ConstrainedDelaunayTriangulation<
     V,
     DE = (),
@@ -160,6 +354,40 @@ pub struct ConstrainedDelaunayTriangulation<
     dcel: Dcel<V, DE, CdtEdge<UE>, F>,
     num_constraints: usize,
     hint_generator: L,
+    /// Vertices that have only been created to split constraint edges.
+    #[cfg_attr(feature = "serde", serde(default))]
+    steiner_vertices: BTreeSet<FixedVertexHandle>,
+}
+
+#[cfg(feature = "serde")]
+impl<V, DE, UE, F, L> Serialize for ConstrainedDelaunayTriangulation<V, DE, UE, F, L>
+where
+    V: HasPosition + Serialize,
+    DE: Default + Serialize,
+    UE: Default + Serialize,
+    F: Default + Serialize,
+    L: HintGenerator<<V as HasPosition>::Scalar> + Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        // Self-describing formats omit the set of steiner vertices if it is empty. This keeps
+        // their output unchanged for triangulations without steiner vertices.
+        // Other formats rely on a fixed number of fields and always contain all fields.
+        let skip_steiner_vertices =
+            serializer.is_human_readable() && self.steiner_vertices.is_empty();
+
+        let mut state = serializer.serialize_struct("ConstrainedDelaunayTriangulation", 4)?;
+        state.serialize_field("dcel", &self.dcel)?;
+        state.serialize_field("num_constraints", &self.num_constraints)?;
+        state.serialize_field("hint_generator", &self.hint_generator)?;
+        if skip_steiner_vertices {
+            state.skip_field("steiner_vertices")?;
+        } else {
+            state.serialize_field("steiner_vertices", &self.steiner_vertices)?;
+        }
+        state.end()
+    }
 }
 
 impl<V, DE, UE, F, L> Default for ConstrainedDelaunayTriangulation<V, DE, UE, F, L>
@@ -175,6 +403,7 @@ where
             dcel: Default::default(),
             num_constraints: 0,
             hint_generator: Default::default(),
+            steiner_vertices: BTreeSet::new(),
         }
     }
 }
@@ -205,8 +434,55 @@ where
         self.is_constraint_edge(edge)
     }
 
+    /// Moves a vertex to a new position.
+    ///
+    /// Constraint edges adjacent to the moved vertex are kept. If the vertex cannot be moved
+    /// locally, its adjacent constraint edges are re-inserted after the vertex has been moved.
+    ///
+    /// *See [Triangulation::move_vertex]*
+    ///
+    /// # Panics
+    ///
+    /// Panics if any re-inserted constraint edge would intersect another constraint edge. The
+    /// triangulation is not modified before panicking. Use
+    /// [ConstrainedDelaunayTriangulation::move_vertex_checked] to return an error instead.
+    fn move_vertex(
+        &mut self,
+        vertex: FixedVertexHandle,
+        new_position: Point2<<Self::Vertex as HasPosition>::Scalar>,
+    ) -> Result<FixedVertexHandle, MoveVertexError>
+    where
+        Self::Vertex: HasPositionMut,
+    {
+        validate_vertex(&new_position)?;
+        if self.vertex(vertex).position() == new_position
+            || self.try_move_vertex_locally(vertex, new_position)
+        {
+            return Ok(vertex);
+        }
+
+        self.check_position_is_free(vertex, new_position)?;
+        assert!(
+            self.find_moved_constraint_intersection(vertex, new_position)
+                .is_none(),
+            "A moved constraint edge intersects an existing constraint edge."
+        );
+        self.move_vertex_with_constraints(vertex, new_position)?;
+        Ok(vertex)
+    }
+
     fn handle_legal_edge_split(&mut self, handles: [FixedDirectedEdgeHandle; 2]) {
         self.num_constraints += 1;
+        // One of the parts re-uses the original edge and keeps its constraint origin.
+        let original = handles
+            .iter()
+            .copied()
+            .find(|edge| self.is_constraint_edge(edge.as_undirected()));
+        if let Some(original) = original {
+            let origin = self.dcel.undirected_edge_data(original.as_undirected()).2;
+            self.inherit_constraint_origin(origin, original, handles);
+        }
+
         for handle in handles.iter().map(|e| e.as_undirected()) {
             if !self.is_constraint_edge(handle) {
                 self.dcel
@@ -216,6 +492,26 @@ where
         }
     }
 
+    fn handle_vertex_removal(
+        &mut self,
+        removed: FixedVertexHandle,
+        swapped_in: Option<FixedVertexHandle>,
+    ) {
+        self.steiner_vertices.remove(&removed);
+        if let Some(swapped_in) = swapped_in {
+            if self.steiner_vertices.remove(&swapped_in) {
+                self.steiner_vertices.insert(removed);
+            }
+        }
+    }
+
+    fn handle_vertex_remapping(&mut self, remapping: &HandleRemapping) {
+        self.steiner_vertices = core::mem::take(&mut self.steiner_vertices)
+            .into_iter()
+            .filter_map(|vertex| remapping.vertex(vertex))
+            .collect();
+    }
+
     fn hint_generator(&self) -> &Self::HintGenerator {
         &self.hint_generator
     }
@@ -233,6 +529,7 @@ where
             dcel,
             num_constraints,
             hint_generator,
+            steiner_vertices: BTreeSet::new(),
         }
     }
 
@@ -248,6 +545,7 @@ where
 
     fn clear(&mut self) {
         self.num_constraints = 0;
+        self.steiner_vertices.clear();
         self.s_mut().clear();
         let new_hint_generator = HintGenerator::initialize_from_triangulation(self);
         *self.hint_generator_mut() = new_hint_generator;
@@ -265,13 +563,14 @@ where
 {
     fn from(value: DelaunayTriangulation<V, DE, UE, F, L>) -> Self {
         let dcel = value.dcel;
-        let s = dcel.map_undirected_edges(|edge| CdtEdge(false, edge));
+        let s = dcel.map_undirected_edges(|edge| CdtEdge(false, edge, None));
         let lookup = value.hint_generator;
 
         ConstrainedDelaunayTriangulation {
             dcel: s,
             num_constraints: 0,
             hint_generator: lookup,
+            steiner_vertices: BTreeSet::new(),
         }
     }
 }
@@ -321,6 +620,8 @@ where
     /// # Panics
     ///
     /// Panics if any constraint edges overlap. Panics if the edges contain an invalid index (out of range).
+    /// Use [ConstrainedDelaunayTriangulation::bulk_load_cdt_with_intersections] for intersecting edges or
+    /// [ConstrainedDelaunayTriangulation::bulk_load_cdt_checked] to return an error instead.
     pub fn bulk_load_cdt(vertices: Vec<V>, edges: Vec<[usize; 2]>) -> Result<Self, InsertionError> {
         let mut result = bulk_load_cdt(vertices, edges)?;
         *result.hint_generator_mut() = L::initialize_from_triangulation(&result);
@@ -393,6 +694,110 @@ where
         Ok(result)
     }
 
+    /// Non-panicking variant of [ConstrainedDelaunayTriangulation::bulk_load_cdt].
+    ///
+    /// Returns an error instead of panicking if any edge contains an invalid index, if any two
+    /// constraint edges intersect or if an edge connects two vertices at the same position.
+    /// Constraint edges that overlap (but don't intersect) are allowed.
+    ///
+    /// The edges are validated while they are inserted one by one. This makes this method
+    /// slower than `bulk_load_cdt`.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{ConstrainedDelaunayTriangulation, ConstraintError, Point2};
+    /// let vertices = vec![
+    ///     Point2::new(-1.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(0.0, -1.0),
+    ///     Point2::new(0.0, 1.0),
+    /// ];
+    ///
+    /// let result = ConstrainedDelaunayTriangulation::<_>::bulk_load_cdt_checked(
+    ///     vertices.clone(),
+    ///     vec![[0, 1], [2, 3]],
+    /// );
+    /// assert_eq!(result.err(), Some(ConstraintError::IntersectingInputEdges([0, 1])));
+    ///
+    /// let result =
+    ///     ConstrainedDelaunayTriangulation::<_>::bulk_load_cdt_checked(vertices, vec![[0, 4]]);
+    /// assert_eq!(result.err(), Some(ConstraintError::InvalidIndex(4)));
+    /// ```
+    pub fn bulk_load_cdt_checked(
+        vertices: Vec<V>,
+        edges: Vec<[usize; 2]>,
+    ) -> Result<Self, ConstraintError> {
+        let positions = Self::validate_bulk_load_edges(&vertices, &edges)?;
+        let result = Self::bulk_load(vertices)?;
+        result.add_bulk_load_edges_checked(positions, edges)
+    }
+
+    /// Non-panicking variant of [ConstrainedDelaunayTriangulation::bulk_load_cdt_stable].
+    ///
+    /// *See [ConstrainedDelaunayTriangulation::bulk_load_cdt_checked]*
+    pub fn bulk_load_cdt_stable_checked(
+        vertices: Vec<V>,
+        edges: Vec<[usize; 2]>,
+    ) -> Result<Self, ConstraintError> {
+        let positions = Self::validate_bulk_load_edges(&vertices, &edges)?;
+        let result: Self = bulk_load_stable(
+            ConstrainedDelaunayTriangulation::<_, DE, UE, F, L>::bulk_load,
+            vertices,
+        )?;
+        result.add_bulk_load_edges_checked(positions, edges)
+    }
+
+    fn validate_bulk_load_edges(
+        vertices: &[V],
+        edges: &[[usize; 2]],
+    ) -> Result<Vec<Point2<V::Scalar>>, ConstraintError> {
+        for &[from, to] in edges {
+            for index in [from, to] {
+                if index >= vertices.len() {
+                    return Err(ConstraintError::InvalidIndex(index));
+                }
+            }
+            if vertices[from].position() == vertices[to].position() {
+                return Err(ConstraintError::ZeroLengthEdge);
+            }
+        }
+        Ok(vertices.iter().map(|vertex| vertex.position()).collect())
+    }
+
+    fn add_bulk_load_edges_checked(
+        mut self,
+        positions: Vec<Point2<V::Scalar>>,
+        edges: Vec<[usize; 2]>,
+    ) -> Result<Self, ConstraintError> {
+        // Duplicates are merged by bulk loading. Map every input vertex to its remaining vertex.
+        let handles = positions
+            .into_iter()
+            .map(|position| {
+                self.locate_vertex(position)
+                    .map(|vertex| vertex.fix())
+                    .expect("Bulk loaded vertex could not be found")
+            })
+            .collect::<Vec<_>>();
+
+        // Constraint edges are never split by `try_add_constraint`, so an intersected edge can be
+        // mapped back to its input edge.
+        let mut input_edges = HashMap::<FixedUndirectedEdgeHandle, usize>::new();
+        for (index, [from, to]) in edges.into_iter().enumerate() {
+            let [from, to] = [handles[from], handles[to]];
+            if let Some(existing) = self.find_intersecting_constraint(from, to) {
+                return Err(ConstraintError::IntersectingInputEdges([
+                    input_edges[&existing],
+                    index,
+                ]));
+            }
+
+            for edge in self.try_add_constraint(from, to) {
+                input_edges.entry(edge.as_undirected()).or_insert(index);
+            }
+        }
+        Ok(self)
+    }
+
     /// Removes a vertex from the triangulation.
     ///
     /// This operation runs in O(n²), where n is the degree of the
@@ -412,6 +817,109 @@ where
         self.remove_and_notify(vertex)
     }
 
+    /// Marks a vertex that has only been inserted to split a constraint edge.
+    ///
+    /// *See [Self::remove_constraint_chain]*
+    pub(crate) fn mark_steiner_vertex(&mut self, vertex: FixedVertexHandle) {
+        self.steiner_vertices.insert(vertex);
+    }
+
+    /// Removes a vertex that lies within a chain of constraint edges and reconnects its two
+    /// neighbors with a new constraint edge.
+    ///
+    /// In contrast to [Self::remove], the constraint chain stays closed. This is useful to
+    /// simplify polylines within an existing triangulation. The vertex doesn't need to lie
+    /// exactly on the line between its neighbors - it is up to the caller to decide how much the
+    /// new constraint may deviate from the original chain. If both removed constraint edges
+    /// belong to the same [constraint ID](Self::add_constraint_with_id), the new constraint edge
+    /// will inherit it.
+    ///
+    /// Like [Self::add_constraint], the new constraint is split if it runs through another
+    /// vertex.
+    ///
+    /// Leaves the triangulation unchanged and returns an error if
+    ///  - `vertex` is not a valid vertex handle,
+    ///  - `vertex` is not adjacent to exactly two constraint edges or
+    ///  - the new constraint edge would intersect another constraint edge. The error contains the
+    ///    first intersected constraint edge.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::ConstraintError> {
+    /// use spade::{ConstrainedDelaunayTriangulation, ConstraintError, Point2, Triangulation};
+    ///
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::new();
+    /// let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+    /// let v2 = cdt.insert(Point2::new(2.0, 0.0))?;
+    /// // Inserted last, other vertex handles remain valid after its removal
+    /// let v1 = cdt.insert(Point2::new(1.0, 0.1))?;
+    /// cdt.add_constraint(v0, v1);
+    /// cdt.add_constraint(v1, v2);
+    ///
+    /// assert_eq!(cdt.remove_and_heal(v0), Err(ConstraintError::InvalidConstraintDegree(1)));
+    ///
+    /// let removed = cdt.remove_and_heal(v1)?;
+    /// assert_eq!(removed, Point2::new(1.0, 0.1));
+    /// assert_eq!(cdt.num_constraints(), 1);
+    /// assert!(cdt.exists_constraint(v0, v2));
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Handle invalidation
+    /// This method will invalidate all vertex, edge and face handles, see [Self::remove].
+    pub fn remove_and_heal(&mut self, vertex: FixedVertexHandle) -> Result<V, ConstraintError> {
+        if !self.s().is_vertex_live(vertex) {
+            return Err(ConstraintError::InvalidIndex(vertex.index()));
+        }
+
+        let constraint_edges = self
+            .vertex(vertex)
+            .out_edges()
+            .filter(|edge| edge.is_constraint_edge())
+            .map(|edge| edge.fix())
+            .collect::<Vec<_>>();
+        let [to_first, to_second] = constraint_edges[..] else {
+            return Err(ConstraintError::InvalidConstraintDegree(
+                constraint_edges.len(),
+            ));
+        };
+
+        let first = self.directed_edge(to_first).to().fix();
+        let second = self.directed_edge(to_second).to().fix();
+
+        // All constraint edges that would intersect the new edge are still present after the
+        // removal. The removed vertex itself cannot block the new edge.
+        if let Some(edge) = self.find_intersecting_constraint(first, second) {
+            return Err(ConstraintError::IntersectingConstraint(edge));
+        }
+
+        let first_origin = self.input_constraint_direction(to_first.rev());
+        let second_origin = self.input_constraint_direction(to_second);
+
+        // Without stable handles, the last vertex is moved into the slot of the removed vertex.
+        let last_vertex = FixedVertexHandle::from_index(self.num_vertices() - 1);
+        let has_stable_handles = self.has_stable_handles();
+        let [first, second] = [first, second].map(|neighbor| {
+            if !has_stable_handles && neighbor == last_vertex {
+                vertex
+            } else {
+                neighbor
+            }
+        });
+
+        let removed = self.remove(vertex);
+        match (first_origin, second_origin) {
+            (Some((id, true)), Some((second_id, true))) if id == second_id => {
+                self.add_constraint_with_id(first, second, id)
+            }
+            (Some((id, false)), Some((second_id, false))) if id == second_id => {
+                self.add_constraint_with_id(second, first, id)
+            }
+            _ => self.add_constraint(first, second),
+        };
+        Ok(removed)
+    }
+
     /// Returns the number of constraint edges.
     pub fn num_constraints(&self) -> usize {
         self.num_constraints
@@ -461,6 +969,21 @@ where
         })
     }
 
+    fn find_intersecting_constraint(
+        &self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+    ) -> Option<FixedUndirectedEdgeHandle> {
+        LineIntersectionIterator::new_from_handles(self, from, to).find_map(|intersection| {
+            match intersection {
+                Intersection::EdgeIntersection(edge) if edge.is_constraint_edge() => {
+                    Some(edge.fix().as_undirected())
+                }
+                _ => None,
+            }
+        })
+    }
+
     /// Creates a several constraint edges by taking and connecting vertices from an iterator.
     ///
     /// Every two sequential vertices in the input iterator will be connected by a constraint edge.
@@ -493,6 +1016,7 @@ where
     /// # Panics
     ///
     /// Panics if any of the generated constraints intersects with any other constraint edge.
+    /// Use [Self::add_constraint_edges_checked] to return an error instead.
     pub fn add_constraint_edges(
         &mut self,
         vertices: impl IntoIterator<Item = V>,
@@ -524,13 +1048,64 @@ where
     /// # Panics
     ///
     /// Panics if the new constraint edge intersects with an existing
-    /// constraint edge. Use [can_add_constraint](Self::can_add_constraint) to check.
+    /// constraint edge. Use [can_add_constraint](Self::can_add_constraint) to check or
+    /// [Self::add_constraint_edge_checked] to return an error instead.
     pub fn add_constraint_edge(&mut self, from: V, to: V) -> Result<bool, InsertionError> {
         let from_handle = self.insert(from)?;
         let to_handle = self.insert(to)?;
         Ok(self.add_constraint(from_handle, to_handle))
     }
 
+    /// Non-panicking variant of [Self::add_constraint_edge].
+    ///
+    /// Returns an error if the new constraint edge would intersect an existing constraint edge
+    /// or if both vertices have the same position. Nothing is inserted if both vertices have the
+    /// same position. If `to` cannot be inserted, `from` remains inserted. If the constraint edge
+    /// intersects an existing constraint edge, both vertices remain inserted.
+    ///
+    /// *See [Self::add_constraint_checked]*
+    pub fn add_constraint_edge_checked(&mut self, from: V, to: V) -> Result<bool, ConstraintError> {
+        if from.position() == to.position() {
+            return Err(ConstraintError::ZeroLengthEdge);
+        }
+        let initial_num_constraints = self.num_constraints();
+        let from_handle = self.insert(from)?;
+        let to_handle = self.insert(to)?;
+        self.add_constraint_checked(from_handle, to_handle)?;
+        Ok(self.num_constraints != initial_num_constraints)
+    }
+
+    /// Non-panicking variant of [Self::add_constraint_edges].
+    ///
+    /// Returns an error if any new constraint edge would intersect an existing constraint edge
+    /// or if any two consecutive vertices have the same position. All vertices and constraint
+    /// edges that were added before the error occurred remain in the triangulation.
+    ///
+    /// *See [Self::add_constraint_checked]*
+    pub fn add_constraint_edges_checked(
+        &mut self,
+        vertices: impl IntoIterator<Item = V>,
+        closed: bool,
+    ) -> Result<(), ConstraintError> {
+        let mut iter = vertices.into_iter();
+        if let Some(first) = iter.next() {
+            let first_handle = self.insert(first)?;
+            let mut previous_handle = first_handle;
+            let mut current_handle = first_handle;
+            for current in iter {
+                current_handle = self.insert(current)?;
+                self.add_constraint_checked(previous_handle, current_handle)?;
+                previous_handle = current_handle;
+            }
+
+            if closed && current_handle != first_handle {
+                self.add_constraint_checked(current_handle, first_handle)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Adds a constraint edge between to vertices.
     ///
     /// Returns `true` if at least one constraint edge was added.
@@ -544,15 +1119,129 @@ where
     /// # Panics
     ///
     /// Panics if the new constraint edge intersects an existing
-    /// constraint edge. Use [Self::try_add_constraint] or [Self::add_constraint_and_split] to work
-    /// around that.
+    /// constraint edge. Use [Self::try_add_constraint], [Self::add_constraint_checked] or
+    /// [Self::add_constraint_and_split] to work around that.
     pub fn add_constraint(&mut self, from: FixedVertexHandle, to: FixedVertexHandle) -> bool {
         let initial_num_constraints = self.num_constraints();
-        self.resolve_splitting_constraint_request(from, to, None);
+        self.resolve_splitting_constraint_request(from, to, None, &mut Vec::new());
+
+        self.num_constraints != initial_num_constraints
+    }
+
+    /// Adds a constraint edge between two vertices and tags it with an ID.
+    ///
+    /// The ID can be used to map constraint edges back to the input, e.g. to the index of a GIS
+    /// feature or a boundary condition. It is inherited by all edges that the constraint is split
+    /// into, both by this method and by any later operation (e.g. inserting a vertex on the
+    /// constraint, splitting it with another constraint or [refinement](Self::refine)).
+    /// Any constraint edge that overlaps the new constraint is assigned the new ID.
+    ///
+    /// Returns `true` if at least one constraint edge was added.
+    ///
+    /// *See also [constraint_edges_of](Self::constraint_edges_of)*
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new constraint edge intersects an existing constraint edge. Use
+    /// [Self::add_constraint_with_id_checked] to return an error or
+    /// [Self::add_constraint_and_split_with_id] to work around that.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, Triangulation};
+    ///
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::new();
+    /// let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+    /// let v1 = cdt.insert(Point2::new(2.0, 0.0))?;
+    /// cdt.add_constraint_with_id(v0, v1, 42);
+    ///
+    /// // Split the constraint by inserting a vertex onto it
+    /// let v2 = cdt.insert(Point2::new(1.0, 0.0))?;
+    ///
+    /// let chain = cdt.constraint_edges_of(42);
+    /// let vertices: Vec<_> = chain.iter().map(|edge| cdt.directed_edge(*edge).from().fix()).collect();
+    /// assert_eq!(vertices, [v0, v2]);
+    /// assert_eq!(cdt.directed_edge(chain[1]).to().fix(), v1);
+    /// # Ok(()) }
+    /// ```
+    pub fn add_constraint_with_id(
+        &mut self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+        id: u32,
+    ) -> bool {
+        let initial_num_constraints = self.num_constraints();
+        let edges = self.resolve_splitting_constraint_request(from, to, None, &mut Vec::new());
+        self.set_constraint_id(&edges, id);
 
         self.num_constraints != initial_num_constraints
     }
 
+    /// Returns the ID of a constraint edge.
+    ///
+    /// Returns `None` if the edge is not a constraint edge or has been added without an ID.
+    ///
+    /// *See [add_constraint_with_id](Self::add_constraint_with_id)*
+    pub fn constraint_id(&self, edge: FixedUndirectedEdgeHandle) -> Option<u32> {
+        self.dcel.undirected_edge_data(edge).constraint_id()
+    }
+
+    /// Returns all constraint edges with a given ID as ordered chain.
+    ///
+    /// All edges point in the direction of the constraint that they were created from. Edges of
+    /// consecutive input constraints (e.g. multiple constraints forming a polyline with the same
+    /// ID) are chained together. Separate chains follow each other in unspecified order.
+    ///
+    /// This method runs in `O(n)` for `n` edges.
+    ///
+    /// *See [add_constraint_with_id](Self::add_constraint_with_id)*
+    pub fn constraint_edges_of(&self, id: u32) -> Vec<FixedDirectedEdgeHandle> {
+        let mut outgoing = HashMap::<FixedVertexHandle, Vec<FixedDirectedEdgeHandle>>::new();
+        let mut num_incoming = HashMap::<FixedVertexHandle, usize>::new();
+        let mut num_edges = 0;
+        for edge in self.fixed_undirected_edges() {
+            let Some(origin) = self.dcel.undirected_edge_data(edge).2 else {
+                continue;
+            };
+            if origin.id != id {
+                continue;
+            }
+            let edge = if origin.reversed {
+                edge.as_directed().rev()
+            } else {
+                edge.as_directed()
+            };
+            let edge_handle = self.directed_edge(edge);
+            outgoing
+                .entry(edge_handle.from().fix())
+                .or_default()
+                .push(edge);
+            *num_incoming.entry(edge_handle.to().fix()).or_default() += 1;
+            num_edges += 1;
+        }
+
+        // Chains start at vertices with more outgoing than incoming edges. Any remaining edges
+        // form closed loops.
+        let mut vertices = outgoing.keys().copied().collect::<Vec<_>>();
+        vertices.sort_unstable();
+        let (mut starts, loop_starts): (Vec<_>, Vec<_>) =
+            vertices.into_iter().partition(|vertex| {
+                num_incoming.get(vertex).copied().unwrap_or(0) < outgoing[vertex].len()
+            });
+        starts.extend(loop_starts);
+
+        let mut result = Vec::with_capacity(num_edges);
+        for start in starts {
+            let mut current = start;
+            while let Some(edge) = outgoing.get_mut(&current).and_then(|edges| edges.pop()) {
+                result.push(edge);
+                current = self.directed_edge(edge).to().fix();
+            }
+        }
+        result
+    }
+
     /// Takes a conflict region (expressed as a list of intersecting edges) rotates edges to create
     /// a new constraint edge. Then, the rotated edges (except the new constraint edge)
     /// are legalized to restore the Delaunay property.
@@ -717,17 +1406,66 @@ where
         }
     }
 
-    #[cfg(any(test, fuzzing))]
-    #[allow(missing_docs)]
-    pub fn cdt_sanity_check(&self) {
-        self.cdt_sanity_check_with_params(true);
+    /// Assigns a constraint ID to edges that point in the direction of their input constraint.
+    fn set_constraint_id(&mut self, edges: &[FixedDirectedEdgeHandle], id: u32) {
+        for edge in edges {
+            self.dcel.undirected_edge_data_mut(edge.as_undirected()).2 = Some(ConstraintOrigin {
+                id,
+                reversed: !edge.is_normalized(),
+            });
+        }
     }
 
-    #[cfg(any(test, fuzzing))]
-    #[allow(missing_docs)]
-    pub fn cdt_sanity_check_with_params(&self, check_convexity: bool) {
-        let num_constraints = self
-            .dcel
+    /// Passes the origin of a constraint edge on to the edges that replace it.
+    ///
+    /// The parts may be given in any direction.
+    fn inherit_constraint_origin(
+        &mut self,
+        origin: Option<ConstraintOrigin>,
+        original: FixedDirectedEdgeHandle,
+        parts: [FixedDirectedEdgeHandle; 2],
+    ) {
+        let Some(origin) = origin else {
+            return;
+        };
+        let follows_input = original.is_normalized() != origin.reversed;
+        let [from, to] = self.directed_edge(original).positions();
+        let direction = to.sub(from);
+
+        for part in parts {
+            let [p0, p1] = self.directed_edge(part).positions();
+            let part = if p1.sub(p0).dot(direction) >= zero() {
+                part
+            } else {
+                part.rev()
+            };
+            self.dcel.undirected_edge_data_mut(part.as_undirected()).2 = Some(ConstraintOrigin {
+                id: origin.id,
+                reversed: part.is_normalized() != follows_input,
+            });
+        }
+    }
+
+    /// Returns the ID of an edge's constraint and whether the edge points in the direction of
+    /// its input constraint.
+    fn input_constraint_direction(&self, edge: FixedDirectedEdgeHandle) -> Option<(u32, bool)> {
+        self.dcel
+            .undirected_edge_data(edge.as_undirected())
+            .2
+            .map(|origin| (origin.id, edge.is_normalized() != origin.reversed))
+    }
+
+    #[cfg(any(test, fuzzing))]
+    #[allow(missing_docs)]
+    pub fn cdt_sanity_check(&self) {
+        self.cdt_sanity_check_with_params(true);
+    }
+
+    #[cfg(any(test, fuzzing))]
+    #[allow(missing_docs)]
+    pub fn cdt_sanity_check_with_params(&self, check_convexity: bool) {
+        let num_constraints = self
+            .dcel
             .undirected_edges()
             .filter(|e| e.is_constraint_edge())
             .count();
@@ -745,6 +1483,8 @@ where
     ///
     /// Does nothing and returns `false` if the given edge is not a constraint edge.
     /// Otherwise, the edge is unmarked and the Delaunay property is restored in its vicinity.
+    ///
+    /// *See also [Self::remove_constraint_chain] and [Self::remove_constraint_with_id]*
     pub fn remove_constraint_edge(&mut self, edge: FixedUndirectedEdgeHandle) -> bool {
         if self.is_constraint_edge(edge) {
             self.dcel
@@ -808,6 +1548,204 @@ where
         self.resolve_conflict_groups(initial_conflict_regions)
     }
 
+    /// Non-panicking variant of [Self::add_constraint].
+    ///
+    /// Leaves the triangulation unchanged and returns an error if
+    ///  - `from` or `to` is not a valid vertex handle,
+    ///  - `from == to` or
+    ///  - the new edge would intersect an existing constraint edge. The error contains the first
+    ///    intersected constraint edge.
+    ///
+    /// Otherwise, returns all constraint edges that connect `from` and `to`, see
+    /// [Self::try_add_constraint].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use spade::{ConstrainedDelaunayTriangulation, ConstraintError, Point2, Triangulation};
+    /// # fn try_main() -> Result<(), ConstraintError> {
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<_>>::new();
+    /// let v0 = cdt.insert(Point2::new(-1.0, 0.0))?;
+    /// let v1 = cdt.insert(Point2::new(1.0, 0.0))?;
+    /// let v2 = cdt.insert(Point2::new(0.0, 1.0))?;
+    /// let v3 = cdt.insert(Point2::new(0.0, -1.0))?;
+    /// let edges = cdt.add_constraint_checked(v2, v3)?;
+    ///
+    /// let error = cdt.add_constraint_checked(v0, v1).unwrap_err();
+    /// assert_eq!(error, ConstraintError::IntersectingConstraint(edges[0].as_undirected()));
+    /// assert_eq!(cdt.add_constraint_checked(v0, v0), Err(ConstraintError::ZeroLengthEdge));
+    /// # Ok(()) }
+    /// # fn main() { try_main().unwrap() }
+    /// ```
+    pub fn add_constraint_checked(
+        &mut self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+    ) -> Result<Vec<FixedDirectedEdgeHandle>, ConstraintError> {
+        self.validate_constraint_vertices(from, to)?;
+        if let Some(edge) = self.find_intersecting_constraint(from, to) {
+            return Err(ConstraintError::IntersectingConstraint(edge));
+        }
+        Ok(self.try_add_constraint(from, to))
+    }
+
+    /// Non-panicking variant of [Self::add_constraint_with_id].
+    ///
+    /// Returns the same errors as [Self::add_constraint_checked] and leaves the triangulation
+    /// unchanged in this case. Otherwise, returns all constraint edges that connect `from` and
+    /// `to`.
+    pub fn add_constraint_with_id_checked(
+        &mut self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+        id: u32,
+    ) -> Result<Vec<FixedDirectedEdgeHandle>, ConstraintError> {
+        let edges = self.add_constraint_checked(from, to)?;
+        self.set_constraint_id(&edges, id);
+        Ok(edges)
+    }
+
+    /// Non-panicking variant of [Triangulation::move_vertex].
+    ///
+    /// Leaves the triangulation unchanged and returns an error if
+    ///  - `vertex` is not a valid vertex handle,
+    ///  - `new_position` is not a valid position (see [crate::validate_coordinate]),
+    ///  - another vertex is already located at `new_position` or
+    ///  - any constraint edge of `vertex` would intersect another constraint edge after moving.
+    ///    The error contains the first intersected constraint edge.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{ConstrainedDelaunayTriangulation, ConstraintError, Point2, Triangulation};
+    /// # fn try_main() -> Result<(), ConstraintError> {
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<_>>::new();
+    /// let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+    /// let v1 = cdt.insert(Point2::new(1.0, 0.0))?;
+    /// let v2 = cdt.insert(Point2::new(2.0, -1.0))?;
+    /// let v3 = cdt.insert(Point2::new(2.0, 1.0))?;
+    /// cdt.add_constraint(v0, v1);
+    /// cdt.add_constraint(v2, v3);
+    ///
+    /// // The constraint edge v0 -> v1 would cross v2 -> v3
+    /// let result = cdt.move_vertex_checked(v1, Point2::new(3.0, 0.0));
+    /// assert!(matches!(result, Err(ConstraintError::IntersectingConstraint(_))));
+    /// assert_eq!(cdt.vertex(v1).position(), Point2::new(1.0, 0.0));
+    ///
+    /// assert_eq!(
+    ///     cdt.move_vertex_checked(v1, Point2::new(2.0, 1.0)),
+    ///     Err(ConstraintError::OccupiedPosition(v3))
+    /// );
+    /// cdt.move_vertex_checked(v1, Point2::new(1.0, 2.0))?;
+    /// assert!(cdt.exists_constraint(v0, v1));
+    /// # Ok(()) }
+    /// # fn main() { try_main().unwrap() }
+    /// ```
+    pub fn move_vertex_checked(
+        &mut self,
+        vertex: FixedVertexHandle,
+        new_position: Point2<V::Scalar>,
+    ) -> Result<FixedVertexHandle, ConstraintError>
+    where
+        V: HasPositionMut,
+    {
+        if !self.s().is_vertex_live(vertex) {
+            return Err(ConstraintError::InvalidIndex(vertex.index()));
+        }
+        validate_vertex(&new_position)?;
+        if self.vertex(vertex).position() == new_position
+            || self.try_move_vertex_locally(vertex, new_position)
+        {
+            return Ok(vertex);
+        }
+
+        if let PositionInTriangulation::OnVertex(other) = self.locate(new_position) {
+            if other != vertex {
+                return Err(ConstraintError::OccupiedPosition(other));
+            }
+        }
+
+        if let Some(edge) = self.find_moved_constraint_intersection(vertex, new_position) {
+            return Err(ConstraintError::IntersectingConstraint(edge));
+        }
+
+        self.move_vertex_with_constraints(vertex, new_position)?;
+        Ok(vertex)
+    }
+
+    /// Returns the first constraint edge that a constraint edge of `vertex` would intersect after
+    /// moving `vertex` to `new_position`.
+    fn find_moved_constraint_intersection(
+        &self,
+        vertex: FixedVertexHandle,
+        new_position: Point2<V::Scalar>,
+    ) -> Option<FixedUndirectedEdgeHandle> {
+        self.vertex(vertex)
+            .out_edges()
+            .filter(|edge| edge.is_constraint_edge())
+            .find_map(|edge| {
+                let neighbor = edge.to().position();
+                // Constraint edges of the moved vertex are removed and can be ignored. Constraint
+                // edges that contain the new position are split at the moved vertex.
+                LineIntersectionIterator::new(self, new_position, neighbor).find_map(
+                    |intersection| match intersection {
+                        Intersection::EdgeIntersection(edge)
+                            if edge.is_constraint_edge()
+                                && !edge.vertices().iter().any(|v| v.fix() == vertex)
+                                && !edge.side_query(new_position).is_on_line() =>
+                        {
+                            Some(edge.fix().as_undirected())
+                        }
+                        _ => None,
+                    },
+                )
+            })
+    }
+
+    /// Moves a vertex by re-inserting it. Its constraint edges are re-added afterwards.
+    fn move_vertex_with_constraints(
+        &mut self,
+        vertex: FixedVertexHandle,
+        new_position: Point2<V::Scalar>,
+    ) -> Result<(), InsertionError>
+    where
+        V: HasPositionMut,
+    {
+        let constraint_neighbors: Vec<_> = self
+            .vertex(vertex)
+            .out_edges()
+            .filter(|edge| edge.is_constraint_edge())
+            .map(|edge| (edge.to().fix(), self.input_constraint_direction(edge.fix())))
+            .collect();
+
+        // The constraint edges are removed along with the vertex and re-added afterwards
+        self.num_constraints -= constraint_neighbors.len();
+        self.move_vertex_by_reinsertion(vertex, new_position)?;
+        for (neighbor, origin) in constraint_neighbors {
+            match origin {
+                Some((id, true)) => self.add_constraint_with_id(vertex, neighbor, id),
+                Some((id, false)) => self.add_constraint_with_id(neighbor, vertex, id),
+                None => self.add_constraint(vertex, neighbor),
+            };
+        }
+        Ok(())
+    }
+
+    fn validate_constraint_vertices(
+        &self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+    ) -> Result<(), ConstraintError> {
+        for vertex in [from, to] {
+            if !self.s().is_vertex_live(vertex) {
+                return Err(ConstraintError::InvalidIndex(vertex.index()));
+            }
+        }
+        if from == to {
+            return Err(ConstraintError::ZeroLengthEdge);
+        }
+        Ok(())
+    }
+
     fn get_conflict_resolutions(
         &mut self,
         from: FixedVertexHandle,
@@ -859,6 +1797,7 @@ where
         mut from: FixedVertexHandle,
         to: FixedVertexHandle,
         vertex_constructor: Option<&dyn Fn(Point2<f64>) -> V>,
+        splits: &mut Vec<ConstraintSplit>,
     ) -> Vec<FixedDirectedEdgeHandle> {
         let mut result = Vec::new();
         let mut conflict_edges = Vec::new();
@@ -886,6 +1825,7 @@ where
                     }
                     // Slow path. We have found a conflict which needs to be resolved.
                     let [p0, p1] = edge.positions().map(|p| p.to_f64());
+                    let split_edge = [edge.from().fix(), edge.to().fix()];
 
                     let from_pos = self.vertex(from).position().to_f64();
                     let to_pos = self.vertex(to).position().to_f64();
@@ -938,15 +1878,18 @@ where
                                 let next = edge.next().fix();
 
                                 let edge = edge.fix();
+                                let origin = self.dcel.undirected_edge_data(edge.as_undirected()).2;
                                 self.undirected_edge_data_mut(edge.as_undirected())
                                     .unmake_constraint_edge();
                                 self.num_constraints -= 1;
 
                                 self.make_constraint_edge(prev.as_undirected());
                                 self.make_constraint_edge(next.as_undirected());
+                                self.inherit_constraint_origin(origin, edge, [prev, next]);
 
                                 legalize_buffer.push(edge.as_undirected());
                                 self.legalize_edges_after_removal(&mut legalize_buffer, |_| false);
+                                splits.push((split_edge, alternative_vertex));
                             }
 
                             alternative_vertex
@@ -955,6 +1898,8 @@ where
                             let (new_vertex, [e0, e1]) = self.insert_on_edge(edge, new_vertex);
                             self.handle_legal_edge_split([e0, e1]);
                             self.legalize_vertex(new_vertex);
+                            self.mark_steiner_vertex(new_vertex);
+                            splits.push((split_edge, new_vertex));
                             new_vertex
                         };
 
@@ -1187,13 +2132,29 @@ where
     /// not return only `Intersection::EdgeOverlap` as would be expected. Instead, use the returned
     /// `Vec` to identify the edges that form the new constraint.
     /// The absolute deviation from the correct position should be small, especially when using
-    /// `f64` coordinates as storage type.
+    /// `f64` coordinates as storage type. Use [Self::add_constraint_and_snap] to round
+    /// intersection points to a grid instead.
     pub fn add_constraint_and_split<C>(
         &mut self,
         from: FixedVertexHandle,
         to: FixedVertexHandle,
         vertex_constructor: C,
     ) -> Vec<FixedDirectedEdgeHandle>
+    where
+        C: Fn(Point2<<V as HasPosition>::Scalar>) -> V,
+    {
+        self.add_constraint_and_split_recording(from, to, vertex_constructor, &mut Vec::new())
+    }
+
+    /// Like [add_constraint_and_split](Self::add_constraint_and_split), but also appends every
+    /// constraint edge that has been split to `splits`.
+    fn add_constraint_and_split_recording<C>(
+        &mut self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+        vertex_constructor: C,
+        splits: &mut Vec<ConstraintSplit>,
+    ) -> Vec<FixedDirectedEdgeHandle>
     where
         C: Fn(Point2<<V as HasPosition>::Scalar>) -> V,
     {
@@ -1205,86 +2166,840 @@ where
             vertex_constructor(Point2::new(x, y))
         };
 
-        self.resolve_splitting_constraint_request(from, to, Some(r))
+        self.resolve_splitting_constraint_request(from, to, Some(r), splits)
     }
-}
-
-/// Describes all possible ways in which conflict regions which are created while adding a
-/// constraint edge may end.
-enum ConflictRegionEnd {
-    /// Conflict group ends with an existing vertex
-    Existing(FixedVertexHandle),
-    /// Special case of "Existing" - the constraint edge overlaps any existing edge which implies
-    /// that the conflict group also ends on an existing vertex.
-    /// However, it makes sense to handle this specially to prevent having to look up the overlapped
-    /// edge later.
-    EdgeOverlap(FixedDirectedEdgeHandle),
-}
 
-impl core::fmt::Debug for ConflictRegionEnd {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        match self {
-            Existing(handle) => write!(f, "Existing({handle:?})"),
-            EdgeOverlap(edge) => write!(f, "EdgeOverlap({edge:?})"),
-        }
+    /// Adds a constraint to the triangulation and tags it with an ID. Splits any existing
+    /// constraint edge that would intersect the new constraint edge.
+    ///
+    /// Split constraint edges keep their IDs.
+    ///
+    /// *See [add_constraint_and_split](Self::add_constraint_and_split) and
+    /// [add_constraint_with_id](Self::add_constraint_with_id)*
+    pub fn add_constraint_and_split_with_id<C>(
+        &mut self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+        id: u32,
+        vertex_constructor: C,
+    ) -> Vec<FixedDirectedEdgeHandle>
+    where
+        C: Fn(Point2<<V as HasPosition>::Scalar>) -> V,
+    {
+        let edges = self.add_constraint_and_split(from, to, vertex_constructor);
+        self.set_constraint_id(&edges, id);
+        edges
     }
-}
-
-/// Represents a conflict region that does not yet fully exist as a vertex may be missing. This can
-/// happen if adding a constraint edge should split any intersecting existing edge.
-/// This will eventually be turned into a "real" conflict group (described as a list of edges) by
-/// inserting the missing vertex.
-struct InitialConflictRegion {
-    conflict_edges: Vec<FixedDirectedEdgeHandle>,
-    group_end: ConflictRegionEnd,
-}
 
-impl core::fmt::Debug for InitialConflictRegion {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        f.debug_struct("InitialConflictRegion")
-            .field("conflict_edges", &self.conflict_edges)
-            .field("group_end", &self.group_end)
-            .finish()
+    /// Non-panicking variant of [Self::add_constraint_and_split].
+    ///
+    /// Leaves the triangulation unchanged and returns an error if `from` or `to` is not a valid
+    /// vertex handle or if `from == to`.
+    pub fn add_constraint_and_split_checked<C>(
+        &mut self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+        vertex_constructor: C,
+    ) -> Result<Vec<FixedDirectedEdgeHandle>, ConstraintError>
+    where
+        C: Fn(Point2<<V as HasPosition>::Scalar>) -> V,
+    {
+        self.validate_constraint_vertices(from, to)?;
+        Ok(self.add_constraint_and_split(from, to, vertex_constructor))
     }
-}
 
-pub fn get_edge_intersections<S: SpadeNum + Float>(
-    p1: Point2<S>,
-    p2: Point2<S>,
-    p3: Point2<S>,
-    p4: Point2<S>,
-) -> Point2<S> {
-    let p1 = p1.to_f64();
-    let p2 = p2.to_f64();
-    let p3 = p3.to_f64();
-    let p4 = p4.to_f64();
+    /// Non-panicking variant of [Self::add_constraint_and_split_with_id].
+    ///
+    /// *See [Self::add_constraint_and_split_checked]*
+    pub fn add_constraint_and_split_with_id_checked<C>(
+        &mut self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+        id: u32,
+        vertex_constructor: C,
+    ) -> Result<Vec<FixedDirectedEdgeHandle>, ConstraintError>
+    where
+        C: Fn(Point2<<V as HasPosition>::Scalar>) -> V,
+    {
+        self.validate_constraint_vertices(from, to)?;
+        Ok(self.add_constraint_and_split_with_id(from, to, id, vertex_constructor))
+    }
 
-    let a1 = p2.y - p1.y;
-    let b1 = p1.x - p2.x;
-    let c1 = a1 * p1.x + b1 * p1.y;
+    /// Adds a constraint like [add_constraint_and_split](Self::add_constraint_and_split) and
+    /// records it in `chains`.
+    ///
+    /// Any chain in `chains` whose constraint edges are split is updated accordingly.
+    pub(crate) fn add_constraint_and_split_into_chains<C>(
+        &mut self,
+        chains: &mut ConstraintChains,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+        vertex_constructor: C,
+    ) where
+        C: Fn(Point2<<V as HasPosition>::Scalar>) -> V,
+    {
+        let mut splits = Vec::new();
+        let edges = if from == to {
+            Vec::new()
+        } else {
+            self.add_constraint_and_split_recording(from, to, vertex_constructor, &mut splits)
+        };
 
-    let a2 = p4.y - p3.y;
-    let b2 = p3.x - p4.x;
-    let c2 = a2 * p3.x + b2 * p3.y;
+        for (edge, vertex) in splits {
+            chains.split(edge, vertex);
+        }
+        let vertices = core::iter::once(from)
+            .chain(
+                edges
+                    .iter()
+                    .map(|edge| self.directed_edge(*edge).to().fix()),
+            )
+            .collect();
+        chains.push(vertices);
+    }
 
-    let determinant = a1 * b2 - a2 * b1;
+    /// Adds a constraint to the triangulation and splits any intersected constraint edge at a
+    /// vertex that is rounded to the given grid (snap rounding).
+    ///
+    /// Other than [add_constraint_and_split](Self::add_constraint_and_split), split vertices are
+    /// not placed at the exact intersection point, which is often not representable anyway.
+    /// Instead, they are placed on the closest grid point. If a vertex already exists at that grid
+    /// point, it is reused. Otherwise, a new vertex is created with `vertex_constructor`.
+    /// Constraint edges that pass through the hot pixel (the cell around a grid point) of a
+    /// created vertex, as well as the new constraint itself, are rerouted through the
+    /// pixel's vertex. The same applies to existing vertices whose hot pixel is crossed by the new
+    /// constraint. Rerouted constraint edges keep their constraint ID.
+    ///
+    /// As a result, the new constraint and all constraints it intersects form a consistent
+    /// arrangement of straight edges between grid points: repeated insertions of nearly
+    /// coincident constraints don't create near-duplicate vertices or sliver triangles. This is
+    /// only guaranteed if all vertices lie on the grid - `from` and `to` are never moved.
+    ///
+    /// Returns all constraint edges that connect `from` and `to`, in order. In contrast to
+    /// [add_constraint_and_split](Self::add_constraint_and_split), these edges only follow the
+    /// line from `from` to `to` up to the grid precision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a created vertex has an invalid position (see
+    /// [InsertionError](crate::InsertionError)) or if an intersection cannot be resolved. The
+    /// latter can only happen if not all vertices lie on the grid. Use
+    /// [add_constraint_and_snap_checked](Self::add_constraint_and_snap_checked) to handle these
+    /// cases.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, SnapGrid, Triangulation};
+    ///
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::new();
+    /// let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+    /// let v1 = cdt.insert(Point2::new(10.0, 3.0))?;
+    /// let v2 = cdt.insert(Point2::new(0.0, 3.0))?;
+    /// let v3 = cdt.insert(Point2::new(10.0, 0.0))?;
+    /// cdt.add_constraint(v0, v1);
+    ///
+    /// // The exact intersection at (5.0, 1.5) is rounded to (5.0, 2.0)
+    /// let edges = cdt.add_constraint_and_snap(v2, v3, SnapGrid::integer(), |v| v);
+    /// assert_eq!(edges.len(), 2);
+    /// assert_eq!(cdt.directed_edge(edges[0]).to().position(), Point2::new(5.0, 2.0));
+    ///
+    /// // The first constraint was rerouted through the same vertex
+    /// assert_eq!(cdt.num_vertices(), 5);
+    /// assert_eq!(cdt.num_constraints(), 4);
+    /// # Ok(()) }
+    /// ```
+    pub fn add_constraint_and_snap<C>(
+        &mut self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+        grid: SnapGrid,
+        vertex_constructor: C,
+    ) -> Vec<FixedDirectedEdgeHandle>
+    where
+        C: Fn(Point2<<V as HasPosition>::Scalar>) -> V,
+    {
+        self.snap_constraint(from, to, grid, &vertex_constructor)
+            .expect("Failed to snap constraint")
+    }
 
-    let x: f64;
-    let y: f64;
-    if determinant == 0.0 {
-        x = f64::infinity();
-        y = f64::infinity();
-    } else {
-        x = (b2 * c1 - b1 * c2) / determinant;
-        y = (a1 * c2 - a2 * c1) / determinant;
+    /// Non-panicking variant of [Self::add_constraint_and_snap].
+    ///
+    /// Returns an error if
+    ///  - `from` or `to` is not a valid vertex handle,
+    ///  - `from == to`,
+    ///  - a created vertex has an invalid position or
+    ///  - an intersection cannot be resolved because snapping keeps routing a constraint edge
+    ///    through the same vertex. This can only happen if not all vertices lie on the grid. The
+    ///    error contains the intersected constraint edge.
+    ///
+    /// The triangulation is left unchanged if `from` or `to` is invalid. Otherwise, vertices and
+    /// constraint edges that have been created up to the error are kept.
+    pub fn add_constraint_and_snap_checked<C>(
+        &mut self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+        grid: SnapGrid,
+        vertex_constructor: C,
+    ) -> Result<Vec<FixedDirectedEdgeHandle>, ConstraintError>
+    where
+        C: Fn(Point2<<V as HasPosition>::Scalar>) -> V,
+    {
+        self.validate_constraint_vertices(from, to)?;
+        self.snap_constraint(from, to, grid, &vertex_constructor)
     }
 
-    [x, y]
-        .map(|s| <S as NumCast>::from(s).unwrap_or_else(|| (s as f32).into()))
-        .into()
-}
+    fn snap_constraint<C>(
+        &mut self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+        grid: SnapGrid,
+        vertex_constructor: &C,
+    ) -> Result<Vec<FixedDirectedEdgeHandle>, ConstraintError>
+    where
+        C: Fn(Point2<<V as HasPosition>::Scalar>) -> V,
+    {
+        // The vertices that the new constraint passes, in order.
+        let mut route = alloc::vec![from, to];
+        // Segments that still need to be inserted. Rerouted constraint edges keep their ID and
+        // whether they follow the direction of their input constraint.
+        let mut segments = alloc::vec![(from, to, None)];
+        // Every segment is routed through a vertex at most once. Otherwise, two vertices that
+        // don't lie on the grid can keep rerouting a segment through each other.
+        let mut splits = HashSet::new();
+
+        while let Some((from, to, origin)) = segments.pop() {
+            if from == to {
+                continue;
+            }
 
-#[cfg(test)]
+            // Segments of the new constraint must not pass a vertex twice.
+            let is_on_route = route
+                .windows(2)
+                .any(|pair| pair == [from, to] || pair == [to, from]);
+            let is_excluded = |vertex| {
+                splits.contains(&(from, to, vertex))
+                    || is_on_route && vertex != from && vertex != to && route.contains(&vertex)
+            };
+
+            let split_vertex = if let Some(vertex) =
+                self.find_hot_vertex(from, to, grid, is_excluded)
+            {
+                vertex
+            } else if let Some(edge) = self.find_intersecting_constraint(from, to) {
+                let [p0, p1] = self.undirected_edge(edge).positions().map(|p| p.to_f64());
+                let from_pos = self.vertex(from).position().to_f64();
+                let to_pos = self.vertex(to).position().to_f64();
+                let intersection =
+                    mitigate_underflow(get_edge_intersections(p0, p1, from_pos, to_pos));
+
+                let [c, d] = self.undirected_edge(edge).vertices().map(|v| v.fix());
+                let vertex =
+                    self.insert_snapped_vertex(grid.snap(intersection), grid, vertex_constructor)?;
+                let mut is_progress = vertex.handle != from
+                    && vertex.handle != to
+                    && !splits.contains(&(from, to, vertex.handle));
+                for (c, d) in vertex.rerouted.into_iter().chain([(c, d)]) {
+                    if !splits.insert((c, d, vertex.handle)) {
+                        continue;
+                    }
+                    is_progress = true;
+                    if let Some(origin) = self.reroute_constraint(c, d, vertex.handle) {
+                        split_route(&mut route, c, d, vertex.handle);
+                        segments.push((vertex.handle, d, origin));
+                        segments.push((c, vertex.handle, origin));
+                    } else if self.get_edge_from_neighbors(c, d).is_none() {
+                        // The edge has been split by inserting the vertex
+                        split_route(&mut route, c, d, vertex.handle);
+                    }
+                }
+                if !is_progress {
+                    return Err(ConstraintError::IntersectingConstraint(edge));
+                }
+                vertex.handle
+            } else {
+                let edges = self.add_constraint_checked(from, to)?;
+                if let Some((id, follows_input)) = origin {
+                    let edges = edges
+                        .into_iter()
+                        .map(|edge| if follows_input { edge } else { edge.rev() })
+                        .collect::<Vec<_>>();
+                    self.set_constraint_id(&edges, id);
+                }
+                continue;
+            };
+
+            splits.insert((from, to, split_vertex));
+            split_route(&mut route, from, to, split_vertex);
+            segments.push((split_vertex, to, origin));
+            segments.push((from, split_vertex, origin));
+        }
+
+        // Off-grid vertices can still route the constraint back to a vertex it already passed.
+        // Such loops are cut from the returned edges.
+        let mut path: Vec<FixedVertexHandle> = Vec::with_capacity(route.len());
+        for vertex in route {
+            if let Some(index) = path.iter().position(|other| *other == vertex) {
+                path.truncate(index + 1);
+            } else {
+                path.push(vertex);
+            }
+        }
+
+        Ok(path
+            .windows(2)
+            .flat_map(|pair| self.constraint_path(pair[0], pair[1]))
+            .collect())
+    }
+
+    /// Returns the first vertex along the line from `from` to `to` whose hot pixel is
+    /// intersected by that line.
+    ///
+    /// Vertices that lie in the hot pixel of `from` or `to` and excluded vertices are ignored.
+    fn find_hot_vertex(
+        &self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+        grid: SnapGrid,
+        is_excluded: impl Fn(FixedVertexHandle) -> bool,
+    ) -> Option<FixedVertexHandle> {
+        let from_pos = self.vertex(from).position().to_f64();
+        let to_pos = self.vertex(to).position().to_f64();
+
+        let mut candidates = Vec::new();
+        for intersection in LineIntersectionIterator::new_from_handles(self, from, to) {
+            match intersection {
+                Intersection::EdgeIntersection(edge) | Intersection::EdgeOverlap(edge) => {
+                    candidates.extend(edge.vertices().map(|v| v.fix()))
+                }
+                Intersection::VertexIntersection(vertex) => candidates.push(vertex.fix()),
+            }
+        }
+        for vertex in [from, to] {
+            candidates.extend(self.vertex(vertex).out_edges().map(|edge| edge.to().fix()));
+        }
+
+        candidates
+            .into_iter()
+            .filter(|vertex| *vertex != from && *vertex != to)
+            .filter(|vertex| !is_excluded(*vertex))
+            .filter_map(|vertex| {
+                let position = self.vertex(vertex).position().to_f64();
+                let is_hot = !grid.pixel_contains(position, from_pos)
+                    && !grid.pixel_contains(position, to_pos)
+                    && grid.segment_intersects_pixel(from_pos, to_pos, position);
+                let distance = position.sub(from_pos).dot(to_pos.sub(from_pos));
+                is_hot.then_some((vertex, distance))
+            })
+            .min_by(|(_, d0), (_, d1)| d0.total_cmp(d1))
+            .map(|(vertex, _)| vertex)
+    }
+
+    /// Returns the vertex at a grid point. Creates a new vertex if required.
+    ///
+    /// A new vertex is returned together with all adjacent constraint edges that pass through its
+    /// hot pixel.
+    fn insert_snapped_vertex<C>(
+        &mut self,
+        position: Point2<f64>,
+        grid: SnapGrid,
+        vertex_constructor: &C,
+    ) -> Result<SnappedVertex, InsertionError>
+    where
+        C: Fn(Point2<<V as HasPosition>::Scalar>) -> V,
+    {
+        let [x, y] = [position.x, position.y].map(|s| {
+            <<V as HasPosition>::Scalar as NumCast>::from(s).unwrap_or_else(|| (s as f32).into())
+        });
+        let position = Point2::new(x, y);
+        if let PositionInTriangulation::OnVertex(handle) = self.locate(position) {
+            return Ok(SnappedVertex {
+                handle,
+                rerouted: Vec::new(),
+            });
+        }
+
+        let handle = self.insert(vertex_constructor(position))?;
+        self.mark_steiner_vertex(handle);
+
+        let center = self.vertex(handle).position().to_f64();
+        let rerouted = self
+            .vertex(handle)
+            .out_edges()
+            .filter(|edge| !edge.is_outer_edge())
+            .map(|edge| edge.next())
+            .filter(|edge| {
+                let [from, to] = edge.positions().map(|p| p.to_f64());
+                edge.is_constraint_edge() && grid.segment_intersects_pixel(from, to, center)
+            })
+            .map(|edge| (edge.from().fix(), edge.to().fix()))
+            .collect();
+
+        Ok(SnappedVertex { handle, rerouted })
+    }
+
+    /// Removes the constraint edge between `from` and `to` so that it can be rerouted through
+    /// `vertex`.
+    ///
+    /// Returns `None` if no such constraint edge exists or if it is adjacent to `vertex`.
+    /// Otherwise, returns the edge's constraint ID and whether `from -> to` follows the
+    /// direction of its input constraint.
+    fn reroute_constraint(
+        &mut self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+        vertex: FixedVertexHandle,
+    ) -> Option<Option<(u32, bool)>> {
+        let edge = self.get_edge_from_neighbors(from, to)?.fix();
+        if !self.is_constraint_edge(edge.as_undirected()) || vertex == from || vertex == to {
+            return None;
+        }
+        let origin = self.input_constraint_direction(edge);
+        self.remove_constraint_edge(edge.as_undirected());
+        Some(origin)
+    }
+
+    /// Removes a chain of constraint edges, e.g. a polyline or polygon.
+    ///
+    /// The chain contains the given constraint edge and extends in both directions until it
+    /// reaches a vertex that is not adjacent to exactly two constraint edges. All edges of the
+    /// chain are unmarked and the Delaunay property is restored in their vicinity, see
+    /// [Self::remove_constraint_edge].
+    ///
+    /// If `remove_steiner_vertices` is set, vertices of the chain are removed if they have only
+    /// been inserted to split constraint edges. These are the vertices created by
+    /// [Self::add_constraint_and_split], [Self::add_constraint_and_snap],
+    /// [Self::bulk_load_cdt_with_intersections], [Self::make_conforming] and [Self::refine].
+    /// If such a vertex splits another constraint, the remaining constraint is healed (see
+    /// [Self::remove_and_heal]). Vertices inserted by any other method are never removed.
+    ///
+    /// Returns the number of removed constraint edges. Does nothing and returns 0 if the given
+    /// edge is not a constraint edge.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, Triangulation};
+    ///
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::new();
+    /// let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+    /// let v1 = cdt.insert(Point2::new(4.0, 0.0))?;
+    /// let v2 = cdt.insert(Point2::new(2.0, -2.0))?;
+    /// let v3 = cdt.insert(Point2::new(2.0, 2.0))?;
+    /// cdt.add_constraint(v0, v1);
+    /// let edges = cdt.add_constraint_and_split(v2, v3, |v| v);
+    /// assert_eq!(cdt.num_vertices(), 5);
+    ///
+    /// // Removes the second constraint and its intersection with the first constraint
+    /// let removed = cdt.remove_constraint_chain(edges[0].as_undirected(), true);
+    /// assert_eq!(removed, 2);
+    /// assert_eq!(cdt.num_vertices(), 4);
+    /// assert!(cdt.exists_constraint(v0, v1));
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Handle invalidation
+    /// This method will invalidate all vertex, edge and face handles if any vertex is removed.
+    pub fn remove_constraint_chain(
+        &mut self,
+        edge: FixedUndirectedEdgeHandle,
+        remove_steiner_vertices: bool,
+    ) -> usize {
+        if !self.is_constraint_edge(edge) {
+            return 0;
+        }
+
+        // Stops at closed loops and prevents running around loops that are reached through a
+        // straight intersection.
+        let mut visited = HashSet::new();
+        visited.insert(edge);
+
+        let start = edge.as_directed();
+        let mut chain = alloc::vec![start];
+        let mut current = start;
+        while let Some(next) = self.next_in_constraint_chain(current) {
+            if !visited.insert(next.as_undirected()) {
+                break;
+            }
+            chain.push(next);
+            current = next;
+        }
+
+        if self.next_in_constraint_chain(current) != Some(start) {
+            let mut backward = Vec::new();
+            let mut current = start.rev();
+            while let Some(next) = self.next_in_constraint_chain(current) {
+                if !visited.insert(next.as_undirected()) {
+                    break;
+                }
+                backward.push(next.rev());
+                current = next;
+            }
+            backward.reverse();
+            backward.append(&mut chain);
+            chain = backward;
+        }
+
+        self.remove_constraint_edges_and_steiner_vertices(chain, remove_steiner_vertices)
+    }
+
+    /// Returns the constraint edge that continues a chain of constraint edges.
+    ///
+    /// Chains continue through vertices with exactly two adjacent constraint edges and straight
+    /// through Steiner vertices at which two constraints cross.
+    fn next_in_constraint_chain(
+        &self,
+        edge: FixedDirectedEdgeHandle,
+    ) -> Option<FixedDirectedEdgeHandle> {
+        let edge = self.directed_edge(edge);
+        let candidates = edge
+            .to()
+            .out_edges()
+            .filter(|next| next.is_constraint_edge())
+            .collect::<Vec<_>>();
+        match candidates[..] {
+            [first, second] => Some(if first == edge.rev() { second } else { first }.fix()),
+            // Out edges are ordered counterclockwise. The crossing constraint lies in between.
+            [_, _, _, _] if self.steiner_vertices.contains(&edge.to().fix()) => {
+                let index = candidates.iter().position(|next| *next == edge.rev())?;
+                Some(candidates[(index + 2) % 4].fix())
+            }
+            _ => None,
+        }
+    }
+
+    /// Removes all constraint edges with a given [constraint ID](Self::add_constraint_with_id).
+    ///
+    /// Refer to [Self::remove_constraint_chain] for a description of `remove_steiner_vertices`.
+    ///
+    /// Returns the number of removed constraint edges.
+    ///
+    /// # Handle invalidation
+    /// This method will invalidate all vertex, edge and face handles if any vertex is removed.
+    pub fn remove_constraint_with_id(&mut self, id: u32, remove_steiner_vertices: bool) -> usize {
+        let edges = self.constraint_edges_of(id);
+        self.remove_constraint_edges_and_steiner_vertices(edges, remove_steiner_vertices)
+    }
+
+    /// Expects edges that form one or more chains.
+    fn remove_constraint_edges_and_steiner_vertices(
+        &mut self,
+        edges: Vec<FixedDirectedEdgeHandle>,
+        remove_steiner_vertices: bool,
+    ) -> usize {
+        // Vertex handles become invalid once the first vertex is removed. Steiner vertices are
+        // identified by their position instead.
+        let mut steiner_vertices = Vec::new();
+        if remove_steiner_vertices {
+            let mut handles = edges
+                .iter()
+                .flat_map(|edge| self.directed_edge(*edge).vertices())
+                .map(|vertex| vertex.fix())
+                .filter(|vertex| self.steiner_vertices.contains(vertex))
+                .collect::<Vec<_>>();
+            handles.sort();
+            handles.dedup();
+            steiner_vertices.extend(
+                handles
+                    .into_iter()
+                    .map(|vertex| self.vertex(vertex).position()),
+            );
+        }
+
+        let mut num_removed_edges = 0;
+        for edge in edges {
+            if self.remove_constraint_edge(edge.as_undirected()) {
+                num_removed_edges += 1;
+            }
+        }
+
+        for position in steiner_vertices {
+            let Some(vertex) = self.locate_vertex(position).map(|vertex| vertex.fix()) else {
+                continue;
+            };
+            let num_constraint_edges = self
+                .vertex(vertex)
+                .out_edges()
+                .filter(|edge| edge.is_constraint_edge())
+                .count();
+
+            match num_constraint_edges {
+                0 => {
+                    self.remove(vertex);
+                }
+                // The vertex was created by intersecting the removed chain with another constraint
+                2 => {
+                    // The healed constraint can still be blocked in rare cases. The vertex is kept
+                    // in this case.
+                    let _ = self.remove_and_heal(vertex);
+                }
+                _ => {}
+            }
+        }
+        num_removed_edges
+    }
+
+    /// Bulk loads a constrained Delaunay triangulation from constraint edges that may intersect.
+    ///
+    /// Unlike [bulk_load_cdt](Self::bulk_load_cdt), intersecting or overlapping constraint edges
+    /// are allowed. Any intersection is resolved by splitting the intersecting edges at a new vertex,
+    /// created by `vertex_constructor`, just like [add_constraint_and_split](Self::add_constraint_and_split)
+    /// does.
+    ///
+    /// The edges are given as pairs of vertex indices. Returns the triangulation together with the
+    /// chain of constraint edges that each input edge has been split into. The chains are given in
+    /// the order of the input edges, each chain leads from the first to the second vertex of its
+    /// input edge. Chains of overlapping input edges share their common edges. The chain of an edge
+    /// that connects two vertices at the same position is empty.
+    ///
+    /// Duplicated vertices are handled like in [bulk_load_cdt](Self::bulk_load_cdt).
+    ///
+    /// Returns [ConstraintError::InvalidIndex] if any edge contains an index that is out of range.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::ConstraintError> {
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, Triangulation};
+    /// let vertices = vec![
+    ///     Point2::new(-1.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(0.0, -1.0),
+    ///     Point2::new(0.0, 1.0),
+    /// ];
+    /// // The two edges cross at the origin
+    /// let edges = vec![[0, 1], [2, 3]];
+    /// let (cdt, chains) =
+    ///     ConstrainedDelaunayTriangulation::<_>::bulk_load_cdt_with_intersections(vertices, edges, |v| v)?;
+    ///
+    /// assert_eq!(cdt.num_vertices(), 5);
+    /// assert_eq!(cdt.num_constraints(), 4);
+    /// assert_eq!(chains[0].len(), 2);
+    /// assert_eq!(cdt.directed_edge(chains[1][0]).to().position(), Point2::new(0.0, 0.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn bulk_load_cdt_with_intersections<C>(
+        vertices: Vec<V>,
+        edges: Vec<[usize; 2]>,
+        vertex_constructor: C,
+    ) -> Result<(Self, Vec<Vec<FixedDirectedEdgeHandle>>), ConstraintError>
+    where
+        C: Fn(Point2<<V as HasPosition>::Scalar>) -> V,
+    {
+        if let Some(&index) = edges
+            .iter()
+            .flatten()
+            .find(|&&index| index >= vertices.len())
+        {
+            return Err(ConstraintError::InvalidIndex(index));
+        }
+
+        let positions = vertices
+            .iter()
+            .map(|vertex| vertex.position())
+            .collect::<Vec<_>>();
+        let mut result = Self::bulk_load(vertices)?;
+
+        // Duplicates are merged by bulk loading. Map every input vertex to its remaining vertex.
+        let handles = positions
+            .into_iter()
+            .map(|position| {
+                result
+                    .locate_vertex(position)
+                    .map(|vertex| vertex.fix())
+                    .expect("Bulk loaded vertex could not be found")
+            })
+            .collect::<Vec<_>>();
+
+        // Edges returned by add_constraint_and_split can be split again by later edges.
+        let mut chains = ConstraintChains::new();
+        for [from, to] in edges {
+            let [from, to] = [handles[from], handles[to]];
+            result.add_constraint_and_split_into_chains(&mut chains, from, to, &vertex_constructor);
+        }
+
+        let chains = chains.into_edges(&result).collect();
+        Ok((result, chains))
+    }
+}
+
+/// A constraint edge, given by its two vertices, that has been split at a vertex.
+type ConstraintSplit = ([FixedVertexHandle; 2], FixedVertexHandle);
+
+/// Keeps track of the chains of constraint edges that constraints added with
+/// [ConstrainedDelaunayTriangulation::add_constraint_and_split_into_chains] have been split into.
+///
+/// Every chain is stored as a sequence of vertices. Each pair of consecutive vertices is connected
+/// by a constraint edge.
+#[derive(Debug, Default)]
+pub(crate) struct ConstraintChains {
+    chains: Vec<Vec<FixedVertexHandle>>,
+    /// Maps the (sorted) vertices of each constraint edge to the chains that contain it.
+    chains_by_edge: HashMap<[FixedVertexHandle; 2], Vec<usize>>,
+}
+
+impl ConstraintChains {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    fn key(mut edge: [FixedVertexHandle; 2]) -> [FixedVertexHandle; 2] {
+        edge.sort();
+        edge
+    }
+
+    fn push(&mut self, vertices: Vec<FixedVertexHandle>) {
+        let index = self.chains.len();
+        for edge in vertices.windows(2) {
+            self.chains_by_edge
+                .entry(Self::key([edge[0], edge[1]]))
+                .or_default()
+                .push(index);
+        }
+        self.chains.push(vertices);
+    }
+
+    fn split(&mut self, [from, to]: [FixedVertexHandle; 2], vertex: FixedVertexHandle) {
+        // Split edges that are not part of any chain belong to other constraints.
+        let Some(indices) = self.chains_by_edge.remove(&Self::key([from, to])) else {
+            return;
+        };
+
+        for &index in &indices {
+            let chain = &mut self.chains[index];
+            let position = chain
+                .windows(2)
+                .position(|edge| Self::key([edge[0], edge[1]]) == Self::key([from, to]))
+                .expect("Chain does not contain split edge");
+            chain.insert(position + 1, vertex);
+        }
+
+        for edge in [[from, vertex], [vertex, to]] {
+            self.chains_by_edge
+                .entry(Self::key(edge))
+                .or_default()
+                .extend(&indices);
+        }
+    }
+
+    /// Returns the constraint edges of all chains in the order in which they have been added.
+    pub(crate) fn into_edges<T: Triangulation>(
+        self,
+        triangulation: &T,
+    ) -> impl Iterator<Item = Vec<FixedDirectedEdgeHandle>> + '_ {
+        self.chains.into_iter().map(|chain| {
+            chain
+                .windows(2)
+                .map(|edge| {
+                    triangulation
+                        .get_edge_from_neighbors(edge[0], edge[1])
+                        .expect("Chain contains a missing edge")
+                        .fix()
+                })
+                .collect()
+        })
+    }
+}
+
+/// Describes all possible ways in which conflict regions which are created while adding a
+/// constraint edge may end.
+enum ConflictRegionEnd {
+    /// Conflict group ends with an existing vertex
+    Existing(FixedVertexHandle),
+    /// Special case of "Existing" - the constraint edge overlaps any existing edge which implies
+    /// that the conflict group also ends on an existing vertex.
+    /// However, it makes sense to handle this specially to prevent having to look up the overlapped
+    /// edge later.
+    EdgeOverlap(FixedDirectedEdgeHandle),
+}
+
+impl core::fmt::Debug for ConflictRegionEnd {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Existing(handle) => write!(f, "Existing({handle:?})"),
+            EdgeOverlap(edge) => write!(f, "EdgeOverlap({edge:?})"),
+        }
+    }
+}
+
+/// Represents a conflict region that does not yet fully exist as a vertex may be missing. This can
+/// happen if adding a constraint edge should split any intersecting existing edge.
+/// This will eventually be turned into a "real" conflict group (described as a list of edges) by
+/// inserting the missing vertex.
+struct InitialConflictRegion {
+    conflict_edges: Vec<FixedDirectedEdgeHandle>,
+    group_end: ConflictRegionEnd,
+}
+
+impl core::fmt::Debug for InitialConflictRegion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("InitialConflictRegion")
+            .field("conflict_edges", &self.conflict_edges)
+            .field("group_end", &self.group_end)
+            .finish()
+    }
+}
+
+/// A vertex at a grid point, see [ConstrainedDelaunayTriangulation::add_constraint_and_snap].
+struct SnappedVertex {
+    handle: FixedVertexHandle,
+    /// Constraint edges (given by their vertices) that need to be rerouted through the vertex.
+    rerouted: Vec<(FixedVertexHandle, FixedVertexHandle)>,
+}
+
+/// Inserts `vertex` between `from` and `to` if both are adjacent in the route.
+fn split_route(
+    route: &mut Vec<FixedVertexHandle>,
+    from: FixedVertexHandle,
+    to: FixedVertexHandle,
+    vertex: FixedVertexHandle,
+) {
+    let position = route
+        .windows(2)
+        .position(|pair| pair == [from, to] || pair == [to, from]);
+    if let Some(position) = position {
+        route.insert(position + 1, vertex);
+    }
+}
+
+pub fn get_edge_intersections<S: SpadeNum + Float>(
+    p1: Point2<S>,
+    p2: Point2<S>,
+    p3: Point2<S>,
+    p4: Point2<S>,
+) -> Point2<S> {
+    let p1 = p1.to_f64();
+    let p2 = p2.to_f64();
+    let p3 = p3.to_f64();
+    let p4 = p4.to_f64();
+
+    let a1 = p2.y - p1.y;
+    let b1 = p1.x - p2.x;
+    let c1 = a1 * p1.x + b1 * p1.y;
+
+    let a2 = p4.y - p3.y;
+    let b2 = p3.x - p4.x;
+    let c2 = a2 * p3.x + b2 * p3.y;
+
+    let determinant = a1 * b2 - a2 * b1;
+
+    let x: f64;
+    let y: f64;
+    if determinant == 0.0 {
+        x = f64::infinity();
+        y = f64::infinity();
+    } else {
+        x = (b2 * c1 - b1 * c2) / determinant;
+        y = (a1 * c2 - a2 * c1) / determinant;
+    }
+
+    [x, y]
+        .map(|s| <S as NumCast>::from(s).unwrap_or_else(|| (s as f32).into()))
+        .into()
+}
+
+#[cfg(test)]
 mod test {
     use approx::assert_abs_diff_eq;
     use proptest::prelude::*;
@@ -1298,9 +3013,9 @@ mod test {
     use crate::delaunay_core::{FixedDirectedEdgeHandle, TriangulationExt};
     use crate::handles::FixedVertexHandle;
     use crate::test_utilities::*;
-    use crate::{DelaunayTriangulation, InsertionError, Point2, Triangulation};
+    use crate::{DelaunayTriangulation, InsertionError, MoveVertexError, Point2, Triangulation};
 
-    use super::ConstrainedDelaunayTriangulation;
+    use super::{ConstrainedDelaunayTriangulation, ConstraintError, HashSet, SnapGrid};
 
     type Cdt = ConstrainedDelaunayTriangulation<Point2<f64>>;
     type Delaunay = DelaunayTriangulation<Point2<f64>>;
@@ -1631,20 +3346,144 @@ mod test {
         Ok(())
     }
 
-    fn random_points_on_line<R>(
-        range: i64,
-        num_points: usize,
-        rng: &mut R,
-        line_dir: Point2<f64>,
-    ) -> Vec<Point2<f64>>
-    where
-        R: Rng,
-    {
-        let mut result = Vec::with_capacity(num_points);
-        let range = Uniform::new(-range, range).unwrap();
-        for _ in 0..num_points {
-            let factor = range.sample(rng);
-            result.push(line_dir.mul(factor as f64));
+    #[test]
+    fn test_move_vertex() -> Result<(), MoveVertexError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(4.0, 0.0))?;
+        let v2 = cdt.insert(Point2::new(4.0, 4.0))?;
+        let v3 = cdt.insert(Point2::new(0.0, 4.0))?;
+        let v4 = cdt.insert(Point2::new(1.0, 1.0))?;
+        let v5 = cdt.insert(Point2::new(3.0, 3.0))?;
+        cdt.add_constraint(v0, v4);
+        cdt.add_constraint(v4, v5);
+        cdt.add_constraint(v1, v2);
+
+        // Local move
+        cdt.move_vertex(v4, Point2::new(1.5, 0.5))?;
+        cdt.cdt_sanity_check();
+        assert_eq!(cdt.vertex(v4).position(), Point2::new(1.5, 0.5));
+
+        // Moves the vertex outside of the convex hull
+        cdt.move_vertex(v4, Point2::new(-2.0, 2.0))?;
+        cdt.cdt_sanity_check();
+        assert_eq!(cdt.vertex(v4).position(), Point2::new(-2.0, 2.0));
+
+        // Moves a convex hull vertex with a constraint edge
+        cdt.move_vertex(v2, Point2::new(5.0, 5.0))?;
+        cdt.cdt_sanity_check();
+
+        assert_eq!(cdt.num_constraints(), 3);
+        assert!(cdt.exists_constraint(v0, v4));
+        assert!(cdt.exists_constraint(v4, v5));
+        assert!(cdt.exists_constraint(v1, v2));
+        assert_eq!(cdt.vertex(v3).position(), Point2::new(0.0, 4.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_vertex_checked() -> Result<(), ConstraintError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(4.0, 0.0))?;
+        let v2 = cdt.insert(Point2::new(1.0, 4.0))?;
+        let v3 = cdt.insert(Point2::new(4.0, 4.0))?;
+        let v4 = cdt.insert(Point2::new(1.0, 1.0))?;
+        let v5 = cdt.insert(Point2::new(2.0, 0.0))?;
+        cdt.add_constraint(v0, v4);
+        cdt.add_constraint_with_id(v4, v2, 7);
+        cdt.add_constraint(v1, v3);
+        cdt.add_constraint(v0, v1);
+
+        let unchanged = |cdt: &Cdt| {
+            cdt.cdt_sanity_check();
+            assert_eq!(cdt.num_constraints(), 5);
+            assert_eq!(cdt.vertex(v4).position(), Point2::new(1.0, 1.0));
+        };
+
+        // v4 -> v2 would cross v1 -> v3
+        let result = cdt.move_vertex_checked(v4, Point2::new(5.0, 1.0));
+        assert!(matches!(
+            result,
+            Err(ConstraintError::IntersectingConstraint(_))
+        ));
+        unchanged(&cdt);
+        assert_eq!(
+            cdt.move_vertex_checked(v4, Point2::new(4.0, 4.0)),
+            Err(ConstraintError::OccupiedPosition(v3))
+        );
+        assert_eq!(
+            cdt.move_vertex(v4, Point2::new(4.0, 4.0)),
+            Err(MoveVertexError::OccupiedPosition(v3))
+        );
+        assert_eq!(
+            cdt.move_vertex_checked(v4, Point2::new(f64::NAN, 0.0)),
+            Err(ConstraintError::InsertionError(InsertionError::NAN))
+        );
+        assert_eq!(
+            cdt.move_vertex_checked(FixedVertexHandle::from_index(10), Point2::new(1.0, 1.0)),
+            Err(ConstraintError::InvalidIndex(10))
+        );
+        unchanged(&cdt);
+
+        // Moves v4 onto the constraint edge v0 -> v1. The edge is split at v4.
+        cdt.move_vertex_checked(v4, Point2::new(1.0, 0.0))?;
+        cdt.cdt_sanity_check();
+        assert!(cdt.exists_constraint(v0, v4));
+        assert!(cdt.exists_constraint(v4, v5));
+        assert_eq!(
+            cdt.constraint_edges_of(7),
+            vec![cdt.get_edge_from_neighbors(v4, v2).unwrap().fix()]
+        );
+
+        // Moves v4 outside of the convex hull
+        cdt.move_vertex_checked(v4, Point2::new(-2.0, 1.0))?;
+        cdt.cdt_sanity_check();
+        assert_eq!(cdt.vertex(v4).position(), Point2::new(-2.0, 1.0));
+        assert!(cdt.exists_constraint(v0, v4));
+        assert_eq!(cdt.constraint_edges_of(7).len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let vertices = [
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(4.0, 4.0),
+            Point2::new(0.0, 4.0),
+            Point2::new(2.0, 2.0),
+        ]
+        .map(|point| cdt.insert(point).unwrap());
+        cdt.add_constraint(vertices[0], vertices[2]);
+        cdt.add_constraint(vertices[1], vertices[3]);
+        cdt.add_constraint(vertices[3], vertices[0]);
+        cdt.remove(vertices[4]);
+        assert_eq!(cdt.num_constraints(), 1);
+
+        let remapping = cdt.compact();
+        cdt.cdt_sanity_check();
+        assert_eq!(cdt.num_constraints(), 1);
+        let [v3, v0] = [vertices[3], vertices[0]].map(|v| remapping.vertex(v).unwrap());
+        assert!(cdt.exists_constraint(v3, v0));
+        Ok(())
+    }
+
+    fn random_points_on_line<R>(
+        range: i64,
+        num_points: usize,
+        rng: &mut R,
+        line_dir: Point2<f64>,
+    ) -> Vec<Point2<f64>>
+    where
+        R: Rng,
+    {
+        let mut result = Vec::with_capacity(num_points);
+        let range = Uniform::new(-range, range).unwrap();
+        for _ in 0..num_points {
+            let factor = range.sample(rng);
+            result.push(line_dir.mul(factor as f64));
         }
         result
     }
@@ -1714,6 +3553,30 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_move_vertex_panics_before_changes() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(1.0, 0.0))?;
+        let v2 = cdt.insert(Point2::new(2.0, -1.0))?;
+        let v3 = cdt.insert(Point2::new(2.0, 1.0))?;
+        cdt.add_constraint(v0, v1);
+        cdt.add_constraint(v2, v3);
+
+        // The constraint edge v0 -> v1 would cross v2 -> v3
+        let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+            cdt.move_vertex(v1, Point2::new(3.0, 0.0))
+        }));
+        assert!(result.is_err());
+        cdt.cdt_sanity_check();
+        assert_eq!(cdt.vertex(v1).position(), Point2::new(1.0, 0.0));
+        assert_eq!(cdt.num_constraints(), 2);
+        assert!(cdt.exists_constraint(v0, v1));
+        assert!(cdt.exists_constraint(v2, v3));
+        Ok(())
+    }
+
     #[test]
     #[should_panic]
     fn test_panic_when_intersecting_a_constraint_edge() {
@@ -1758,6 +3621,41 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_cdt_remove_with_stable_handles() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        cdt.set_stable_handles(true);
+        let vertices = random_points_with_seed(100, SEED)
+            .into_iter()
+            .map(|position| cdt.insert(position))
+            .collect::<Result<Vec<_>, _>>()?;
+        for pair in vertices[..20].chunks(2) {
+            cdt.try_add_constraint(pair[0], pair[1]);
+        }
+        let constraint_edges: Vec<_> = cdt
+            .undirected_edges()
+            .filter(|edge| edge.is_constraint_edge())
+            .map(|edge| (edge.fix(), edge.positions()))
+            .collect();
+        let num_constraints = cdt.num_constraints();
+        assert!(num_constraints > 0);
+
+        for vertex in &vertices[20..60] {
+            cdt.remove(*vertex);
+        }
+        cdt.cdt_sanity_check();
+        assert_eq!(cdt.num_constraints(), num_constraints);
+        for (edge, positions) in constraint_edges {
+            let edge = cdt.undirected_edge(edge);
+            assert!(edge.is_constraint_edge());
+            assert_eq!(edge.positions(), positions);
+        }
+        for vertex in &vertices[..20] {
+            assert!(cdt.vertex(*vertex).out_edge().is_some());
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_crash_scenario() -> Result<(), InsertionError> {
         let mut cdt = Cdt::new();
@@ -2073,6 +3971,916 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_remove_convex_hull_vertex_with_constraint() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        cdt.insert(Point2::new(-4.0, 0.0))?;
+        cdt.insert(Point2::new(4.0, 0.0))?;
+        let v0 = cdt.insert(Point2::new(0.0, -5.0))?;
+        let v1 = cdt.insert(Point2::new(0.0, 1.0))?;
+        cdt.add_constraint(v0, v1);
+
+        // Removing v0 flips the constraint edge which must not remain a constraint
+        cdt.remove(v0);
+        assert_eq!(cdt.num_constraints(), 0);
+        cdt.cdt_sanity_check();
+        Ok(())
+    }
+
+    fn check_constraint_chain(cdt: &Cdt, id: u32, from: FixedVertexHandle, to: FixedVertexHandle) {
+        let chain = cdt.constraint_edges_of(id);
+        assert!(!chain.is_empty());
+        check_returned_edges(cdt, &chain, from, to);
+        for edge in chain {
+            assert_eq!(cdt.constraint_id(edge.as_undirected()), Some(id));
+        }
+    }
+
+    #[test]
+    fn test_constraint_id_split_by_constraint() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(4.0, 0.0))?;
+        let v2 = cdt.insert(Point2::new(2.0, -2.0))?;
+        let v3 = cdt.insert(Point2::new(2.0, 2.0))?;
+        let v4 = cdt.insert(Point2::new(3.0, -2.0))?;
+        let v5 = cdt.insert(Point2::new(3.0, 2.0))?;
+
+        // The second constraint runs against the direction of the normalized edges
+        assert!(cdt.add_constraint_with_id(v1, v0, 7));
+        let edges = cdt.add_constraint_and_split_with_id(v3, v2, 3, |v| v);
+        assert_eq!(edges.len(), 2);
+        cdt.add_constraint_and_split(v4, v5, |v| v);
+
+        assert_eq!(cdt.constraint_edges_of(7).len(), 3);
+        check_constraint_chain(&cdt, 7, v1, v0);
+        check_constraint_chain(&cdt, 3, v3, v2);
+        assert!(cdt.constraint_edges_of(0).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_constraint_id_vertex_on_edge() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(0.0, 4.0))?;
+        cdt.insert(Point2::new(1.0, 2.0))?;
+        cdt.add_constraint_with_id(v0, v1, 1);
+        let v2 = cdt.insert(Point2::new(0.0, 1.0))?;
+        let v3 = cdt.insert(Point2::new(0.0, 3.0))?;
+
+        let chain = cdt.constraint_edges_of(1);
+        let vertices: Vec<_> = chain
+            .iter()
+            .map(|edge| cdt.directed_edge(*edge).from().fix())
+            .collect();
+        assert_eq!(vertices, [v0, v2, v3]);
+        check_constraint_chain(&cdt, 1, v0, v1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_constraint_id_refine() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let vertices = [
+            cdt.insert(Point2::new(0.0, 0.0))?,
+            cdt.insert(Point2::new(10.0, 0.0))?,
+            cdt.insert(Point2::new(10.0, 10.0))?,
+            cdt.insert(Point2::new(0.0, 10.0))?,
+        ];
+        for (id, (from, to)) in vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .enumerate()
+        {
+            cdt.add_constraint_with_id(*from, *to, id as u32);
+        }
+        cdt.refine(crate::RefinementParameters::new().with_max_allowed_area(1.0));
+
+        let mut num_edges = 0;
+        for (id, (from, to)) in vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .enumerate()
+        {
+            num_edges += cdt.constraint_edges_of(id as u32).len();
+            check_constraint_chain(&cdt, id as u32, *from, *to);
+        }
+        assert!(num_edges > 4);
+        assert_eq!(num_edges, cdt.num_constraints());
+        Ok(())
+    }
+
+    #[test]
+    fn test_constraint_id_loop() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(1.0, 0.0))?;
+        let v2 = cdt.insert(Point2::new(0.0, 1.0))?;
+        cdt.add_constraint_with_id(v0, v1, 5);
+        cdt.add_constraint_with_id(v1, v2, 5);
+        cdt.add_constraint_with_id(v2, v0, 5);
+
+        let chain = cdt.constraint_edges_of(5);
+        let vertices: Vec<_> = chain
+            .iter()
+            .map(|edge| cdt.directed_edge(*edge).from().fix())
+            .collect();
+        assert_eq!(vertices, [v0, v1, v2]);
+        assert_eq!(cdt.directed_edge(chain[2]).to().fix(), v0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_constraint_id_move_and_remove() -> Result<(), MoveVertexError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(3.0, 0.0))?;
+        let v2 = cdt.insert(Point2::new(1.0, 2.0))?;
+        cdt.insert(Point2::new(1.0, -2.0))?;
+        cdt.add_constraint_with_id(v1, v0, 2);
+        cdt.add_constraint(v0, v2);
+
+        cdt.move_vertex(v0, Point2::new(-1.0, 0.5))?;
+        check_constraint_chain(&cdt, 2, v1, v0);
+        let edge = cdt.get_edge_from_neighbors(v0, v2).unwrap().fix();
+        assert_eq!(cdt.constraint_id(edge.as_undirected()), None);
+
+        let edge = cdt.get_edge_from_neighbors(v0, v1).unwrap().fix();
+        cdt.remove_constraint_edge(edge.as_undirected());
+        assert_eq!(cdt.constraint_id(edge.as_undirected()), None);
+        assert!(cdt.constraint_edges_of(2).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_load_cdt_with_intersections() -> Result<(), ConstraintError> {
+        // A grid of horizontal and vertical lines, a diagonal and two overlapping edges
+        let mut vertices = Vec::new();
+        let mut edges = Vec::new();
+        for i in 0..5 {
+            let offset = i as f64 * 2.0 + 1.0;
+            vertices.push(Point2::new(offset, 0.0));
+            vertices.push(Point2::new(offset, 10.0));
+            vertices.push(Point2::new(0.0, offset));
+            vertices.push(Point2::new(10.0, offset));
+            edges.push([i * 4, i * 4 + 1]);
+            edges.push([i * 4 + 3, i * 4 + 2]);
+        }
+        vertices.push(Point2::new(0.5, 0.5));
+        vertices.push(Point2::new(9.5, 9.5));
+        vertices.push(Point2::new(1.0, 5.0));
+        vertices.push(Point2::new(1.0, 10.0)); // Duplicate
+        edges.push([20, 21]);
+        edges.push([22, 23]);
+        edges.push([21, 21]);
+
+        let (cdt, chains) =
+            Cdt::bulk_load_cdt_with_intersections(vertices.clone(), edges.clone(), |v| v)?;
+        cdt.cdt_sanity_check();
+        assert_eq!(chains.len(), edges.len());
+
+        // 25 grid crossings and 5 diagonal crossings (at existing vertices)
+        assert_eq!(cdt.num_vertices(), 4 * 5 + 2 + 25);
+        for (chain, [from, to]) in chains.iter().zip(edges) {
+            if from == to {
+                assert!(chain.is_empty());
+                continue;
+            }
+            let from = cdt.locate_vertex(vertices[from]).unwrap().fix();
+            let to = cdt.locate_vertex(vertices[to]).unwrap().fix();
+            check_returned_edges(&cdt, chain, from, to);
+            for edge in chain {
+                assert!(cdt.is_constraint_edge(edge.as_undirected()));
+            }
+        }
+
+        assert_eq!(chains[0].len(), 6);
+        assert_eq!(chains[1].len(), 6);
+        assert_eq!(chains[10].len(), 6);
+        assert_eq!(chains[11].len(), 3);
+
+        let result = Cdt::bulk_load_cdt_with_intersections(vertices, vec![[0, 1], [2, 30]], |v| v);
+        assert_eq!(result.err(), Some(ConstraintError::InvalidIndex(30)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_load_cdt_with_nearly_parallel_intersections() -> Result<(), ConstraintError> {
+        // Nearly parallel edges whose split vertices cannot be placed exactly on their lines,
+        // followed by a collinear chain of overlapping edges.
+        let vertices = vec![
+            Point2::new(0.0f32, 0.0),
+            Point2::new(10.0, 3.3),
+            Point2::new(4.908313, 1.6211311),
+            Point2::new(8.899735, 2.934443),
+            Point2::new(6.395058, 1.6293852),
+            Point2::new(6.065058, 2.6293852),
+            Point2::new(4.3604803, 1.4388149),
+            Point2::new(8.359718, 2.758853),
+            Point2::new(-10.0, -3.0),
+            Point2::new(-6.0, -3.0),
+            Point2::new(-2.0, -3.0),
+            Point2::new(2.0, -3.0),
+        ];
+        let edges = vec![[0, 1], [2, 3], [4, 5], [6, 7], [8, 10], [9, 11], [11, 8]];
+
+        let (cdt, chains) =
+            ConstrainedDelaunayTriangulation::<Point2<f32>>::bulk_load_cdt_with_intersections(
+                vertices.clone(),
+                edges.clone(),
+                |v| v,
+            )?;
+        assert_eq!(chains.len(), edges.len());
+
+        let mut chain_edges = HashSet::new();
+        for (chain, [from, to]) in chains.iter().zip(edges) {
+            let from = cdt.locate_vertex(vertices[from]).unwrap().fix();
+            let to = cdt.locate_vertex(vertices[to]).unwrap().fix();
+            check_returned_edges(&cdt, chain, from, to);
+            for edge in chain {
+                assert!(cdt.is_constraint_edge(edge.as_undirected()));
+                chain_edges.insert(edge.as_undirected());
+            }
+        }
+        // Every constraint edge belongs to at least one chain
+        assert_eq!(chain_edges.len(), cdt.num_constraints());
+        // The overlapping edges share their common part
+        let overlap = chains[6].iter().map(|edge| edge.rev()).collect::<Vec<_>>();
+        assert!(chains[4].iter().all(|edge| overlap.contains(edge)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_constraint_checked() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(-1.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(1.0, 0.0))?;
+        let v2 = cdt.insert(Point2::new(0.0, 1.0))?;
+        let v3 = cdt.insert(Point2::new(0.0, -1.0))?;
+
+        let edges = cdt.add_constraint_checked(v2, v3).unwrap();
+        assert_eq!(edges.len(), 1);
+        assert_eq!(
+            cdt.add_constraint_checked(v0, v1),
+            Err(ConstraintError::IntersectingConstraint(
+                edges[0].as_undirected()
+            ))
+        );
+        assert_eq!(
+            cdt.add_constraint_checked(v0, FixedVertexHandle::from_index(10)),
+            Err(ConstraintError::InvalidIndex(10))
+        );
+        assert_eq!(
+            cdt.add_constraint_checked(v1, v1),
+            Err(ConstraintError::ZeroLengthEdge)
+        );
+        assert_eq!(cdt.num_constraints(), 1);
+        cdt.cdt_sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_checked_constraint_variants() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(-1.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(1.0, 0.0))?;
+        let v2 = cdt.insert(Point2::new(0.0, 1.0))?;
+        let v3 = cdt.insert(Point2::new(0.0, -1.0))?;
+        let invalid = FixedVertexHandle::from_index(10);
+
+        let edges = cdt.add_constraint_with_id_checked(v2, v3, 3).unwrap();
+        assert_eq!(cdt.constraint_edges_of(3), edges);
+        assert_eq!(
+            cdt.add_constraint_with_id_checked(v0, v1, 4),
+            Err(ConstraintError::IntersectingConstraint(
+                edges[0].as_undirected()
+            ))
+        );
+        assert_eq!(
+            cdt.add_constraint_with_id_checked(v0, invalid, 4),
+            Err(ConstraintError::InvalidIndex(10))
+        );
+        assert!(cdt.constraint_edges_of(4).is_empty());
+
+        assert_eq!(
+            cdt.add_constraint_and_split_checked(invalid, v1, |v| v),
+            Err(ConstraintError::InvalidIndex(10))
+        );
+        assert_eq!(
+            cdt.add_constraint_and_split_with_id_checked(v0, v0, 5, |v| v),
+            Err(ConstraintError::ZeroLengthEdge)
+        );
+        assert_eq!(cdt.num_vertices(), 4);
+        assert_eq!(cdt.num_constraints(), 1);
+
+        let edges = cdt
+            .add_constraint_and_split_with_id_checked(v0, v1, 5, |v| v)
+            .unwrap();
+        assert_eq!(edges.len(), 2);
+        assert_eq!(cdt.constraint_edges_of(5), edges);
+        assert_eq!(cdt.num_constraints(), 4);
+        cdt.cdt_sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_constraint_edges_checked() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        cdt.add_constraint_edges_checked(
+            [
+                Point2::new(0.0, 0.0),
+                Point2::new(2.0, 0.0),
+                Point2::new(2.0, 2.0),
+            ],
+            true,
+        )
+        .unwrap();
+        assert_eq!(cdt.num_constraints(), 3);
+
+        let result = cdt.add_constraint_edge_checked(Point2::new(1.0, -1.0), Point2::new(1.0, 0.5));
+        assert!(matches!(
+            result,
+            Err(ConstraintError::IntersectingConstraint(_))
+        ));
+        assert_eq!(
+            cdt.add_constraint_edge_checked(Point2::new(5.0, 5.0), Point2::new(5.0, 5.0)),
+            Err(ConstraintError::ZeroLengthEdge)
+        );
+        assert_eq!(
+            cdt.add_constraint_edge_checked(Point2::new(0.0, 0.0), Point2::new(f64::NAN, 5.0)),
+            Err(ConstraintError::InsertionError(InsertionError::NAN))
+        );
+
+        let result = cdt.add_constraint_edges_checked(
+            [
+                Point2::new(3.0, 0.0),
+                Point2::new(3.0, 0.0),
+                Point2::new(4.0, 0.0),
+            ],
+            false,
+        );
+        assert_eq!(result, Err(ConstraintError::ZeroLengthEdge));
+        assert_eq!(cdt.num_constraints(), 3);
+        cdt.cdt_sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_load_cdt_checked() -> Result<(), InsertionError> {
+        let vertices = vec![
+            Point2::new(-1.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(0.0, -1.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(3.0, 0.0),
+            Point2::new(1.0, 0.0), // Duplicate
+        ];
+
+        // Overlapping edges are fine
+        let edges = vec![[0, 4], [1, 4], [2, 5], [0, 2], [2, 1]];
+        let cdt = Cdt::bulk_load_cdt_checked(vertices.clone(), edges.clone()).unwrap();
+        let expected = Cdt::bulk_load_cdt(vertices.clone(), vec![[0, 4], [2, 5], [0, 2]])?;
+        cdt.cdt_sanity_check();
+        assert_eq!(cdt.num_vertices(), 5);
+        assert_eq!(cdt.num_constraints(), 4);
+        assert_eq!(cdt.num_constraints(), expected.num_constraints());
+
+        let cdt = Cdt::bulk_load_cdt_stable_checked(vertices.clone(), edges).unwrap();
+        cdt.cdt_sanity_check();
+        assert_eq!(cdt.num_constraints(), 4);
+        assert_eq!(
+            cdt.vertices().map(|v| v.position()).collect::<Vec<_>>(),
+            vertices[..5]
+        );
+
+        let result = Cdt::bulk_load_cdt_checked(vertices.clone(), vec![[0, 2], [0, 4], [3, 2]]);
+        assert_eq!(
+            result.err(),
+            Some(ConstraintError::IntersectingInputEdges([1, 2]))
+        );
+        let result = Cdt::bulk_load_cdt_stable_checked(vertices.clone(), vec![[0, 6]]);
+        assert_eq!(result.err(), Some(ConstraintError::InvalidIndex(6)));
+        let result = Cdt::bulk_load_cdt_checked(vertices.clone(), vec![[1, 5]]);
+        assert_eq!(result.err(), Some(ConstraintError::ZeroLengthEdge));
+        let result = Cdt::bulk_load_cdt_checked(vec![Point2::new(f64::NAN, 0.0)], Vec::new());
+        assert_eq!(
+            result.err(),
+            Some(ConstraintError::InsertionError(InsertionError::NAN))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_and_heal() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        cdt.insert(Point2::new(1.0, 3.0))?;
+        cdt.insert(Point2::new(1.0, -3.0))?;
+        let v0 = cdt.insert(Point2::new(-2.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(0.0, 0.5))?;
+        let v2 = cdt.insert(Point2::new(3.0, 1.0))?;
+        let v3 = cdt.insert(Point2::new(5.0, 0.0))?;
+        cdt.add_constraint_with_id(v3, v2, 4);
+        cdt.add_constraint_with_id(v2, v1, 4);
+        cdt.add_constraint_with_id(v1, v0, 4);
+
+        // v3 is the last vertex and will be moved into the slot of v2
+        assert_eq!(cdt.remove_and_heal(v2), Ok(Point2::new(3.0, 1.0)));
+        let v3 = v2;
+        cdt.cdt_sanity_check();
+        assert_eq!(cdt.num_constraints(), 2);
+        assert!(cdt.exists_constraint(v3, v1));
+        let chain = cdt.constraint_edges_of(4);
+        let vertices: Vec<_> = chain
+            .iter()
+            .map(|edge| cdt.directed_edge(*edge).from().fix())
+            .collect();
+        assert_eq!(vertices, [v3, v1]);
+
+        assert_eq!(
+            cdt.remove_and_heal(v0),
+            Err(ConstraintError::InvalidConstraintDegree(1))
+        );
+        assert_eq!(
+            cdt.remove_and_heal(FixedVertexHandle::from_index(10)),
+            Err(ConstraintError::InvalidIndex(10))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_and_heal_blocked() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        cdt.set_stable_handles(true);
+        let v0 = cdt.insert(Point2::new(-2.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(0.0, 2.0))?;
+        let v2 = cdt.insert(Point2::new(2.0, 0.0))?;
+        let v3 = cdt.insert(Point2::new(0.0, 1.0))?;
+        let v4 = cdt.insert(Point2::new(0.0, -1.0))?;
+        cdt.add_constraint(v0, v1);
+        cdt.add_constraint(v1, v2);
+        cdt.add_constraint(v3, v4);
+        let blocking_edge = cdt.get_edge_from_neighbors(v3, v4).unwrap().fix();
+
+        assert_eq!(
+            cdt.remove_and_heal(v1),
+            Err(ConstraintError::IntersectingConstraint(
+                blocking_edge.as_undirected()
+            ))
+        );
+        assert_eq!(cdt.num_vertices(), 5);
+        assert_eq!(cdt.num_constraints(), 3);
+
+        // Unblock and retry. Handles are stable and remain valid.
+        cdt.remove(v4);
+        cdt.remove_and_heal(v1).unwrap();
+        cdt.cdt_sanity_check();
+        assert_eq!(cdt.num_constraints(), 1);
+        let edge = cdt.get_edge_from_neighbors(v0, v2).unwrap();
+        assert!(edge.is_constraint_edge());
+        assert_eq!(cdt.constraint_id(edge.fix().as_undirected()), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_constraint_chain_after_refinement() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let corners = [
+            Point2::new(0.0, 0.0),
+            Point2::new(10.0, 0.0),
+            Point2::new(10.0, 10.0),
+            Point2::new(0.0, 10.0),
+        ];
+        cdt.add_constraint_edges(corners, true)?;
+        // A second polyline that shares a corner. The chain must stop at the shared vertex.
+        cdt.add_constraint_edge(Point2::new(10.0, 10.0), Point2::new(12.0, 14.0))?;
+        cdt.refine(crate::RefinementParameters::new().with_max_allowed_area(2.0));
+        assert!(cdt.num_constraints() > 20);
+
+        let edge = cdt
+            .undirected_edges()
+            .find(|edge| edge.is_constraint_edge() && edge.positions()[0].y == 0.0)
+            .unwrap()
+            .fix();
+        let num_constraints = cdt.num_constraints();
+        let removed = cdt.remove_constraint_chain(edge, true);
+        assert_eq!(removed + cdt.num_constraints(), num_constraints);
+        // Refinement splits the convex hull at imprecise positions
+        cdt.cdt_sanity_check_with_params(false);
+        for edge in cdt
+            .undirected_edges()
+            .filter(|edge| edge.is_constraint_edge())
+        {
+            let [from, to] = edge.positions();
+            assert!(from.x > 10.0 || to.x > 10.0);
+        }
+
+        let on_boundary = cdt
+            .vertices()
+            .filter(|v| {
+                let p = v.position();
+                p.x == 0.0 || p.y == 0.0 || p.x == 10.0 || p.y == 10.0
+            })
+            .count();
+        assert_eq!(on_boundary, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_constraint_with_id() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(-2.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(2.0, 0.0))?;
+        let v2 = cdt.insert(Point2::new(-1.0, -1.0))?;
+        let v3 = cdt.insert(Point2::new(-1.0, 1.0))?;
+        let v4 = cdt.insert(Point2::new(1.0, 1.0))?;
+        let v5 = cdt.insert(Point2::new(1.0, -1.0))?;
+        cdt.add_constraint_with_id(v0, v1, 0);
+        cdt.add_constraint_and_split_with_id(v2, v3, 1, |v| v);
+        cdt.add_constraint_and_split_with_id(v3, v4, 1, |v| v);
+        cdt.add_constraint_and_split_with_id(v4, v5, 1, |v| v);
+        assert_eq!(cdt.num_vertices(), 8);
+
+        let mut without_steiner_removal = cdt.clone();
+        assert_eq!(
+            without_steiner_removal.remove_constraint_with_id(1, false),
+            5
+        );
+        assert_eq!(without_steiner_removal.num_vertices(), 8);
+        assert_eq!(without_steiner_removal.num_constraints(), 3);
+
+        assert_eq!(cdt.remove_constraint_with_id(1, true), 5);
+        cdt.cdt_sanity_check();
+        assert_eq!(cdt.num_vertices(), 6);
+        assert_eq!(cdt.num_constraints(), 1);
+        assert!(cdt.exists_constraint(v0, v1));
+        assert_eq!(cdt.constraint_edges_of(0).len(), 1);
+        assert_eq!(cdt.remove_constraint_with_id(1, true), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_constraint_chain_keeps_inserted_vertices() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        cdt.add_constraint_edges(
+            [
+                Point2::new(0.0, 0.0),
+                Point2::new(1.0, 0.0),
+                Point2::new(2.0, 0.0),
+            ],
+            false,
+        )?;
+        let edge = cdt
+            .undirected_edges()
+            .find(|edge| edge.is_constraint_edge());
+        assert_eq!(cdt.remove_constraint_chain(edge.unwrap().fix(), true), 2);
+        assert_eq!(cdt.num_vertices(), 3);
+        assert_eq!(cdt.num_constraints(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_snapped_steiner_vertices() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(3.0, 1.0))?;
+        let v2 = cdt.insert(Point2::new(1.0, -1.0))?;
+        let v3 = cdt.insert(Point2::new(2.0, 2.0))?;
+        cdt.add_constraint(v0, v1);
+        // The intersection at (1.5, 0.5) is snapped to (2.0, 1.0) which lies on neither line
+        let edges = cdt.add_constraint_and_snap(v2, v3, SnapGrid::integer(), |v| v);
+        assert_eq!(cdt.num_vertices(), 5);
+        assert!(cdt.locate_vertex(Point2::new(2.0, 1.0)).is_some());
+
+        assert_eq!(
+            cdt.remove_constraint_chain(edges[0].as_undirected(), true),
+            2
+        );
+        cdt.cdt_sanity_check();
+        assert_eq!(cdt.num_vertices(), 4);
+        assert_eq!(cdt.num_constraints(), 1);
+        assert!(cdt.exists_constraint(v0, v1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_steiner_vertices_survive_handle_changes() -> Result<(), InsertionError> {
+        for stable_handles in [false, true] {
+            let mut cdt = Cdt::new();
+            cdt.set_stable_handles(stable_handles);
+            let extra = cdt.insert(Point2::new(5.0, 5.0))?;
+            let v0 = cdt.insert(Point2::new(-1.0, 0.0))?;
+            let v1 = cdt.insert(Point2::new(1.0, 0.0))?;
+            let v2 = cdt.insert(Point2::new(0.0, -1.0))?;
+            let v3 = cdt.insert(Point2::new(0.0, 1.0))?;
+            cdt.add_constraint(v0, v1);
+            cdt.add_constraint_and_split(v2, v3, |v| v);
+
+            // Swaps the Steiner vertex into the first slot or leaves a gap. The next vertex
+            // takes the Steiner vertex' old slot or the gap.
+            cdt.remove(extra);
+            cdt.insert(Point2::new(0.0, 0.5))?;
+            cdt.set_stable_handles(false);
+            cdt.compact();
+            assert_eq!(cdt.num_vertices(), 6);
+
+            let edge = cdt
+                .undirected_edges()
+                .find(|edge| edge.is_constraint_edge() && edge.positions()[0].x == 0.0)
+                .unwrap()
+                .fix();
+            assert_eq!(cdt.remove_constraint_chain(edge, true), 3);
+            cdt.cdt_sanity_check();
+            assert_eq!(cdt.num_vertices(), 5);
+            assert_eq!(cdt.num_constraints(), 1);
+            assert!(cdt.locate_vertex(Point2::new(0.0, 0.0)).is_none());
+            assert!(cdt.locate_vertex(Point2::new(0.0, 0.5)).is_some());
+        }
+        Ok(())
+    }
+
+    fn check_snapped_edges(
+        cdt: &Cdt,
+        edges: &[FixedDirectedEdgeHandle],
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+    ) {
+        cdt.cdt_sanity_check();
+        let mut current = from;
+        for edge in edges {
+            let edge = cdt.directed_edge(*edge);
+            assert!(edge.is_constraint_edge());
+            assert_eq!(edge.from().fix(), current);
+            current = edge.to().fix();
+        }
+        assert_eq!(current, to);
+    }
+
+    fn is_connected_by_constraints(
+        cdt: &Cdt,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+    ) -> bool {
+        let mut visited = vec![from];
+        let mut stack = vec![from];
+        while let Some(vertex) = stack.pop() {
+            for edge in cdt.vertex(vertex).out_edges() {
+                let next = edge.to().fix();
+                if edge.is_constraint_edge() && !visited.contains(&next) {
+                    visited.push(next);
+                    stack.push(next);
+                }
+            }
+        }
+        visited.contains(&to)
+    }
+
+    #[test]
+    fn test_snap_grid() {
+        let grid = SnapGrid::decimals(1);
+        assert_eq!(grid.snap(Point2::new(0.34, -1.26)), Point2::new(0.3, -1.3));
+        assert_eq!(
+            SnapGrid::new(0.5).snap(Point2::new(0.8, 1.2)),
+            Point2::new(1.0, 1.0)
+        );
+
+        let grid = SnapGrid::integer();
+        let center = Point2::new(2.0, 2.0);
+        assert!(grid.segment_intersects_pixel(
+            Point2::new(0.0, 1.5),
+            Point2::new(4.0, 1.5),
+            center
+        ));
+        assert!(grid.segment_intersects_pixel(
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 3.0),
+            center
+        ));
+        assert!(!grid.segment_intersects_pixel(
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 1.0),
+            center
+        ));
+        assert!(!grid.segment_intersects_pixel(
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 2.0),
+            center
+        ));
+    }
+
+    #[test]
+    fn test_add_constraint_and_snap() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(10.0, 3.0))?;
+        let v2 = cdt.insert(Point2::new(0.0, 3.0))?;
+        let v3 = cdt.insert(Point2::new(10.0, 0.0))?;
+        assert!(cdt.add_constraint_with_id(v0, v1, 4));
+
+        let edges = cdt.add_constraint_and_snap(v2, v3, SnapGrid::integer(), |v| v);
+        check_snapped_edges(&cdt, &edges, v2, v3);
+        assert_eq!(edges.len(), 2);
+        let split = cdt.directed_edge(edges[0]).to();
+        assert_eq!(split.position(), Point2::new(5.0, 2.0));
+
+        // The intersected constraint is rerouted and keeps its ID
+        let chain = cdt.constraint_edges_of(4);
+        check_snapped_edges(&cdt, &chain, v0, v1);
+        assert_eq!(chain.len(), 2);
+        assert_eq!(cdt.directed_edge(chain[0]).to(), split);
+        assert_eq!(cdt.num_constraints(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_constraint_and_snap_hot_pixel() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(9.0, 2.0))?;
+        let v2 = cdt.insert(Point2::new(5.0, 1.0))?;
+
+        // The line passes (5.0, 1.11) and is routed through v2
+        let edges = cdt.add_constraint_and_snap(v0, v1, SnapGrid::integer(), |v| v);
+        check_snapped_edges(&cdt, &edges, v0, v1);
+        assert_eq!(edges.len(), 2);
+        assert_eq!(cdt.directed_edge(edges[0]).to().fix(), v2);
+        assert_eq!(cdt.num_vertices(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_constraint_and_snap_repeated() -> Result<(), InsertionError> {
+        // Many nearly concurrent lines through the center of a circle
+        let mut cdt = Cdt::new();
+        let grid = SnapGrid::decimals(2);
+        let num_lines = 12;
+        let mut constraints = Vec::new();
+        for index in 0..num_lines {
+            let angle = index as f64 * core::f64::consts::PI / num_lines as f64 + 0.01;
+            let (sin, cos) = angle.sin_cos();
+            let [from, to] = [1.0, -1.0].map(|sign| {
+                grid.snap(Point2::new(
+                    5.003 + sign * 3.0 * cos,
+                    4.998 + sign * 3.0 * sin,
+                ))
+            });
+            let from = cdt.insert(from)?;
+            let to = cdt.insert(to)?;
+            let edges = cdt.add_constraint_and_snap(from, to, grid, |v| v);
+            check_snapped_edges(&cdt, &edges, from, to);
+            constraints.push((from, to));
+        }
+
+        for vertex in cdt.vertices() {
+            let position = vertex.position();
+            assert_eq!(grid.snap(position), position);
+        }
+
+        // All lines still connect their end points
+        for (from, to) in constraints {
+            assert!(is_connected_by_constraints(&cdt, from, to));
+        }
+        // Instead of one vertex per pair of lines, only a few vertices are created near the center
+        assert!(cdt.num_vertices() < 2 * num_lines + 6);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_deserialize_without_constraint_ids() {
+        // A triangulation with a single constraint edge, serialized by spade 2.13
+        let json = r#"
+            {"dcel":{"vertices":[{"data":{"x":0.0,"y":0.0},"out_edge":{"index":0,"ty":null,"inner_outer":null}},
+            {"data":{"x":1.0,"y":0.0},"out_edge":{"index":1,"ty":null,"inner_outer":null}},{"data":{"x":0.0,
+            "y":1.0},"out_edge":{"index":4,"ty":null,"inner_outer":null}}],"faces":[{"adjacent_edge":{"index":5,
+            "ty":null,"inner_outer":null},"data":null},{"adjacent_edge":{"index":0,"ty":null,
+            "inner_outer":null},"data":null}],"edges":[{"entries":[{"next":{"index":2,"ty":null,
+            "inner_outer":null},"prev":{"index":4,"ty":null,"inner_outer":null},"face":{"index":1,
+            "ty":null,"inner_outer":null},"origin":{"index":0,"ty":null,"inner_outer":null}},
+            {"next":{"index":5,"ty":null,"inner_outer":null},"prev":{"index":3,"ty":null,"inner_outer":null},
+            "face":{"index":0,"ty":null,"inner_outer":null},"origin":{"index":1,"ty":null,"inner_outer":null}}],
+            "directed_data":[null,null],"undirected_data":[true,null]},{"entries":[{"next":{"index":4,
+            "ty":null,"inner_outer":null},"prev":{"index":0,"ty":null,"inner_outer":null},"face":{"index":1,
+            "ty":null,"inner_outer":null},"origin":{"index":1,"ty":null,"inner_outer":null}},
+            {"next":{"index":1,"ty":null,"inner_outer":null},"prev":{"index":5,"ty":null,"inner_outer":null},
+            "face":{"index":0,"ty":null,"inner_outer":null},"origin":{"index":2,"ty":null,"inner_outer":null}}],
+            "directed_data":[null,null],"undirected_data":[false,null]},{"entries":[{"next":{"index":0,
+            "ty":null,"inner_outer":null},"prev":{"index":2,"ty":null,"inner_outer":null},"face":{"index":1,
+            "ty":null,"inner_outer":null},"origin":{"index":2,"ty":null,"inner_outer":null}},
+            {"next":{"index":3,"ty":null,"inner_outer":null},"prev":{"index":1,"ty":null,"inner_outer":null},
+            "face":{"index":0,"ty":null,"inner_outer":null},"origin":{"index":0,"ty":null,"inner_outer":null}}],
+            "directed_data":[null,null],"undirected_data":[false,null]}]},"num_constraints":1,
+            "hint_generator":{}}
+        "#;
+        let cdt: Cdt = serde_json::from_str(json).unwrap();
+        cdt.cdt_sanity_check();
+        assert_eq!(cdt.num_constraints(), 1);
+        let edge = cdt.get_edge_from_neighbors(
+            FixedVertexHandle::from_index(0),
+            FixedVertexHandle::from_index(1),
+        );
+        let edge = edge.unwrap();
+        assert!(edge.is_constraint_edge());
+        assert_eq!(edge.as_undirected().data().constraint_id(), None);
+
+        // Triangulations without constraint IDs and steiner vertices are serialized as before
+        let json = json.split_whitespace().collect::<alloc::string::String>();
+        assert_eq!(serde_json::to_string(&cdt).unwrap(), json);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_constraint_ids() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(1.0, 0.0))?;
+        let v2 = cdt.insert(Point2::new(0.0, 1.0))?;
+        cdt.add_constraint(v0, v1);
+        cdt.add_constraint_with_id(v2, v1, 7);
+
+        let constraint_ids = |cdt: &Cdt| {
+            cdt.undirected_edges()
+                .map(|edge| (edge.is_constraint_edge(), edge.data().constraint_id()))
+                .collect::<Vec<_>>()
+        };
+        let expected = constraint_ids(&cdt);
+        assert!(expected.contains(&(true, Some(7))));
+
+        // Self-describing formats omit missing constraint IDs
+        let json = serde_json::to_string(&cdt).unwrap();
+        assert!(json.contains(r#""undirected_data":[true,null]"#));
+        assert!(json.contains(r#""undirected_data":[false,null]"#));
+        let deserialized: Cdt = serde_json::from_str(&json).unwrap();
+        assert_eq!(constraint_ids(&deserialized), expected);
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+
+        // Other formats always contain all fields
+        let bytes = bincode::serialize(&cdt).unwrap();
+        let deserialized: Cdt = bincode::deserialize(&bytes).unwrap();
+        deserialized.cdt_sanity_check();
+        assert_eq!(constraint_ids(&deserialized), expected);
+        assert_eq!(bincode::serialize(&deserialized).unwrap(), bytes);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_steiner_vertices() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+        let v1 = cdt.insert(Point2::new(4.0, 0.0))?;
+        let v2 = cdt.insert(Point2::new(2.0, -2.0))?;
+        let v3 = cdt.insert(Point2::new(2.0, 2.0))?;
+        cdt.add_constraint(v0, v1);
+        assert!(!serde_json::to_string(&cdt)
+            .unwrap()
+            .contains("steiner_vertices"));
+
+        let edges = cdt.add_constraint_and_split(v2, v3, |v| v);
+        let json = serde_json::to_string(&cdt).unwrap();
+        assert!(json.contains("steiner_vertices"));
+        let deserialized: Cdt = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+
+        let bytes = bincode::serialize(&cdt).unwrap();
+        let mut deserialized: Cdt = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(bincode::serialize(&deserialized).unwrap(), bytes);
+
+        // The intersection is still known to be a steiner vertex
+        deserialized.remove_constraint_chain(edges[0].as_undirected(), true);
+        assert_eq!(deserialized.num_vertices(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_constraint_and_snap_off_grid() -> Result<(), ConstraintError> {
+        // Segment end points that don't lie on the grid used to route segments back and forth
+        // between two hot vertices forever.
+        let mut state = 185u64;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64 * 20.0
+        };
+
+        let mut cdt = Cdt::new();
+        let grid = SnapGrid::decimals(1);
+        for _ in 0..40 {
+            let from = cdt.insert(Point2::new(next(), next()))?;
+            let to = cdt.insert(Point2::new(next(), next()))?;
+            let edges = cdt.add_constraint_and_snap_checked(from, to, grid, |v| v)?;
+            check_snapped_edges(&cdt, &edges, from, to);
+        }
+
+        let v0 = FixedVertexHandle::from_index(0);
+        assert_eq!(
+            cdt.add_constraint_and_snap_checked(v0, v0, grid, |v| v),
+            Err(ConstraintError::ZeroLengthEdge)
+        );
+        Ok(())
+    }
+
     #[test]
     fn edge_intersection_precision_test_2() -> Result<(), InsertionError> {
         let edges = [
@@ -0,0 +1,327 @@
+use alloc::vec::Vec;
+
+use num_traits::Float;
+
+use crate::{ConstrainedDelaunayTriangulation, HasPosition, InsertionError, Point2, Triangulation};
+
+use super::{DirectedEdgeHandle, FillRule};
+
+/// The set operation that is applied by [boolean_operation].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BooleanOperation {
+    /// Covers all areas that are covered by the subject or the clip polygons.
+    Union,
+    /// Covers all areas that are covered by both the subject and the clip polygons.
+    Intersection,
+    /// Covers all areas that are covered by the subject but not by the clip polygons.
+    Difference,
+    /// Covers all areas that are covered by exactly one of the subject and clip polygons.
+    Xor,
+}
+
+impl BooleanOperation {
+    fn is_inside(self, subject: bool, clip: bool) -> bool {
+        match self {
+            BooleanOperation::Union => subject || clip,
+            BooleanOperation::Intersection => subject && clip,
+            BooleanOperation::Difference => subject && !clip,
+            BooleanOperation::Xor => subject != clip,
+        }
+    }
+}
+
+/// A set of (possibly overlapping) polygons with holes that serves as input for
+/// [boolean_operation].
+///
+/// The rings are interpreted in the same way as by
+/// [ConstrainedDelaunayTriangulation::from_polygons].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct PolygonSet<V> {
+    /// The outer rings. The last vertex of a ring is connected to its first vertex.
+    pub outer_rings: Vec<Vec<V>>,
+    /// The holes. The last vertex of a ring is connected to its first vertex.
+    pub holes: Vec<Vec<V>>,
+    /// Determines which areas are covered by the polygon set.
+    pub fill_rule: FillRule,
+}
+
+impl<V> PolygonSet<V> {
+    /// Creates a new polygon set.
+    pub fn new(outer_rings: Vec<Vec<V>>, holes: Vec<Vec<V>>, fill_rule: FillRule) -> Self {
+        Self {
+            outer_rings,
+            holes,
+            fill_rule,
+        }
+    }
+}
+
+/// The result of a [boolean_operation].
+pub struct BooleanResult<V: HasPosition> {
+    /// The boundary rings of the result.
+    ///
+    /// Rings which enclose covered area are counterclockwise, rings which enclose holes are
+    /// clockwise. The covered area is always on the left side of a ring. Rings that touch each
+    /// other in a single vertex are reported as separate rings.
+    pub rings: Vec<Vec<Point2<V::Scalar>>>,
+    /// A triangulation of the result.
+    ///
+    /// Its constraint edges are exactly the edges of [rings](BooleanResult::rings). Use
+    /// [interior_faces](ConstrainedDelaunayTriangulation::interior_faces) to iterate over all
+    /// faces that are covered by the result.
+    pub triangulation: ConstrainedDelaunayTriangulation<V>,
+}
+
+/// Applies a boolean operation to two polygon sets.
+///
+/// Both polygon sets are inserted into a single constrained Delaunay triangulation. Intersecting
+/// ring edges are split with a vertex created by `V::from`. Every face is then classified by the
+/// fill rules of both polygon sets and the given operation. Only constraint edges that separate
+/// covered from uncovered faces are kept.
+///
+/// Returns an error if any vertex has an invalid position.
+///
+/// # Example
+/// ```
+/// # fn main() -> Result<(), spade::InsertionError> {
+/// use spade::boolean::{boolean_operation, BooleanOperation, PolygonSet};
+/// use spade::{FillRule, Point2};
+///
+/// let square = |min: f64, max: f64| {
+///     vec![
+///         Point2::new(min, min),
+///         Point2::new(max, min),
+///         Point2::new(max, max),
+///         Point2::new(min, max),
+///     ]
+/// };
+///
+/// let subject = PolygonSet::new(vec![square(0.0, 2.0)], vec![], FillRule::NonZero);
+/// let clip = PolygonSet::new(vec![square(1.0, 3.0)], vec![], FillRule::NonZero);
+///
+/// let result = boolean_operation(subject, clip, BooleanOperation::Intersection)?;
+/// assert_eq!(result.rings.len(), 1);
+/// assert_eq!(result.rings[0].len(), 4);
+///
+/// let area: f64 = result.triangulation.interior_faces().map(|face| face.area()).sum();
+/// assert_eq!(area, 1.0);
+/// # Ok(()) }
+/// ```
+pub fn boolean_operation<V>(
+    subject: PolygonSet<V>,
+    clip: PolygonSet<V>,
+    operation: BooleanOperation,
+) -> Result<BooleanResult<V>, InsertionError>
+where
+    V: HasPosition + From<Point2<<V as HasPosition>::Scalar>>,
+    <V as HasPosition>::Scalar: Float,
+{
+    let mut triangulation = ConstrainedDelaunayTriangulation::<V>::new();
+
+    let rings = subject
+        .outer_rings
+        .into_iter()
+        .map(|ring| (ring, [1, 0]))
+        .chain(subject.holes.into_iter().map(|ring| (ring, [-1, 0])))
+        .chain(clip.outer_rings.into_iter().map(|ring| (ring, [0, 1])))
+        .chain(clip.holes.into_iter().map(|ring| (ring, [0, -1])));
+    let winding_numbers = triangulation.insert_rings(rings)?;
+
+    let is_inside = winding_numbers
+        .into_iter()
+        .map(|[subject_winding, clip_winding]| {
+            operation.is_inside(
+                subject.fill_rule.is_inside(subject_winding),
+                clip.fill_rule.is_inside(clip_winding),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    triangulation.remove_non_separating_constraints(|face| is_inside[face.index()]);
+
+    let rings = extract_rings(&triangulation, &is_inside);
+
+    Ok(BooleanResult {
+        rings,
+        triangulation,
+    })
+}
+
+/// Walks along all constraint edges that have a covered face on their left side and an uncovered
+/// face on their right side.
+fn extract_rings<V>(
+    triangulation: &ConstrainedDelaunayTriangulation<V>,
+    is_inside: &[bool],
+) -> Vec<Vec<Point2<V::Scalar>>>
+where
+    V: HasPosition,
+{
+    let is_boundary = |edge: DirectedEdgeHandle<V, _, _, _>| {
+        is_inside[edge.face().fix().index()] && !is_inside[edge.rev().face().fix().index()]
+    };
+
+    let mut visited = alloc::vec![false; triangulation.num_directed_edges()];
+    let mut rings = Vec::new();
+
+    for start in triangulation.directed_edges() {
+        if visited[start.fix().index()] || !is_boundary(start) {
+            continue;
+        }
+
+        let mut ring = Vec::new();
+        let mut current = start;
+        loop {
+            visited[current.fix().index()] = true;
+            ring.push(current.from().position());
+
+            // Rotate around the target vertex through covered faces until the next boundary edge
+            // is found. This splits rings that touch each other in a single vertex.
+            let mut next = current.next();
+            while !is_boundary(next) {
+                next = next.rev().next();
+            }
+
+            current = next;
+            if current == start {
+                break;
+            }
+        }
+        rings.push(ring);
+    }
+
+    rings
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use crate::test_utilities::square;
+    use crate::{FillRule, InsertionError, Point2};
+
+    use super::{boolean_operation, BooleanOperation, BooleanResult, PolygonSet};
+
+    fn ring_area(ring: &[Point2<f64>]) -> f64 {
+        super::super::polygon_fill::signed_area(ring)
+    }
+
+    fn check_result(result: &BooleanResult<Point2<f64>>, expected_area: f64) {
+        result.triangulation.cdt_sanity_check();
+
+        let area: f64 = result
+            .triangulation
+            .interior_faces()
+            .map(|face| face.area())
+            .sum();
+        assert_eq!(area, expected_area);
+
+        let ring_areas: f64 = result.rings.iter().map(|ring| ring_area(ring)).sum();
+        assert_eq!(ring_areas, expected_area);
+
+        let ring_edges: usize = result.rings.iter().map(|ring| ring.len()).sum();
+        assert_eq!(ring_edges, result.triangulation.num_constraints());
+    }
+
+    fn overlapping_squares(
+        operation: BooleanOperation,
+    ) -> Result<BooleanResult<Point2<f64>>, InsertionError> {
+        boolean_operation(
+            PolygonSet::new(alloc::vec![square(0.0, 2.0)], Vec::new(), FillRule::NonZero),
+            PolygonSet::new(alloc::vec![square(1.0, 3.0)], Vec::new(), FillRule::NonZero),
+            operation,
+        )
+    }
+
+    #[test]
+    fn test_overlapping_squares() -> Result<(), InsertionError> {
+        let union = overlapping_squares(BooleanOperation::Union)?;
+        check_result(&union, 7.0);
+        assert_eq!(union.rings.len(), 1);
+        assert_eq!(union.rings[0].len(), 8);
+
+        let intersection = overlapping_squares(BooleanOperation::Intersection)?;
+        check_result(&intersection, 1.0);
+        assert_eq!(intersection.rings.len(), 1);
+        assert_eq!(intersection.rings[0].len(), 4);
+
+        let difference = overlapping_squares(BooleanOperation::Difference)?;
+        check_result(&difference, 3.0);
+        assert_eq!(difference.rings.len(), 1);
+        assert_eq!(difference.rings[0].len(), 6);
+
+        // Both L-shaped parts touch each other at (1, 2) and (2, 1)
+        let xor = overlapping_squares(BooleanOperation::Xor)?;
+        check_result(&xor, 6.0);
+        assert_eq!(xor.rings.len(), 2);
+        for ring in &xor.rings {
+            assert_eq!(ring.len(), 6);
+            assert_eq!(ring_area(ring), 3.0);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_difference_creates_hole() -> Result<(), InsertionError> {
+        let result = boolean_operation(
+            PolygonSet::new(
+                alloc::vec![square(0.0, 10.0)],
+                Vec::new(),
+                FillRule::EvenOdd,
+            ),
+            PolygonSet::new(alloc::vec![square(4.0, 6.0)], Vec::new(), FillRule::EvenOdd),
+            BooleanOperation::Difference,
+        )?;
+        check_result(&result, 96.0);
+
+        let mut areas = result
+            .rings
+            .iter()
+            .map(|ring| ring_area(ring))
+            .collect::<Vec<_>>();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(areas, [-4.0, 100.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_rules_and_holes() -> Result<(), InsertionError> {
+        // The subject covers [0, 4]² without [1, 3]², the clip covers [2, 6]x[0, 4] twice.
+        let clip_ring = alloc::vec![
+            Point2::new(2.0, 0.0),
+            Point2::new(6.0, 0.0),
+            Point2::new(6.0, 4.0),
+            Point2::new(2.0, 4.0),
+        ];
+        let subject = PolygonSet::new(
+            alloc::vec![square(0.0, 4.0)],
+            alloc::vec![square(1.0, 3.0)],
+            FillRule::NonZero,
+        );
+
+        let result = boolean_operation(
+            subject.clone(),
+            PolygonSet::new(
+                alloc::vec![clip_ring.clone(), clip_ring.clone()],
+                Vec::new(),
+                FillRule::NonZero,
+            ),
+            BooleanOperation::Intersection,
+        )?;
+        check_result(&result, 6.0);
+
+        // With the even-odd rule, both clip rings cancel out each other.
+        let result = boolean_operation(
+            subject,
+            PolygonSet::new(
+                alloc::vec![clip_ring.clone(), clip_ring],
+                Vec::new(),
+                FillRule::EvenOdd,
+            ),
+            BooleanOperation::Intersection,
+        )?;
+        check_result(&result, 0.0);
+        assert!(result.rings.is_empty());
+
+        Ok(())
+    }
+}
@@ -8,9 +8,25 @@ fn fuzz_test(vertices: Vec<Point2<f64>>) {
     clone.dedup();
     let expected_size = clone.len();
 
+    #[cfg(feature = "rayon")]
+    let parallel = DelaunayTriangulation::<_>::bulk_load_parallel(vertices.clone()).unwrap();
+
     let triangulation = DelaunayTriangulation::<_>::bulk_load(vertices).unwrap();
     triangulation.sanity_check();
     assert_eq!(triangulation.num_vertices(), expected_size);
+
+    #[cfg(feature = "rayon")]
+    {
+        // The triangulation is not unique if vertices are cocircular. The number of edges and faces
+        // only depends on the convex hull and must be identical.
+        parallel.sanity_check();
+        assert_eq!(parallel.num_vertices(), expected_size);
+        assert_eq!(
+            parallel.num_undirected_edges(),
+            triangulation.num_undirected_edges()
+        );
+        assert_eq!(parallel.num_inner_faces(), triangulation.num_inner_faces());
+    }
 }
 
 #[test]
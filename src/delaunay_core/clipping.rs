@@ -0,0 +1,314 @@
+use alloc::vec::Vec;
+use num_traits::Float;
+
+use crate::{handles::FixedVertexHandle, Point2, SpadeNum};
+
+/// Describes the convex area that Voronoi cells are clipped to.
+///
+/// *See [FloatTriangulation::voronoi_cells_clipped](crate::FloatTriangulation::voronoi_cells_clipped)*
+#[derive(Debug, Clone, PartialEq)]
+pub enum CellBounds<S> {
+    /// An axis aligned rectangle, given by its lower and upper corner.
+    Rectangle {
+        /// The rectangle's corner with the smallest coordinates.
+        lower: Point2<S>,
+        /// The rectangle's corner with the largest coordinates.
+        upper: Point2<S>,
+    },
+
+    /// A convex polygon, given by its vertices in clockwise or counterclockwise order.
+    ///
+    /// The clipping result is unspecified if the polygon is not convex.
+    ConvexPolygon(Vec<Point2<S>>),
+}
+
+impl<S> CellBounds<S>
+where
+    S: SpadeNum + Float,
+{
+    /// Returns the boundary polygon in counterclockwise order.
+    pub(crate) fn to_ccw_polygon(&self) -> Vec<Point2<S>> {
+        match self {
+            CellBounds::Rectangle { lower, upper } => rectangle(
+                Point2::new(lower.x.min(upper.x), lower.y.min(upper.y)),
+                Point2::new(lower.x.max(upper.x), lower.y.max(upper.y)),
+            ),
+            CellBounds::ConvexPolygon(polygon) => {
+                let mut polygon = polygon.clone();
+                if signed_polygon_area(&polygon) < S::zero() {
+                    polygon.reverse();
+                }
+                polygon
+            }
+        }
+    }
+}
+
+/// A Voronoi cell that has been clipped to a convex area.
+///
+/// *See [FloatTriangulation::voronoi_cells_clipped](crate::FloatTriangulation::voronoi_cells_clipped)*
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClippedCell<S> {
+    /// The vertex to which this cell belongs.
+    pub vertex: FixedVertexHandle,
+
+    /// The cell's vertices in counterclockwise order.
+    ///
+    /// The polygon is closed implicitly: Its last vertex connects to its first vertex.
+    /// The polygon is empty if the cell doesn't overlap the clipping area.
+    pub polygon: Vec<Point2<S>>,
+
+    /// The polygon's area.
+    pub area: S,
+
+    /// The polygon's centroid (center of mass).
+    ///
+    /// This is `None` if the polygon is empty. Degenerate polygons with zero area use the
+    /// average of their vertices instead.
+    pub centroid: Option<Point2<S>>,
+}
+
+impl<S> ClippedCell<S>
+where
+    S: SpadeNum + Float,
+{
+    pub(crate) fn new(vertex: FixedVertexHandle, polygon: Vec<Point2<S>>) -> Self {
+        Self {
+            vertex,
+            area: polygon_area(&polygon),
+            centroid: polygon_centroid(&polygon),
+            polygon,
+        }
+    }
+}
+
+/// Returns the corners of an axis aligned rectangle in counterclockwise order.
+pub fn rectangle<S: SpadeNum>(lower: Point2<S>, upper: Point2<S>) -> Vec<Point2<S>> {
+    alloc::vec![
+        lower,
+        Point2::new(upper.x, lower.y),
+        upper,
+        Point2::new(lower.x, upper.y),
+    ]
+}
+
+/// Clips a convex polygon against the half plane `{p | normal · p <= offset}`.
+///
+/// Uses the [Sutherland-Hodgman algorithm](https://en.wikipedia.org/wiki/Sutherland%E2%80%93Hodgman_algorithm).
+/// The orientation of the polygon is preserved.
+pub fn clip_by_half_plane<S>(polygon: &[Point2<S>], normal: Point2<S>, offset: S) -> Vec<Point2<S>>
+where
+    S: SpadeNum + Float,
+{
+    let mut result = Vec::with_capacity(polygon.len() + 1);
+    let distance = |p: Point2<S>| normal.dot(p) - offset;
+
+    for (index, &current) in polygon.iter().enumerate() {
+        let next = polygon[(index + 1) % polygon.len()];
+        let current_distance = distance(current);
+        let next_distance = distance(next);
+
+        if current_distance <= S::zero() {
+            result.push(current);
+        }
+
+        if (current_distance < S::zero()) != (next_distance < S::zero()) {
+            let factor = current_distance / (current_distance - next_distance);
+            let intersection = current.add(next.sub(current).mul(factor));
+            if factor > S::zero() && factor < S::one() {
+                result.push(intersection);
+            }
+        }
+    }
+    result
+}
+
+/// Returns the part of a convex polygon that belongs to the power cell of `site`.
+///
+/// The power cell is the set of all points `p` with
+/// `|p - site|² - site_weight <= |p - neighbor|² - neighbor_weight` for all given neighbors.
+/// Zero weights result in Voronoi cells.
+pub fn clip_power_cell<S>(
+    bounds: &[Point2<S>],
+    site: Point2<S>,
+    site_weight: S,
+    neighbors: impl IntoIterator<Item = (Point2<S>, S)>,
+) -> Vec<Point2<S>>
+where
+    S: SpadeNum + Float,
+{
+    let one = S::one();
+    let half = one / (one + one);
+
+    // Calculate relative to the site to reduce rounding errors
+    let mut polygon: Vec<_> = bounds.iter().map(|p| p.sub(site)).collect();
+    for (neighbor, neighbor_weight) in neighbors {
+        if polygon.is_empty() {
+            break;
+        }
+        let diff = neighbor.sub(site);
+        let offset = (diff.length2() - neighbor_weight + site_weight) * half;
+        polygon = clip_by_half_plane(&polygon, diff, offset);
+    }
+
+    for point in &mut polygon {
+        *point = point.add(site);
+    }
+    polygon
+}
+
+/// Returns the (non-negative) area of a simple polygon.
+pub fn polygon_area<S>(polygon: &[Point2<S>]) -> S
+where
+    S: SpadeNum + Float,
+{
+    signed_polygon_area(polygon).abs()
+}
+
+/// Returns the area of a simple polygon. The area is negative for clockwise polygons.
+fn signed_polygon_area<S>(polygon: &[Point2<S>]) -> S
+where
+    S: SpadeNum + Float,
+{
+    let Some(&origin) = polygon.first() else {
+        return S::zero();
+    };
+
+    let mut double_area = S::zero();
+    for window in polygon.windows(2) {
+        let from = window[0].sub(origin);
+        let to = window[1].sub(origin);
+        double_area = double_area + from.x * to.y - from.y * to.x;
+    }
+    double_area * (S::one() / (S::one() + S::one()))
+}
+
+/// Returns the centroid of a simple polygon.
+///
+/// Falls back to the average vertex position for polygons without area. Returns `None` for
+/// empty polygons.
+pub fn polygon_centroid<S>(polygon: &[Point2<S>]) -> Option<Point2<S>>
+where
+    S: SpadeNum + Float,
+{
+    let origin = *polygon.first()?;
+
+    let mut double_area = S::zero();
+    let mut sum = Point2::new(S::zero(), S::zero());
+    for window in polygon.windows(2) {
+        let from = window[0].sub(origin);
+        let to = window[1].sub(origin);
+        let cross = from.x * to.y - from.y * to.x;
+        double_area = double_area + cross;
+        sum = sum.add(from.add(to).mul(cross));
+    }
+
+    if double_area == S::zero() {
+        let count: S = num_traits::NumCast::from(polygon.len()).unwrap();
+        let sum = polygon
+            .iter()
+            .fold(Point2::new(S::zero(), S::zero()), |sum, p| {
+                sum.add(p.sub(origin))
+            });
+        return Some(origin.add(sum.mul(S::one() / count)));
+    }
+
+    let three = S::one() + S::one() + S::one();
+    Some(origin.add(sum.mul(S::one() / (three * double_area))))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        clip_by_half_plane, clip_power_cell, polygon_area, polygon_centroid, rectangle, CellBounds,
+    };
+    use crate::Point2;
+
+    use alloc::vec;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_clip_by_half_plane() {
+        let square = rectangle(Point2::new(0.0, 0.0), Point2::new(2.0, 2.0));
+
+        let clipped = clip_by_half_plane(&square, Point2::new(1.0, 0.0), 1.0);
+        assert_eq!(
+            clipped,
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(1.0, 0.0),
+                Point2::new(1.0, 2.0),
+                Point2::new(0.0, 2.0),
+            ]
+        );
+
+        assert_eq!(
+            clip_by_half_plane(&square, Point2::new(1.0, 1.0), 10.0),
+            square
+        );
+        assert!(clip_by_half_plane(&square, Point2::new(1.0, 1.0), -1.0).is_empty());
+    }
+
+    #[test]
+    fn test_clip_power_cell() {
+        let bounds = rectangle(Point2::new(-2.0, -2.0), Point2::new(2.0, 2.0));
+        let neighbors = [(Point2::new(2.0, 0.0), 0.0), (Point2::new(0.0, 2.0), 0.0)];
+        let cell = clip_power_cell(&bounds, Point2::new(0.0, 0.0), 0.0, neighbors);
+        assert_relative_eq!(polygon_area(&cell), 9.0);
+
+        // Moves the bisector between the site and its neighbor towards the neighbor
+        let neighbors = [(Point2::new(2.0, 0.0), 0.0)];
+        let cell = clip_power_cell(&bounds, Point2::new(0.0, 0.0), 1.0, neighbors);
+        assert_relative_eq!(polygon_area(&cell), 13.0);
+    }
+
+    #[test]
+    fn test_polygon_area() {
+        assert_eq!(polygon_area::<f64>(&[]), 0.0);
+        let square = rectangle(Point2::new(1.0, 1.0), Point2::new(3.0, 4.0));
+        assert_eq!(polygon_area(&square), 6.0);
+        let mut reversed = square.clone();
+        reversed.reverse();
+        assert_eq!(polygon_area(&reversed), 6.0);
+    }
+
+    #[test]
+    fn test_polygon_centroid() {
+        assert_eq!(polygon_centroid::<f64>(&[]), None);
+
+        let square = rectangle(Point2::new(1.0, 1.0), Point2::new(3.0, 4.0));
+        assert_eq!(polygon_centroid(&square), Some(Point2::new(2.0, 2.5)));
+
+        let triangle = [
+            Point2::new(0.0, 0.0),
+            Point2::new(0.0, 3.0),
+            Point2::new(3.0, 0.0),
+        ];
+        let centroid = polygon_centroid(&triangle).unwrap();
+        assert_relative_eq!(centroid.x, 1.0);
+        assert_relative_eq!(centroid.y, 1.0);
+
+        let segment = [Point2::new(0.0, 0.0), Point2::new(2.0, 2.0)];
+        assert_eq!(polygon_centroid(&segment), Some(Point2::new(1.0, 1.0)));
+    }
+
+    #[test]
+    fn test_cell_bounds_orientation() {
+        let rectangle = CellBounds::Rectangle {
+            lower: Point2::new(2.0, 0.0),
+            upper: Point2::new(0.0, 2.0),
+        };
+        let expected = vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(0.0, 2.0),
+        ];
+        assert_eq!(rectangle.to_ccw_polygon(), expected);
+
+        let mut clockwise = expected.clone();
+        clockwise.reverse();
+        let polygon = CellBounds::ConvexPolygon(clockwise);
+        assert_eq!(polygon.to_ccw_polygon(), expected);
+    }
+}
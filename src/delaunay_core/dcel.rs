@@ -1,4 +1,4 @@
-use super::handles::handle_defs::FixedHandleImpl;
+use super::handles::handle_defs::{CheckedElementType, CheckedHandleImpl, FixedHandleImpl};
 use super::handles::iterators::*;
 use super::handles::*;
 
@@ -6,6 +6,7 @@ use super::handles::*;
 use serde::{Deserialize, Serialize};
 
 use alloc::vec::Vec;
+use smallvec::SmallVec;
 
 #[derive(Default, PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
 pub struct EdgeData<DE, UE> {
@@ -92,16 +93,334 @@ pub(super) struct HalfEdgeEntry {
     pub origin: FixedVertexHandle,
 }
 
-#[derive(Clone, Debug)]
+/// Contains the indices of all removed elements of a single type if stable handles are enabled.
+///
+/// Removed elements leave a gap that is filled by subsequently added elements. The most recently
+/// created gap is filled first.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde")
+)]
+pub struct FreeList {
+    /// All gaps in the order in which they have been created.
+    gaps: Vec<usize>,
+    /// Contains a set bit for each gap.
+    is_gap: Vec<u64>,
+}
+
+/// Returns `true` if the bit with the given index is set. Missing words are treated as zero.
+pub(super) fn is_bit_set(bits: &[u64], index: usize) -> bool {
+    bits.get(index / 64)
+        .is_some_and(|word| word & (1 << (index % 64)) != 0)
+}
+
+/// Returns the number of set bits within the given range of bit indices.
+pub(super) fn count_bits(bits: &[u64], range: core::ops::Range<usize>) -> usize {
+    let mut result = 0;
+    let mut index = range.start;
+    while index < range.end {
+        let Some(word) = bits.get(index / 64) else {
+            break;
+        };
+        let offset = index % 64;
+        let len = (64 - offset).min(range.end - index);
+        let mask = if len == 64 { u64::MAX } else { (1 << len) - 1 };
+        result += ((word >> offset) & mask).count_ones() as usize;
+        index += len;
+    }
+    result
+}
+
+impl FreeList {
+    pub(crate) fn len(&self) -> usize {
+        self.gaps.len()
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.gaps.is_empty()
+    }
+
+    pub(crate) fn contains(&self, index: usize) -> bool {
+        is_bit_set(&self.is_gap, index)
+    }
+
+    /// Returns a bit set that contains all gaps.
+    pub(crate) fn bits(&self) -> &[u64] {
+        &self.is_gap
+    }
+
+    fn set_bit(&mut self, index: usize, value: bool) {
+        let word_index = index / 64;
+        if self.is_gap.len() <= word_index {
+            self.is_gap.resize(word_index + 1, 0);
+        }
+        let mask = 1 << (index % 64);
+        if value {
+            self.is_gap[word_index] |= mask;
+        } else {
+            self.is_gap[word_index] &= !mask;
+        }
+    }
+
+    fn push(&mut self, index: usize) {
+        debug_assert!(!self.contains(index));
+        self.set_bit(index, true);
+        self.gaps.push(index);
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        let index = self.gaps.pop()?;
+        self.set_bit(index, false);
+        Some(index)
+    }
+
+    /// Returns the `offset`-th index that will be used by subsequent calls to `push_into`.
+    fn next_index(&self, len: usize, offset: usize) -> usize {
+        if offset < self.gaps.len() {
+            self.gaps[self.gaps.len() - 1 - offset]
+        } else {
+            len + offset - self.gaps.len()
+        }
+    }
+
+    /// Updates the gaps after the elements at two indices have been swapped.
+    fn swap(&mut self, index0: usize, index1: usize) {
+        let (gap, old_gap) = match (self.contains(index0), self.contains(index1)) {
+            (true, false) => (index1, index0),
+            (false, true) => (index0, index1),
+            _ => return,
+        };
+        if let Some(position) = self.gaps.iter().rposition(|&index| index == old_gap) {
+            self.gaps[position] = gap;
+        }
+        self.set_bit(old_gap, false);
+        self.set_bit(gap, true);
+    }
+
+    fn clear(&mut self) {
+        self.gaps.clear();
+        self.is_gap.clear();
+    }
+
+    /// Returns all gaps in ascending order.
+    pub(super) fn into_sorted(self) -> Vec<usize> {
+        let mut result = self.gaps;
+        result.sort_unstable();
+        result
+    }
+}
+
+/// Contains the removed elements of each type if stable handles are enabled.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 #[cfg_attr(
     feature = "serde",
     derive(Serialize, Deserialize),
     serde(crate = "serde")
 )]
+pub(super) struct FreeLists {
+    pub(super) vertices: FreeList,
+    pub(super) undirected_edges: FreeList,
+    pub(super) faces: FreeList,
+}
+
+impl FreeLists {
+    fn is_empty(&self) -> bool {
+        self.vertices.is_empty() && self.undirected_edges.is_empty() && self.faces.is_empty()
+    }
+
+    fn clear(&mut self) {
+        self.vertices.clear();
+        self.undirected_edges.clear();
+        self.faces.clear();
+    }
+}
+
+/// Maps vertex handles to the index of their entry in `Dcel::vertices`.
+///
+/// Vertex entries are stored without gaps. Removing a vertex with stable handles enabled moves
+/// the last entry into its place while all handles remain unchanged. Both lists are empty as
+/// long as each vertex entry is stored at its handle's index.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde")
+)]
+pub(super) struct VertexSlots {
+    /// The entry index of each vertex handle or `REMOVED` if the vertex has been removed.
+    entries: Vec<usize>,
+    /// The handle index of each entry.
+    handles: Vec<usize>,
+}
+
+const REMOVED: usize = usize::MAX;
+
+impl VertexSlots {
+    fn is_identity(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub(super) fn entry(&self, handle_index: usize) -> usize {
+        if self.is_identity() {
+            handle_index
+        } else {
+            self.entries[handle_index]
+        }
+    }
+
+    /// Makes the mapping of the given number of entries explicit.
+    fn materialize(&mut self, num_entries: usize) {
+        if self.is_identity() {
+            self.entries = (0..num_entries).collect();
+            self.handles = (0..num_entries).collect();
+        }
+    }
+
+    /// Moves each entry to its handle's index. Requires that no vertex slot is empty.
+    fn normalize<V>(&mut self, vertices: &mut [VertexEntry<V>]) {
+        if self.is_identity() {
+            return;
+        }
+        for entry in 0..vertices.len() {
+            while self.handles[entry] != entry {
+                let target = self.handles[entry];
+                vertices.swap(entry, target);
+                self.handles.swap(entry, target);
+            }
+        }
+        self.entries.clear();
+        self.handles.clear();
+    }
+}
+
+/// Contains a generation counter for each storage slot.
+///
+/// A slot's generation is increased whenever its element is removed or replaced by another element.
+/// This allows checked handles to detect that they have become outdated. Lists are allocated
+/// lazily, missing entries have generation `0`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde")
+)]
+pub(super) struct Generations {
+    vertices: Vec<u32>,
+    undirected_edges: Vec<u32>,
+    faces: Vec<u32>,
+}
+
+impl Generations {
+    fn is_empty(&self) -> bool {
+        self.vertices.is_empty() && self.undirected_edges.is_empty() && self.faces.is_empty()
+    }
+}
+
+fn generation(generations: &[u32], index: usize) -> u32 {
+    generations.get(index).copied().unwrap_or(0)
+}
+
+fn generation_mut(generations: &mut Vec<u32>, index: usize) -> &mut u32 {
+    if generations.len() <= index {
+        generations.resize(index + 1, 0);
+    }
+    &mut generations[index]
+}
+
+fn increment_generation(generations: &mut Vec<u32>, index: usize) {
+    let generation = generation_mut(generations, index);
+    *generation = generation.wrapping_add(1);
+}
+
+/// Returns the data of all vertex entries, ordered by their handle.
+fn vertex_data_by_handle<V>(vertices: Vec<VertexEntry<V>>, slots: VertexSlots) -> Vec<V> {
+    if slots.is_identity() {
+        return vertices
+            .into_iter()
+            .map(|vertex_data| vertex_data.data)
+            .collect();
+    }
+
+    let mut result: Vec<_> = (0..slots.entries.len()).map(|_| None).collect();
+    for (vertex_data, handle) in vertices.into_iter().zip(slots.handles) {
+        result[handle] = Some(vertex_data.data);
+    }
+    result.into_iter().flatten().collect()
+}
+
+/// Adds an element to the most recently created gap or to the end of `elements`.
+fn push_into<T>(elements: &mut Vec<T>, free_list: &mut FreeList, element: T) -> usize {
+    if let Some(index) = free_list.pop() {
+        elements[index] = element;
+        index
+    } else {
+        elements.push(element);
+        elements.len() - 1
+    }
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Deserialize), serde(crate = "serde"))]
 pub struct Dcel<V, DE = (), UE = (), F = ()> {
     pub(super) vertices: Vec<VertexEntry<V>>,
     pub(super) faces: Vec<FaceEntry<F>>,
     pub(super) edges: Vec<EdgeEntry<DE, UE>>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) stable_handles: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) free_lists: FreeLists,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) generations: Generations,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(super) vertex_slots: VertexSlots,
+}
+
+#[cfg(feature = "serde")]
+impl<V, DE, UE, F> Serialize for Dcel<V, DE, UE, F>
+where
+    V: Serialize,
+    DE: Serialize,
+    UE: Serialize,
+    F: Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        // Self-describing formats omit the state of stable and checked handles unless they have
+        // been used. This keeps their output unchanged for triangulations without such handles.
+        // Other formats rely on a fixed number of fields and always contain all fields.
+        let is_human_readable = serializer.is_human_readable();
+        let skip_stable_handles = is_human_readable
+            && !self.stable_handles
+            && self.free_lists.is_empty()
+            && self.vertex_slots.is_identity();
+        let skip_generations = is_human_readable && self.generations.is_empty();
+
+        let mut state = serializer.serialize_struct("Dcel", 7)?;
+        state.serialize_field("vertices", &self.vertices)?;
+        state.serialize_field("faces", &self.faces)?;
+        state.serialize_field("edges", &self.edges)?;
+        if skip_stable_handles {
+            state.skip_field("stable_handles")?;
+            state.skip_field("free_lists")?;
+        } else {
+            state.serialize_field("stable_handles", &self.stable_handles)?;
+            state.serialize_field("free_lists", &self.free_lists)?;
+        }
+        if skip_generations {
+            state.skip_field("generations")?;
+        } else {
+            state.serialize_field("generations", &self.generations)?;
+        }
+        if skip_stable_handles {
+            state.skip_field("vertex_slots")?;
+        } else {
+            state.serialize_field("vertex_slots", &self.vertex_slots)?;
+        }
+        state.end()
+    }
 }
 
 impl<V, DE, UE, F> Default for Dcel<V, DE, UE, F>
@@ -127,14 +446,253 @@ impl<V, DE, UE, F> Dcel<V, DE, UE, F> {
         self.faces.reserve(num_faces);
     }
 
+    pub fn shrink_to_fit(&mut self) {
+        self.vertices.shrink_to_fit();
+        self.edges.shrink_to_fit();
+        self.faces.shrink_to_fit();
+    }
+
     pub fn clear(&mut self) {
+        self.invalidate_all_slots();
+        self.remove_all_elements();
+    }
+
+    /// Removes all elements like [clear](Self::clear) and returns the data of all vertices,
+    /// ordered by their index.
+    pub fn take_vertices(&mut self) -> Vec<V> {
+        self.invalidate_all_slots();
+        let vertices = core::mem::take(&mut self.vertices);
+        let vertex_slots = core::mem::take(&mut self.vertex_slots);
+        self.remove_all_elements();
+        vertex_data_by_handle(vertices, vertex_slots)
+    }
+
+    fn remove_all_elements(&mut self) {
         self.vertices.clear();
         self.edges.clear();
         self.faces.truncate(1); // Keep outer face
+        self.free_lists.clear();
+        self.vertex_slots = VertexSlots::default();
+    }
+
+    /// Invalidates all checked handles except for handles to the outer face.
+    fn invalidate_all_slots(&mut self) {
+        let num_slots = [
+            self.num_vertex_slots(),
+            self.num_undirected_edge_slots(),
+            self.num_face_slots(),
+        ];
+        let generations = &mut self.generations;
+        let lists = [
+            &mut generations.vertices,
+            &mut generations.undirected_edges,
+            &mut generations.faces,
+        ];
+        for (list, num_slots) in lists.into_iter().zip(num_slots) {
+            // Slots without an allocated generation have generation 0 and must be updated as well
+            if list.len() < num_slots {
+                list.resize(num_slots, 0);
+            }
+        }
+
+        for generation in generations
+            .vertices
+            .iter_mut()
+            .chain(&mut generations.undirected_edges)
+        {
+            *generation = generation.wrapping_add(1);
+        }
+        for generation in generations.faces.iter_mut().skip(1) {
+            *generation = generation.wrapping_add(1);
+        }
     }
 
     pub fn get_vertex(&self, handle: FixedVertexHandle) -> Option<VertexHandle<V, DE, UE, F>> {
-        (handle.index() < self.vertices.len()).then(|| self.vertex(handle))
+        self.is_vertex_live(handle).then(|| self.vertex(handle))
+    }
+
+    pub fn has_stable_handles(&self) -> bool {
+        self.stable_handles
+    }
+
+    /// Returns `true` if any element has been removed while stable handles were enabled.
+    pub fn has_removed_elements(&self) -> bool {
+        !self.free_lists.is_empty()
+    }
+
+    pub fn is_vertex_live(&self, handle: FixedVertexHandle) -> bool {
+        handle.index() < self.num_vertex_slots()
+            && !self.free_lists.vertices.contains(handle.index())
+    }
+
+    pub fn is_undirected_edge_live(&self, handle: FixedUndirectedEdgeHandle) -> bool {
+        handle.index() < self.edges.len()
+            && !self.free_lists.undirected_edges.contains(handle.index())
+    }
+
+    pub fn is_face_live<InnerOuter: InnerOuterMarker>(
+        &self,
+        handle: FixedHandleImpl<FaceTag, InnerOuter>,
+    ) -> bool {
+        handle.index() < self.faces.len() && !self.free_lists.faces.contains(handle.index())
+    }
+
+    /// Returns `true` if a checked handle still refers to the element it was created for.
+    pub fn is_checked_handle_valid<Type: CheckedElementType, InnerOuter: InnerOuterMarker>(
+        &self,
+        handle: CheckedHandleImpl<Type, InnerOuter>,
+    ) -> bool {
+        let index = handle.fix().index();
+        Type::is_live(self, index) && Type::generation(self, index) == handle.generation()
+    }
+
+    pub fn vertex_generation(&self, handle: FixedVertexHandle) -> u32 {
+        generation(&self.generations.vertices, handle.index())
+    }
+
+    pub fn undirected_edge_generation(&self, handle: FixedUndirectedEdgeHandle) -> u32 {
+        generation(&self.generations.undirected_edges, handle.index())
+    }
+
+    pub fn face_generation<InnerOuter: InnerOuterMarker>(
+        &self,
+        handle: FixedHandleImpl<FaceTag, InnerOuter>,
+    ) -> u32 {
+        generation(&self.generations.faces, handle.index())
+    }
+
+    /// Overwrites the generation of a vertex slot. Used to restore a vertex' identity after it has
+    /// been removed and re-inserted internally.
+    pub(super) fn set_vertex_generation(&mut self, handle: FixedVertexHandle, generation: u32) {
+        *generation_mut(&mut self.generations.vertices, handle.index()) = generation;
+    }
+
+    /// Invalidates all checked handles that refer to the given vertex slot.
+    pub(super) fn invalidate_vertex_slot(&mut self, index: usize) {
+        increment_generation(&mut self.generations.vertices, index);
+    }
+
+    /// Invalidates all checked handles that refer to the given undirected edge slot.
+    pub(super) fn invalidate_undirected_edge_slot(&mut self, index: usize) {
+        increment_generation(&mut self.generations.undirected_edges, index);
+    }
+
+    /// Invalidates all checked handles that refer to the given face slot.
+    pub(super) fn invalidate_face_slot(&mut self, index: usize) {
+        increment_generation(&mut self.generations.faces, index);
+    }
+
+    /// Returns the number of vertex slots, including the gaps of removed vertices.
+    pub fn num_vertex_slots(&self) -> usize {
+        if self.vertex_slots.is_identity() {
+            self.vertices.len()
+        } else {
+            self.vertex_slots.entries.len()
+        }
+    }
+
+    /// Returns the number of undirected edge slots, including the gaps of removed edges.
+    pub fn num_undirected_edge_slots(&self) -> usize {
+        self.edges.len()
+    }
+
+    /// Returns the number of face slots, including the gaps of removed faces.
+    pub fn num_face_slots(&self) -> usize {
+        self.faces.len()
+    }
+
+    pub(super) fn next_vertex_handle(&self) -> FixedVertexHandle {
+        FixedVertexHandle::new(
+            self.free_lists
+                .vertices
+                .next_index(self.num_vertex_slots(), 0),
+        )
+    }
+
+    /// Returns the handle of the `offset`-th undirected edge that will be pushed next.
+    pub(super) fn next_undirected_edge_handle(&self, offset: usize) -> FixedUndirectedEdgeHandle {
+        FixedUndirectedEdgeHandle::new(
+            self.free_lists
+                .undirected_edges
+                .next_index(self.edges.len(), offset),
+        )
+    }
+
+    /// Returns the handle of the `offset`-th face that will be pushed next.
+    pub(super) fn next_face_handle<InnerOuter: InnerOuterMarker>(
+        &self,
+        offset: usize,
+    ) -> FixedHandleImpl<FaceTag, InnerOuter> {
+        FixedHandleImpl::new(self.free_lists.faces.next_index(self.faces.len(), offset))
+    }
+
+    pub(super) fn push_vertex(&mut self, entry: VertexEntry<V>) -> FixedVertexHandle {
+        let slots = &mut self.vertex_slots;
+        let index = if let Some(index) = self.free_lists.vertices.pop() {
+            // Gaps only exist if the slots are not an identity mapping
+            slots.entries[index] = self.vertices.len();
+            slots.handles.push(index);
+            index
+        } else if slots.is_identity() {
+            self.vertices.len()
+        } else {
+            slots.entries.push(self.vertices.len());
+            slots.handles.push(slots.entries.len() - 1);
+            slots.entries.len() - 1
+        };
+        self.vertices.push(entry);
+        FixedVertexHandle::new(index)
+    }
+
+    pub(super) fn push_edge(&mut self, entry: EdgeEntry<DE, UE>) -> FixedUndirectedEdgeHandle {
+        FixedUndirectedEdgeHandle::new(push_into(
+            &mut self.edges,
+            &mut self.free_lists.undirected_edges,
+            entry,
+        ))
+    }
+
+    pub(super) fn push_face(&mut self, entry: FaceEntry<F>) -> FixedFaceHandle<PossiblyOuterTag> {
+        FixedFaceHandle::new(push_into(
+            &mut self.faces,
+            &mut self.free_lists.faces,
+            entry,
+        ))
+    }
+
+    /// Marks a vertex as removed and returns its data. Requires stable handles.
+    pub(super) fn free_vertex(&mut self, handle: FixedVertexHandle) -> V {
+        assert!(
+            self.is_vertex_live(handle),
+            "Vertex has already been removed"
+        );
+        let slots = &mut self.vertex_slots;
+        slots.materialize(self.vertices.len());
+
+        // The last entry is moved into the removed entry's place
+        let entry_index = core::mem::replace(&mut slots.entries[handle.index()], REMOVED);
+        slots.handles.swap_remove(entry_index);
+        if let Some(&moved) = slots.handles.get(entry_index) {
+            slots.entries[moved] = entry_index;
+        }
+        let entry = self.vertices.swap_remove(entry_index);
+
+        self.free_lists.vertices.push(handle.index());
+        self.invalidate_vertex_slot(handle.index());
+        entry.data
+    }
+
+    /// Marks an undirected edge as removed. Requires stable handles.
+    pub(super) fn free_undirected_edge(&mut self, handle: FixedUndirectedEdgeHandle) {
+        self.free_lists.undirected_edges.push(handle.index());
+        self.invalidate_undirected_edge_slot(handle.index());
+    }
+
+    /// Marks a face as removed. Requires stable handles.
+    pub(super) fn free_face(&mut self, handle: FixedFaceHandle<InnerTag>) {
+        self.faces[handle.index()].adjacent_edge = None;
+        self.free_lists.faces.push(handle.index());
+        self.invalidate_face_slot(handle.index());
     }
 
     pub fn map_vertices<M, V2>(self, f: M) -> Dcel<V2, DE, UE, F>
@@ -152,9 +710,18 @@ impl<V, DE, UE, F> Dcel<V, DE, UE, F> {
                 .collect(),
             faces: self.faces,
             edges: self.edges,
+            stable_handles: self.stable_handles,
+            free_lists: self.free_lists,
+            generations: self.generations,
+            vertex_slots: self.vertex_slots,
         }
     }
 
+    /// Returns the data of all vertices that have not been removed, ordered by their index.
+    pub fn into_vertices(self) -> Vec<V> {
+        vertex_data_by_handle(self.vertices, self.vertex_slots)
+    }
+
     pub fn map_undirected_edges<M, UE2>(self, f: M) -> Dcel<V, DE, UE2, F>
     where
         M: Fn(UE) -> UE2,
@@ -171,6 +738,10 @@ impl<V, DE, UE, F> Dcel<V, DE, UE, F> {
                 })
                 .collect(),
             faces: self.faces,
+            stable_handles: self.stable_handles,
+            free_lists: self.free_lists,
+            generations: self.generations,
+            vertex_slots: self.vertex_slots,
         }
     }
 
@@ -179,15 +750,15 @@ impl<V, DE, UE, F> Dcel<V, DE, UE, F> {
     }
 
     pub fn num_directed_edges(&self) -> usize {
-        self.edges.len() * 2
+        self.num_undirected_edges() * 2
     }
 
     pub fn num_undirected_edges(&self) -> usize {
-        self.edges.len()
+        self.edges.len() - self.free_lists.undirected_edges.len()
     }
 
     pub fn num_faces(&self) -> usize {
-        self.faces.len()
+        self.faces.len() - self.free_lists.faces.len()
     }
 
     pub fn vertex(&self, handle: FixedVertexHandle) -> VertexHandle<V, DE, UE, F> {
@@ -195,7 +766,7 @@ impl<V, DE, UE, F> Dcel<V, DE, UE, F> {
     }
 
     pub fn vertex_out_edge(&self, handle: FixedVertexHandle) -> Option<FixedDirectedEdgeHandle> {
-        self.vertices[handle.index()]
+        self.vertex_entry(handle)
             .out_edge
             .map(|e| e.adjust_inner_outer())
     }
@@ -219,6 +790,14 @@ impl<V, DE, UE, F> Dcel<V, DE, UE, F> {
         self.face(outer_face)
     }
 
+    pub(super) fn vertex_entry(&self, handle: FixedVertexHandle) -> &VertexEntry<V> {
+        &self.vertices[self.vertex_slots.entry(handle.index())]
+    }
+
+    pub(super) fn vertex_entry_mut(&mut self, handle: FixedVertexHandle) -> &mut VertexEntry<V> {
+        &mut self.vertices[self.vertex_slots.entry(handle.index())]
+    }
+
     pub(super) fn edge_entry<InnerOuter: InnerOuterMarker>(
         &self,
         handle: FixedHandleImpl<UndirectedEdgeTag, InnerOuter>,
@@ -296,14 +875,14 @@ impl<V, DE, UE, F> Dcel<V, DE, UE, F> {
         &self,
         handle: FixedHandleImpl<VertexTag, InnerOuter>,
     ) -> &V {
-        &self.vertices[handle.index()].data
+        &self.vertex_entry(handle.adjust_inner_outer()).data
     }
 
     pub fn vertex_data_mut<InnerOuter: InnerOuterMarker>(
         &mut self,
         handle: FixedHandleImpl<VertexTag, InnerOuter>,
     ) -> &mut V {
-        &mut self.vertices[handle.index()].data
+        &mut self.vertex_entry_mut(handle.adjust_inner_outer()).data
     }
 
     pub fn get_edge_from_neighbors(
@@ -325,7 +904,7 @@ impl<V, DE, UE, F> Dcel<V, DE, UE, F> {
         handle: FixedHandleImpl<VertexTag, InnerOuter>,
         data: V,
     ) {
-        self.vertices[handle.index()].data = data;
+        self.vertex_entry_mut(handle.adjust_inner_outer()).data = data;
     }
 
     pub fn directed_edges(&self) -> DirectedEdgeIterator<V, DE, UE, F> {
@@ -341,7 +920,7 @@ impl<V, DE, UE, F> Dcel<V, DE, UE, F> {
     }
 
     pub fn fixed_vertices(&self) -> FixedVertexIterator {
-        FixedVertexIterator::new(self.num_vertices())
+        FixedVertexIterator::new(self)
     }
 
     pub fn faces(&self) -> FaceIterator<V, DE, UE, F> {
@@ -355,13 +934,34 @@ impl<V, DE, UE, F> Dcel<V, DE, UE, F> {
     }
 
     pub fn fixed_faces(&self) -> FixedFaceIterator {
-        FixedFaceIterator::new(self.num_faces())
+        FixedFaceIterator::new(self)
     }
 
     pub fn swap_vertices(&mut self, v0: FixedVertexHandle, v1: FixedVertexHandle) {
-        let out_0 = self.vertices[v0.index()].out_edge;
-        let out_1 = self.vertices[v1.index()].out_edge;
-        self.vertices.swap(v0.index(), v1.index());
+        let [out_0, out_1] = [v0, v1].map(|v| {
+            self.is_vertex_live(v)
+                .then(|| self.vertex_entry(v).out_edge)
+                .flatten()
+        });
+
+        let slots = &mut self.vertex_slots;
+        if slots.is_identity() {
+            self.vertices.swap(v0.index(), v1.index());
+        } else {
+            slots.entries.swap(v0.index(), v1.index());
+            for v in [v0, v1] {
+                if let Some(handle) = slots.handles.get_mut(slots.entries[v.index()]) {
+                    *handle = v.index();
+                }
+            }
+        }
+        // A removed vertex' gap moves to the other index
+        self.free_lists.vertices.swap(v0.index(), v1.index());
+
+        // Swapped vertices keep their identity
+        let generations = &mut self.generations.vertices;
+        generation_mut(generations, v0.index().max(v1.index()));
+        generations.swap(v0.index(), v1.index());
 
         if let Some(mut out_0) = out_0 {
             loop {
@@ -390,8 +990,69 @@ impl<V, DE, UE, F> Dcel<V, DE, UE, F> {
         }
     }
 
+    /// Moves the vertex with the largest handle into the given gap.
+    ///
+    /// The last vertex slot must not be a gap unless it is the given gap. Returns the previous
+    /// handle of the moved vertex, if any.
+    pub(super) fn close_vertex_gap(&mut self, gap: FixedVertexHandle) -> Option<FixedVertexHandle> {
+        let last = FixedVertexHandle::new(self.num_vertex_slots() - 1);
+        self.invalidate_vertex_slot(gap.index());
+        self.invalidate_vertex_slot(last.index());
+
+        let slots = &mut self.vertex_slots;
+        let entry = slots.entries.pop().expect("Vertex slots must not be empty");
+        if gap == last {
+            return None;
+        }
+        slots.entries[gap.index()] = entry;
+        slots.handles[entry] = gap.index();
+
+        let out_edges: SmallVec<[_; 8]> = self
+            .vertex(gap)
+            .out_edges()
+            .map(|edge| edge.fix())
+            .collect();
+        for edge in out_edges {
+            self.half_edge_mut(edge).origin = gap;
+        }
+        Some(last)
+    }
+
+    /// Stores each vertex entry at its handle's index. Requires that no vertex slot is a gap.
+    pub(super) fn normalize_vertex_slots(&mut self) {
+        debug_assert!(self.free_lists.vertices.is_empty());
+        self.vertex_slots.normalize(&mut self.vertices);
+    }
+
     #[cfg(any(test, fuzzing))]
     pub fn sanity_check(&self) {
+        if !self.stable_handles {
+            assert!(self.free_lists.is_empty());
+            assert!(self.vertex_slots.is_identity());
+        }
+        for (free_list, len) in [
+            (&self.free_lists.vertices, self.num_vertex_slots()),
+            (&self.free_lists.undirected_edges, self.edges.len()),
+            (&self.free_lists.faces, self.faces.len()),
+        ] {
+            assert_eq!(count_bits(free_list.bits(), 0..len), free_list.len());
+            assert!(free_list
+                .gaps
+                .iter()
+                .all(|&index| free_list.contains(index)));
+        }
+        if !self.vertex_slots.is_identity() {
+            let slots = &self.vertex_slots;
+            assert_eq!(slots.handles.len(), self.vertices.len());
+            for (index, &entry) in slots.entries.iter().enumerate() {
+                assert_eq!(entry == REMOVED, self.free_lists.vertices.contains(index));
+                if entry != REMOVED {
+                    assert_eq!(slots.handles[entry], index);
+                }
+            }
+        }
+        assert!(!self.free_lists.faces.contains(0));
+
         if self.num_vertices() <= 1 {
             assert_eq!(self.num_faces(), 1);
             assert_eq!(self.num_undirected_edges(), 0);
@@ -403,21 +1064,24 @@ impl<V, DE, UE, F> Dcel<V, DE, UE, F> {
             return;
         }
 
-        for (index, face) in self.faces.iter().enumerate() {
-            assert_eq!(
-                self.directed_edge(face.adjacent_edge.unwrap()).face().fix(),
-                FixedFaceHandle::new(index)
-            );
+        for face in self.fixed_faces() {
+            let adjacent_edge = self.faces[face.index()].adjacent_edge.unwrap();
+            assert!(self.is_undirected_edge_live(adjacent_edge.as_undirected()));
+            assert_eq!(self.directed_edge(adjacent_edge).face().fix(), face);
         }
-        for (index, vertex) in self.vertices.iter().enumerate() {
-            assert_eq!(
-                self.directed_edge(vertex.out_edge.unwrap()).from().fix(),
-                FixedVertexHandle::new(index)
-            );
+        for vertex in self.fixed_vertices() {
+            let out_edge = self.vertex_entry(vertex).out_edge.unwrap();
+            assert!(self.is_undirected_edge_live(out_edge.as_undirected()));
+            assert_eq!(self.directed_edge(out_edge).from().fix(), vertex);
         }
 
-        for handle in 0..self.edges.len() {
-            let edge = self.directed_edge(FixedDirectedEdgeHandle::new_normalized(handle));
+        for edge in self.undirected_edges() {
+            let edge = edge.as_directed();
+            for directed in [edge, edge.rev()] {
+                assert!(self.is_vertex_live(directed.from().fix()));
+                assert!(self.is_face_live(directed.face().fix()));
+                assert!(self.is_undirected_edge_live(directed.next().fix().as_undirected()));
+            }
             assert_eq!(edge, edge.next().prev());
             assert_eq!(edge, edge.prev().next());
             assert_eq!(edge, edge.rev().rev());
@@ -2,6 +2,7 @@ use crate::RemovalResult;
 
 use super::dcel::{Dcel, EdgeEntry, FaceEntry, HalfEdgeEntry, VertexEntry};
 use super::handles::*;
+use super::remapping::HandleRemapping;
 
 use smallvec::SmallVec;
 
@@ -9,17 +10,32 @@ use alloc::{vec, vec::Vec};
 
 pub const OUTER_FACE_HANDLE: FixedFaceHandle<PossiblyOuterTag> = new_fixed_face_handle(0);
 
+/// Invalidates all checked handles of an inner face whose vertices are about to change.
+///
+/// The outer face is never invalidated as it keeps its identity.
+fn invalidate_changed_face<V, DE, UE, F>(
+    dcel: &mut Dcel<V, DE, UE, F>,
+    face: FixedFaceHandle<PossiblyOuterTag>,
+) {
+    if face != OUTER_FACE_HANDLE {
+        dcel.invalidate_face_slot(face.index());
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct IsolateVertexResult {
     pub new_edges: Vec<FixedUndirectedEdgeHandle>,
-    pub smallest_new_edge: FixedUndirectedEdgeHandle,
+    /// Contains the same handles as `new_edges` in ascending order.
+    pub sorted_new_edges: Vec<FixedUndirectedEdgeHandle>,
     pub edges_to_remove: Vec<FixedUndirectedEdgeHandle>,
     pub faces_to_remove: Vec<FixedFaceHandle<InnerTag>>,
 }
 
 impl IsolateVertexResult {
     pub fn is_new_edge(&self, edge: FixedUndirectedEdgeHandle) -> bool {
-        edge >= self.smallest_new_edge
+        // New edges can fill gaps of removed edges if stable handles are enabled. They are thus
+        // not guaranteed to have the largest indices.
+        self.sorted_new_edges.binary_search(&edge).is_ok()
     }
 }
 
@@ -52,12 +68,12 @@ where
         dcel.half_edge_mut(edge).face = OUTER_FACE_HANDLE;
 
         dcel.faces[OUTER_FACE_HANDLE.index()].adjacent_edge = Some(edge);
-        dcel.vertices[from.index()].out_edge = Some(edge);
+        dcel.vertex_entry_mut(from).out_edge = Some(edge);
     }
 
     IsolateVertexResult {
         new_edges: Vec::new(),
-        smallest_new_edge: FixedUndirectedEdgeHandle::new(0),
+        sorted_new_edges: Vec::new(),
         edges_to_remove,
         faces_to_remove,
     }
@@ -100,7 +116,6 @@ where
     F: Default,
 {
     let mut new_edges = Vec::new();
-    let smallest_new_edge = FixedUndirectedEdgeHandle::new(dcel.edges.len());
 
     // Re-mesh border loop by fanning out triangles
     let mut inner_edge = border_loop.pop().unwrap();
@@ -110,8 +125,8 @@ where
         let outer_edge_from = dcel.directed_edge(outer_edge).from().fix();
         let outer_edge_to = dcel.directed_edge(outer_edge).to().fix();
 
-        let new_edge_handle = FixedDirectedEdgeHandle::new_normalized(dcel.edges.len());
-        let new_face_handle = FixedFaceHandle::new(dcel.faces.len());
+        let new_edge_handle = dcel.next_undirected_edge_handle(0).normalized();
+        let new_face_handle = dcel.next_face_handle(0);
 
         // This edge needs to be created
         //      |
@@ -159,11 +174,11 @@ where
         dcel.half_edge_mut(inner_edge).next = outer_edge;
         dcel.half_edge_mut(inner_edge).face = new_face_handle;
 
-        dcel.vertices[outer_edge_from.index()].out_edge = Some(outer_edge);
+        dcel.vertex_entry_mut(outer_edge_from).out_edge = Some(outer_edge);
 
-        dcel.edges.push(new_edge);
+        dcel.push_edge(new_edge);
         new_edges.push(new_edge_handle.as_undirected());
-        dcel.faces.push(new_face);
+        dcel.push_face(new_face);
 
         // Prepare next iteration
         inner_edge = new_edge_handle.rev();
@@ -174,13 +189,13 @@ where
     let inner_edge_prev = border_loop.pop().unwrap();
 
     // Create a new face for the last triangle
-    let new_face_handle = FixedFaceHandle::new(dcel.faces.len());
+    let new_face_handle = dcel.next_face_handle(0);
     let new_face = FaceEntry {
         adjacent_edge: Some(inner_edge),
         data: F::default(),
     };
     dcel.half_edge_mut(inner_edge).face = new_face_handle;
-    dcel.faces.push(new_face);
+    dcel.push_face(new_face);
     dcel.half_edge_mut(inner_edge_prev).face = new_face_handle;
     dcel.half_edge_mut(inner_edge_next).face = new_face_handle;
 
@@ -196,17 +211,20 @@ where
 
     // Update out_edge entries for the triangle's vertices
     let prev_origin = dcel.half_edge_mut(inner_edge_prev).origin;
-    dcel.vertices[prev_origin.index()].out_edge = Some(inner_edge_prev);
+    dcel.vertex_entry_mut(prev_origin).out_edge = Some(inner_edge_prev);
 
     let next_origin = dcel.half_edge_mut(inner_edge_next).origin;
-    dcel.vertices[next_origin.index()].out_edge = Some(inner_edge_next);
+    dcel.vertex_entry_mut(next_origin).out_edge = Some(inner_edge_next);
 
     // fan_origin is the third vertex of the triangle
-    dcel.vertices[fan_origin.index()].out_edge = Some(inner_edge);
+    dcel.vertex_entry_mut(fan_origin).out_edge = Some(inner_edge);
+
+    let mut sorted_new_edges = new_edges.clone();
+    sorted_new_edges.sort_unstable();
 
     IsolateVertexResult {
         new_edges,
-        smallest_new_edge,
+        sorted_new_edges,
         edges_to_remove,
         faces_to_remove,
     }
@@ -247,7 +265,7 @@ where
     let next_entry = *dcel.half_edge(edge_entry.next);
     let next_to = dcel.directed_edge(edge_entry.next).to().fix();
 
-    let new_face_handle = FixedFaceHandle::<PossiblyOuterTag>::new(dcel.num_faces());
+    let new_face_handle = dcel.next_face_handle::<PossiblyOuterTag>(0);
 
     let inner_edge_entry = HalfEdgeEntry {
         next: edge,
@@ -269,7 +287,7 @@ where
         undirected_data: UE::default(),
     };
 
-    let new_edge_handle = FixedUndirectedEdgeHandle::new(dcel.num_undirected_edges());
+    let new_edge_handle = dcel.next_undirected_edge_handle(0);
 
     let new_inner_handle = FixedDirectedEdgeHandle::new_normalized(new_edge_handle.index());
     let new_outer_handle = new_inner_handle.rev();
@@ -285,8 +303,8 @@ where
 
     dcel.faces[OUTER_FACE_HANDLE.index()].adjacent_edge = Some(new_outer_handle);
 
-    dcel.edges.push(new_edge_entry);
-    dcel.faces.push(FaceEntry {
+    dcel.push_edge(new_edge_entry);
+    dcel.push_face(FaceEntry {
         adjacent_edge: Some(new_inner_handle),
         data: F::default(),
     });
@@ -323,11 +341,11 @@ where
     let edge_from = edge_entry.origin;
     let edge_to = dcel.directed_edge(edge).to().fix();
 
-    let new_next_handle = FixedUndirectedEdgeHandle::new(dcel.num_undirected_edges());
-    let new_prev_handle = FixedUndirectedEdgeHandle::new(dcel.num_undirected_edges() + 1);
+    let new_next_handle = dcel.next_undirected_edge_handle(0);
+    let new_prev_handle = dcel.next_undirected_edge_handle(1);
 
-    let new_face_handle = FixedFaceHandle::<PossiblyOuterTag>::new(dcel.num_faces());
-    let new_vertex_handle = FixedVertexHandle::new(dcel.num_vertices());
+    let new_face_handle = dcel.next_face_handle::<PossiblyOuterTag>(0);
+    let new_vertex_handle = dcel.next_vertex_handle();
 
     let new_next = HalfEdgeEntry {
         next: new_prev_handle.normalized(),
@@ -357,24 +375,24 @@ where
         origin: edge_from,
     };
 
-    dcel.edges.push(EdgeEntry {
+    dcel.push_edge(EdgeEntry {
         entries: [new_next, new_next_rev],
         directed_data: [DE::default(), DE::default()],
         undirected_data: UE::default(),
     });
 
-    dcel.edges.push(EdgeEntry {
+    dcel.push_edge(EdgeEntry {
         entries: [new_prev, new_prev_rev],
         directed_data: [DE::default(), DE::default()],
         undirected_data: UE::default(),
     });
 
-    dcel.faces.push(FaceEntry {
+    dcel.push_face(FaceEntry {
         adjacent_edge: Some(edge),
         data: F::default(),
     });
 
-    dcel.vertices.push(VertexEntry {
+    dcel.push_vertex(VertexEntry {
         data: new_vertex,
         out_edge: Some(new_prev_handle.normalized()),
     });
@@ -420,10 +438,10 @@ where
         "end vertex is not at the end of the line"
     );
 
-    let new_edge = FixedDirectedEdgeHandle::new_normalized(dcel.num_undirected_edges());
+    let new_edge = dcel.next_undirected_edge_handle(0).normalized();
     let new_edge_rev = new_edge.rev();
 
-    let new_vertex_handle = FixedVertexHandle::new(dcel.num_vertices());
+    let new_vertex_handle = dcel.next_vertex_handle();
     let face = out_edge.face().fix();
 
     let out_edge = out_edge.fix();
@@ -433,7 +451,7 @@ where
     dcel.half_edge_mut(out_edge).prev = new_edge;
     dcel.half_edge_mut(in_edge).next = new_edge_rev;
 
-    dcel.edges.push(EdgeEntry::new(
+    dcel.push_edge(EdgeEntry::new(
         HalfEdgeEntry {
             next: out_edge,
             prev: new_edge_rev,
@@ -448,7 +466,7 @@ where
         },
     ));
 
-    dcel.vertices.push(VertexEntry {
+    dcel.push_vertex(VertexEntry {
         data: new_vertex,
         out_edge: Some(new_edge),
     });
@@ -469,14 +487,14 @@ where
     let rev = edge.rev();
     assert_eq!(edge.face(), rev.face());
 
-    let new_edge = FixedDirectedEdgeHandle::new_normalized(dcel.num_undirected_edges());
+    let new_edge = dcel.next_undirected_edge_handle(0).normalized();
     let new_edge_rev = new_edge.rev();
 
     let edge_next = edge.next().fix();
     let rev_prev = rev.prev().fix();
 
     let to = edge.to().fix();
-    let new_vertex_handle = FixedVertexHandle::new(dcel.vertices.len());
+    let new_vertex_handle = dcel.next_vertex_handle();
 
     let face = edge.face().fix();
     let edge = edge.fix();
@@ -496,8 +514,9 @@ where
     dcel.half_edge_mut(rev).prev = new_edge_rev;
 
     dcel.half_edge_mut(rev).origin = new_vertex_handle;
+    dcel.invalidate_undirected_edge_slot(edge.as_undirected().index());
 
-    dcel.vertices[to.index()].out_edge = Some(new_edge_rev);
+    dcel.vertex_entry_mut(to).out_edge = Some(new_edge_rev);
 
     let (new_edge_next, new_rev_prev) = if is_isolated {
         (new_edge_rev, new_edge)
@@ -507,7 +526,7 @@ where
         (edge_next, rev_prev)
     };
 
-    dcel.edges.push(EdgeEntry::new(
+    dcel.push_edge(EdgeEntry::new(
         HalfEdgeEntry {
             next: new_edge_next,
             prev: edge,
@@ -522,7 +541,7 @@ where
         },
     ));
 
-    dcel.vertices.push(VertexEntry {
+    dcel.push_vertex(VertexEntry {
         data: new_vertex,
         out_edge: Some(new_edge),
     });
@@ -600,14 +619,14 @@ where
     let edge_twin_face = edge.rev().face().fix();
 
     let f1 = edge.face().fix();
-    let nf = FixedFaceHandle::new(dcel.faces.len());
+    let nf = dcel.next_face_handle(0);
 
-    let e1 = FixedDirectedEdgeHandle::new_normalized(dcel.edges.len());
+    let e1 = dcel.next_undirected_edge_handle(0).normalized();
     let t1 = e1.rev();
-    let e2 = FixedDirectedEdgeHandle::new_normalized(dcel.edges.len() + 1);
+    let e2 = dcel.next_undirected_edge_handle(1).normalized();
     let t2 = e2.rev();
 
-    let nv = FixedVertexHandle::new(dcel.vertices.len());
+    let nv = dcel.next_vertex_handle();
 
     let edge1 = HalfEdgeEntry {
         next: e2,
@@ -647,10 +666,10 @@ where
         out_edge: Some(e2),
     };
 
-    dcel.edges.push(EdgeEntry::new(edge1, twin1));
-    dcel.edges.push(EdgeEntry::new(edge2, twin2));
-    dcel.faces.push(new_face);
-    dcel.vertices.push(new_vertex_entry);
+    dcel.push_edge(EdgeEntry::new(edge1, twin1));
+    dcel.push_edge(EdgeEntry::new(edge2, twin2));
+    dcel.push_face(new_face);
+    dcel.push_vertex(new_vertex_entry);
 
     dcel.half_edge_mut(edge_twin_prev).next = t2;
 
@@ -662,8 +681,10 @@ where
 
     dcel.half_edge_mut(edge_next).face = nf;
     dcel.half_edge_mut(edge_twin).origin = nv;
+    dcel.invalidate_undirected_edge_slot(edge_handle.as_undirected().index());
+    invalidate_changed_face(dcel, f1);
 
-    dcel.vertices[to.index()].out_edge = Some(e2.rev());
+    dcel.vertex_entry_mut(to).out_edge = Some(e2.rev());
     dcel.faces[f1.index()].adjacent_edge = Some(edge_handle);
 
     (nv, [edge_handle, e2])
@@ -726,23 +747,23 @@ where
 
     let f0 = edge.face;
     let f1 = twin.face;
-    let f2 = FixedFaceHandle::new(dcel.faces.len());
-    let f3 = FixedFaceHandle::new(dcel.faces.len() + 1);
+    let f2 = dcel.next_face_handle(0);
+    let f3 = dcel.next_face_handle(1);
 
     let e0 = edge_handle;
     let t0 = e0.rev();
-    let e1 = FixedDirectedEdgeHandle::new_normalized(dcel.edges.len());
+    let e1 = dcel.next_undirected_edge_handle(0).normalized();
     let t1 = e1.rev();
-    let e2 = FixedDirectedEdgeHandle::new_normalized(dcel.edges.len() + 1);
+    let e2 = dcel.next_undirected_edge_handle(1).normalized();
     let t2 = e2.rev();
-    let e3 = FixedDirectedEdgeHandle::new_normalized(dcel.edges.len() + 2);
+    let e3 = dcel.next_undirected_edge_handle(2).normalized();
     let t3 = e3.rev();
     let ep = edge.prev;
     let en = edge.next;
     let tn = twin.next;
     let tp = twin.prev;
 
-    let v0 = FixedVertexHandle::new(dcel.vertices.len());
+    let v0 = dcel.next_vertex_handle();
     let v1 = edge.origin;
     let v2 = dcel.half_edge(tp).origin;
     let v3 = twin.origin;
@@ -821,9 +842,12 @@ where
 
     *dcel.half_edge_mut(e0) = edge0;
     *dcel.half_edge_mut(t0) = twin0;
-    dcel.edges.push(EdgeEntry::new(edge1, twin1));
-    dcel.edges.push(EdgeEntry::new(edge2, twin2));
-    dcel.edges.push(EdgeEntry::new(edge3, twin3));
+    dcel.invalidate_undirected_edge_slot(e0.as_undirected().index());
+    invalidate_changed_face(dcel, f0);
+    invalidate_changed_face(dcel, f1);
+    dcel.push_edge(EdgeEntry::new(edge1, twin1));
+    dcel.push_edge(EdgeEntry::new(edge2, twin2));
+    dcel.push_edge(EdgeEntry::new(edge3, twin3));
 
     dcel.half_edge_mut(en).next = e3;
     dcel.half_edge_mut(en).prev = t2;
@@ -836,13 +860,13 @@ where
     dcel.half_edge_mut(tn).next = e1;
     dcel.half_edge_mut(ep).prev = t3;
 
-    dcel.vertices.push(new_vertex_entry);
-    dcel.vertices[v3.index()].out_edge = Some(e2);
+    dcel.push_vertex(new_vertex_entry);
+    dcel.vertex_entry_mut(v3).out_edge = Some(e2);
 
     dcel.faces[f0.index()].adjacent_edge = Some(e0);
     dcel.faces[f1.index()].adjacent_edge = Some(e1);
-    dcel.faces.push(face2);
-    dcel.faces.push(face3);
+    dcel.push_face(face2);
+    dcel.push_face(face3);
 
     (v0.adjust_inner_outer(), [e0, e2.rev()])
 }
@@ -851,12 +875,11 @@ pub fn insert_first_vertex<V, DE, UE, F>(
     dcel: &mut Dcel<V, DE, UE, F>,
     vertex: V,
 ) -> FixedVertexHandle {
-    assert!(dcel.vertices.is_empty());
-    dcel.vertices.push(VertexEntry {
+    assert_eq!(dcel.num_vertices(), 0);
+    dcel.push_vertex(VertexEntry {
         data: vertex,
         out_edge: None,
-    });
-    FixedVertexHandle::new(0)
+    })
 }
 
 pub fn insert_second_vertex<V, DE, UE, F>(
@@ -867,12 +890,15 @@ where
     DE: Default,
     UE: Default,
 {
-    let first_vertex = FixedVertexHandle::new(0);
-    let second_vertex = FixedVertexHandle::new(1);
-    let normalized = FixedDirectedEdgeHandle::new_normalized(0);
+    let first_vertex = dcel
+        .fixed_vertices()
+        .next()
+        .expect("Expected a single vertex");
+    let second_vertex = dcel.next_vertex_handle();
+    let normalized = dcel.next_undirected_edge_handle(0).normalized();
     let not_normalized = normalized.rev();
 
-    dcel.edges.push(EdgeEntry::new(
+    dcel.push_edge(EdgeEntry::new(
         HalfEdgeEntry {
             next: not_normalized,
             prev: not_normalized,
@@ -887,11 +913,11 @@ where
         },
     ));
 
-    dcel.vertices.push(VertexEntry {
+    dcel.push_vertex(VertexEntry {
         data: vertex,
         out_edge: Some(not_normalized),
     });
-    dcel.vertices[0].out_edge = Some(normalized);
+    dcel.vertex_entry_mut(first_vertex).out_edge = Some(normalized);
     dcel.faces[OUTER_FACE_HANDLE.index()].adjacent_edge = Some(normalized);
     second_vertex
 }
@@ -936,20 +962,20 @@ where
 
     let e1 = dcel.half_edge(e0).next;
     let e2 = dcel.half_edge(e1).next;
-    let e3 = FixedDirectedEdgeHandle::new_normalized(dcel.edges.len());
+    let e3 = dcel.next_undirected_edge_handle(0).normalized();
     let e4 = e3.rev();
-    let e5 = FixedDirectedEdgeHandle::new_normalized(dcel.edges.len() + 1);
+    let e5 = dcel.next_undirected_edge_handle(1).normalized();
     let e6 = e5.rev();
-    let e7 = FixedDirectedEdgeHandle::new_normalized(dcel.edges.len() + 2);
+    let e7 = dcel.next_undirected_edge_handle(2).normalized();
     let e8 = e7.rev();
 
-    let v = FixedVertexHandle::new(dcel.vertices.len());
+    let v = dcel.next_vertex_handle();
     let v0 = dcel.half_edge(e0).origin;
     let v1 = dcel.half_edge(e1).origin;
     let v2 = dcel.half_edge(e2).origin;
 
-    let f1 = FixedFaceHandle::new(dcel.faces.len());
-    let f2 = FixedFaceHandle::new(dcel.faces.len() + 1);
+    let f1 = dcel.next_face_handle(0);
+    let f2 = dcel.next_face_handle(1);
 
     let face1 = FaceEntry {
         adjacent_edge: Some(e1),
@@ -961,14 +987,16 @@ where
         data: F::default(),
     };
 
-    dcel.faces.push(face1);
-    dcel.faces.push(face2);
+    dcel.push_face(face1);
+    dcel.push_face(face2);
 
     let vertex = VertexEntry {
         out_edge: Some(e4),
         data: vertex,
     };
-    dcel.vertices.push(vertex);
+    dcel.push_vertex(vertex);
+
+    dcel.invalidate_face_slot(f0.index());
 
     dcel.half_edge_mut(e0).prev = e8;
     dcel.half_edge_mut(e0).next = e3;
@@ -1021,9 +1049,9 @@ where
         face: f0.adjust_inner_outer(),
     };
 
-    dcel.edges.push(EdgeEntry::new(edge3, edge4));
-    dcel.edges.push(EdgeEntry::new(edge5, edge6));
-    dcel.edges.push(EdgeEntry::new(edge7, edge8));
+    dcel.push_edge(EdgeEntry::new(edge3, edge4));
+    dcel.push_edge(EdgeEntry::new(edge5, edge6));
+    dcel.push_edge(EdgeEntry::new(edge7, edge8));
 
     // Return inserted vertex handle
     v.adjust_inner_outer()
@@ -1042,6 +1070,10 @@ where
         vertices: Vec::new(),
         edges: Vec::new(),
         faces: vec![outer_face],
+        stable_handles: false,
+        free_lists: Default::default(),
+        generations: Default::default(),
+        vertex_slots: Default::default(),
     }
 }
 
@@ -1079,11 +1111,16 @@ pub fn flip_cw<V, DE, UE, F>(dcel: &mut Dcel<V, DE, UE, F>, e: FixedUndirectedEd
     dcel.half_edge_mut(ep).prev = t;
     dcel.half_edge_mut(ep).face = t_face;
 
-    dcel.vertices[e_origin.index()].out_edge = Some(tn);
-    dcel.vertices[t_origin.index()].out_edge = Some(en);
+    dcel.vertex_entry_mut(e_origin).out_edge = Some(tn);
+    dcel.vertex_entry_mut(t_origin).out_edge = Some(en);
 
     dcel.faces[e_face.index()].adjacent_edge = Some(e);
     dcel.faces[t_face.index()].adjacent_edge = Some(t);
+
+    // The flipped edge connects two other vertices now and both faces have changed.
+    dcel.invalidate_undirected_edge_slot(e.as_undirected().index());
+    invalidate_changed_face(dcel, e_face);
+    invalidate_changed_face(dcel, t_face);
 }
 
 /// Vertex removal has two stages: First, the vertex is disconnected from its surroundings (isolated).
@@ -1095,21 +1132,240 @@ pub fn cleanup_isolated_vertex<V, DE, UE, F>(
     // Remove disconnected edges, faces and the vertex
     isolated.edges_to_remove.sort_unstable();
     for edge in isolated.edges_to_remove.iter().rev() {
-        swap_remove_undirected_edge(dcel, *edge);
+        remove_undirected_edge(dcel, *edge);
     }
 
     isolated.faces_to_remove.sort_unstable();
     for face in isolated.faces_to_remove.iter().rev() {
-        swap_remove_face(dcel, *face);
+        remove_face(dcel, *face);
+    }
+}
+
+/// Removes a vertex from the DCEL.
+///
+/// If stable handles are enabled, the vertex's slot is left empty and no other vertex is moved.
+/// Otherwise, this is equal to `swap_remove_vertex`.
+pub fn remove_vertex<V, DE, UE, F>(
+    dcel: &mut Dcel<V, DE, UE, F>,
+    vertex_handle: FixedVertexHandle,
+) -> RemovalResult<V> {
+    if dcel.stable_handles {
+        RemovalResult {
+            removed_vertex: dcel.free_vertex(vertex_handle),
+            swapped_in_vertex: None,
+        }
+    } else {
+        swap_remove_vertex(dcel, vertex_handle)
+    }
+}
+
+fn remove_undirected_edge<V, DE, UE, F>(
+    dcel: &mut Dcel<V, DE, UE, F>,
+    edge_handle: FixedUndirectedEdgeHandle,
+) {
+    if dcel.stable_handles {
+        dcel.free_undirected_edge(edge_handle);
+    } else {
+        swap_remove_undirected_edge(dcel, edge_handle);
+    }
+}
+
+fn remove_face<V, DE, UE, F>(dcel: &mut Dcel<V, DE, UE, F>, face: FixedFaceHandle<InnerTag>) {
+    if dcel.stable_handles {
+        dcel.free_face(face);
+    } else {
+        swap_remove_face(dcel, face);
+    }
+}
+
+/// Enables or disables stable handles.
+///
+/// Disabling stable handles closes all gaps that have been left by removed elements. This will
+/// move elements to lower indices.
+pub fn set_stable_handles<V, DE, UE, F>(
+    dcel: &mut Dcel<V, DE, UE, F>,
+    stable_handles: bool,
+) -> Vec<(FixedVertexHandle, FixedVertexHandle)> {
+    let mut swapped_vertices = Vec::new();
+    if !stable_handles {
+        swapped_vertices = remove_gaps(dcel);
+    }
+    dcel.stable_handles = stable_handles;
+    swapped_vertices
+}
+
+/// Reorders all vertices, edges and faces and closes all gaps left by removed elements.
+///
+/// `vertex_order` must contain the handle of every vertex exactly once. Edges and faces are
+/// ordered by their first occurrence when visiting the out edges of all vertices in their new
+/// order. This keeps elements that are close to each other close in memory if the vertices are
+/// sorted accordingly. The outer face keeps its index.
+///
+/// Returns the mapping from old to new handles.
+pub fn reorder<V, DE, UE, F>(
+    dcel: &mut Dcel<V, DE, UE, F>,
+    vertex_order: &[FixedVertexHandle],
+) -> HandleRemapping {
+    assert_eq!(vertex_order.len(), dcel.num_vertices());
+
+    let num_vertex_slots = dcel.num_vertex_slots();
+    let mut remapping = HandleRemapping {
+        vertices: vec![None; num_vertex_slots],
+        undirected_edges: vec![None; dcel.edges.len()],
+        faces: vec![None; dcel.faces.len()],
+    };
+    remapping.faces[0] = Some(OUTER_FACE_HANDLE);
+
+    let mut edge_order = Vec::with_capacity(dcel.num_undirected_edges());
+    let mut face_order = Vec::with_capacity(dcel.num_faces());
+    face_order.push(0);
+
+    for (new_index, vertex) in vertex_order.iter().enumerate() {
+        let new_vertex = &mut remapping.vertices[vertex.index()];
+        assert!(new_vertex.is_none(), "Vertex order contains duplicates");
+        *new_vertex = Some(FixedVertexHandle::new(new_index));
+
+        for edge in dcel.vertex(*vertex).out_edges() {
+            let undirected = edge.fix().as_undirected();
+            let new_edge = &mut remapping.undirected_edges[undirected.index()];
+            if new_edge.is_none() {
+                *new_edge = Some(FixedUndirectedEdgeHandle::new(edge_order.len()));
+                edge_order.push(undirected.index());
+            }
+
+            let face = edge.face().fix();
+            let new_face = &mut remapping.faces[face.index()];
+            if new_face.is_none() {
+                *new_face = Some(FixedFaceHandle::new(face_order.len()));
+                face_order.push(face.index());
+            }
+        }
+    }
+
+    let new_vertex = |handle: FixedVertexHandle| remapping.vertex(handle).unwrap();
+    let new_edge = |handle: FixedDirectedEdgeHandle| remapping.directed_edge(handle).unwrap();
+    let new_face = |handle: FixedFaceHandle<PossiblyOuterTag>| remapping.face(handle).unwrap();
+
+    let entry_indices: Vec<_> = vertex_order
+        .iter()
+        .map(|vertex| dcel.vertex_slots.entry(vertex.index()))
+        .collect();
+    let mut vertices: Vec<_> = core::mem::take(&mut dcel.vertices)
+        .into_iter()
+        .map(Some)
+        .collect();
+    dcel.vertices = entry_indices
+        .iter()
+        .map(|&index| {
+            let mut entry = vertices[index].take().unwrap();
+            entry.out_edge = entry.out_edge.map(new_edge);
+            entry
+        })
+        .collect();
+    dcel.vertex_slots = Default::default();
+
+    let mut edges: Vec<_> = core::mem::take(&mut dcel.edges)
+        .into_iter()
+        .map(Some)
+        .collect();
+    dcel.edges = edge_order
+        .iter()
+        .map(|&index| {
+            let mut entry = edges[index].take().unwrap();
+            for half_edge in &mut entry.entries {
+                half_edge.next = new_edge(half_edge.next);
+                half_edge.prev = new_edge(half_edge.prev);
+                half_edge.face = new_face(half_edge.face);
+                half_edge.origin = new_vertex(half_edge.origin);
+            }
+            entry
+        })
+        .collect();
+
+    let mut faces: Vec<_> = core::mem::take(&mut dcel.faces)
+        .into_iter()
+        .map(Some)
+        .collect();
+    dcel.faces = face_order
+        .iter()
+        .map(|&index| {
+            let mut entry = faces[index].take().unwrap();
+            entry.adjacent_edge = entry.adjacent_edge.map(new_edge);
+            entry
+        })
+        .collect();
+
+    dcel.free_lists = Default::default();
+
+    // Invalidates all slots whose element has changed, including the slots of moved elements
+    // that are now beyond the end of their `Vec`.
+    let order = vertex_order.iter().map(|vertex| vertex.index());
+    for slot in changed_slots(order, num_vertex_slots) {
+        dcel.invalidate_vertex_slot(slot);
+    }
+    for slot in changed_slots(edge_order.iter().copied(), edges.len()) {
+        dcel.invalidate_undirected_edge_slot(slot);
+    }
+    for slot in changed_slots(face_order.iter().copied(), faces.len()) {
+        dcel.invalidate_face_slot(slot);
+    }
+
+    remapping
+}
+
+/// Returns all slots that contain a different element after reordering.
+///
+/// `order` contains the old index of the element at each new index.
+fn changed_slots(
+    order: impl ExactSizeIterator<Item = usize>,
+    old_len: usize,
+) -> impl Iterator<Item = usize> {
+    let new_len = order.len();
+    order
+        .enumerate()
+        .filter(|(new_index, old_index)| new_index != old_index)
+        .map(|(new_index, _)| new_index)
+        .chain(new_len..old_len)
+}
+
+/// Removes all gaps left by removed elements by swapping in the last element of each `Vec`.
+///
+/// Returns each vertex gap together with the previous handle of the vertex that was swapped in.
+fn remove_gaps<V, DE, UE, F>(
+    dcel: &mut Dcel<V, DE, UE, F>,
+) -> Vec<(FixedVertexHandle, FixedVertexHandle)> {
+    let free_lists = core::mem::take(&mut dcel.free_lists);
+
+    // Gaps are processed in descending order. This ensures that the swapped in element is never
+    // a gap itself.
+    for index in free_lists.undirected_edges.into_sorted().into_iter().rev() {
+        swap_remove_undirected_edge(dcel, FixedUndirectedEdgeHandle::new(index));
+    }
+
+    for index in free_lists.faces.into_sorted().into_iter().rev() {
+        swap_remove_face(dcel, FixedFaceHandle::new(index));
+    }
+
+    let mut swapped_vertices = Vec::new();
+    for index in free_lists.vertices.into_sorted().into_iter().rev() {
+        let gap = FixedVertexHandle::new(index);
+        if let Some(swapped_in) = dcel.close_vertex_gap(gap) {
+            swapped_vertices.push((gap, swapped_in));
+        }
     }
+    dcel.normalize_vertex_slots();
+    swapped_vertices
 }
 
 fn swap_remove_undirected_edge<V, DE, UE, F>(
     dcel: &mut Dcel<V, DE, UE, F>,
     edge_handle: FixedUndirectedEdgeHandle,
 ) {
+    // Invalidates handles to both the removed and the swapped in edge
+    dcel.invalidate_undirected_edge_slot(edge_handle.index());
+    dcel.invalidate_undirected_edge_slot(dcel.edges.len() - 1);
     dcel.edges.swap_remove(edge_handle.index());
-    if dcel.num_undirected_edges() > edge_handle.index() {
+    if dcel.edges.len() > edge_handle.index() {
         let directed = edge_handle.as_directed();
         fix_handle_swap(dcel, directed.rev());
         fix_handle_swap(dcel, directed);
@@ -1124,8 +1380,8 @@ fn fix_handle_swap<V, DE, UE, F>(
     // Make sure to update all references to this edge
     //
     // Since this method gets only called as part of a swap_remove, the edge that got
-    // swapped in always had the index dcel.num_undirected_edges .
-    let old_handle = FixedUndirectedEdgeHandle::new(dcel.num_undirected_edges());
+    // swapped in always had the index dcel.edges.len() .
+    let old_handle = FixedUndirectedEdgeHandle::new(dcel.edges.len());
     let old_to_new = |handle: FixedDirectedEdgeHandle| {
         let undirected = handle.as_undirected();
         if undirected == old_handle {
@@ -1148,7 +1404,7 @@ fn fix_handle_swap<V, DE, UE, F>(
 
     let edge_origin = dcel.half_edge(edge_handle).origin;
     let edge_face = dcel.half_edge(edge_handle).face;
-    dcel.vertices[edge_origin.index()].out_edge = Some(edge_handle);
+    dcel.vertex_entry_mut(edge_origin).out_edge = Some(edge_handle);
     dcel.faces[edge_face.index()].adjacent_edge = Some(edge_handle);
 }
 
@@ -1162,6 +1418,12 @@ pub fn swap_remove_vertex<V, DE, UE, F>(
     dcel: &mut Dcel<V, DE, UE, F>,
     vertex_handle: FixedVertexHandle,
 ) -> RemovalResult<V> {
+    // Gaps of removed vertices only exist if stable handles are enabled
+    debug_assert!(!dcel.has_removed_elements());
+    dcel.normalize_vertex_slots();
+
+    dcel.invalidate_vertex_slot(vertex_handle.index());
+    dcel.invalidate_vertex_slot(dcel.vertices.len() - 1);
     let data = dcel.vertices.swap_remove(vertex_handle.index()).data;
     let mut swapped_in_vertex = None;
     if dcel.vertices.len() != vertex_handle.index() {
@@ -1189,6 +1451,8 @@ pub fn swap_remove_vertex<V, DE, UE, F>(
 /// to fix the site around the removed face. This method only ensure that any references to
 /// the swapped in faces are updated accordingly.
 fn swap_remove_face<V, DE, UE, F>(dcel: &mut Dcel<V, DE, UE, F>, face: FixedFaceHandle<InnerTag>) {
+    dcel.invalidate_face_slot(face.index());
+    dcel.invalidate_face_slot(dcel.faces.len() - 1);
     dcel.faces.swap_remove(face.index());
     if dcel.faces.len() > face.index() {
         let neighs: SmallVec<[_; 3]> = dcel
@@ -1219,16 +1483,12 @@ fn remove_when_one_vertex_left<V, DE, UE, F>(
     dcel: &mut Dcel<V, DE, UE, F>,
     vertex_to_remove: FixedVertexHandle,
 ) -> RemovalResult<V> {
-    assert_eq!(
-        vertex_to_remove.index(),
-        0,
+    assert!(
+        dcel.is_vertex_live(vertex_to_remove),
         "Attempting to remove invalid vertex"
     );
 
-    RemovalResult {
-        removed_vertex: dcel.vertices.pop().unwrap().data,
-        swapped_in_vertex: None,
-    }
+    remove_vertex(dcel, vertex_to_remove)
 }
 
 fn remove_when_two_vertices_left<V, DE, UE, F>(
@@ -1239,20 +1499,17 @@ fn remove_when_two_vertices_left<V, DE, UE, F>(
     assert_eq!(dcel.num_vertices(), 2);
     assert_eq!(dcel.num_directed_edges(), 2);
 
-    let swapped_in_vertex = if vertex_to_remove.index() == 1 {
-        None
-    } else {
-        Some(FixedVertexHandle::new(1))
-    };
+    let edge = dcel
+        .vertex(vertex_to_remove)
+        .out_edge()
+        .expect("Expected an out edge")
+        .fix();
+    let remaining_vertex = dcel.directed_edge(edge).to().fix();
 
-    let result = dcel.vertices.swap_remove(vertex_to_remove.index()).data;
     dcel.faces[OUTER_FACE_HANDLE.index()].adjacent_edge = None;
-    dcel.vertices[0].out_edge = None;
-    dcel.edges.clear();
-    RemovalResult {
-        removed_vertex: result,
-        swapped_in_vertex,
-    }
+    dcel.vertex_entry_mut(remaining_vertex).out_edge = None;
+    remove_undirected_edge(dcel, edge.as_undirected());
+    remove_vertex(dcel, vertex_to_remove)
 }
 
 fn remove_when_all_vertices_on_line<V, DE, UE, F>(
@@ -1273,12 +1530,12 @@ fn remove_when_all_vertices_on_line<V, DE, UE, F>(
 
             dcel.half_edge_mut(o_next).prev = o_next.rev();
             dcel.half_edge_mut(o_next.rev()).next = o_next;
-            dcel.vertices[vertex_to_update.index()].out_edge = Some(o_next);
+            dcel.vertex_entry_mut(vertex_to_update).out_edge = Some(o_next);
             dcel.faces[OUTER_FACE_HANDLE.index()].adjacent_edge = Some(o_next);
 
-            swap_remove_undirected_edge(dcel, out_edge1.as_undirected());
+            remove_undirected_edge(dcel, out_edge1.as_undirected());
 
-            swap_remove_vertex(dcel, vertex_to_remove)
+            remove_vertex(dcel, vertex_to_remove)
         }
         [e1, e2] => {
             // Topology sketch (with v0 = vertex_to_remove, t1 = e1.rev()):
@@ -1303,13 +1560,13 @@ fn remove_when_all_vertices_on_line<V, DE, UE, F>(
                 dcel.half_edge_mut(t1.rev()).prev = t2_prev;
             }
 
-            dcel.vertices[e2_to.index()].out_edge = Some(t1.rev());
+            dcel.vertex_entry_mut(e2_to).out_edge = Some(t1.rev());
             dcel.half_edge_mut(t1.rev()).origin = e2_to;
 
             dcel.faces[OUTER_FACE_HANDLE.index()].adjacent_edge = Some(t1);
 
-            let result = swap_remove_vertex(dcel, vertex_to_remove);
-            swap_remove_undirected_edge(dcel, e2.as_undirected());
+            let result = remove_vertex(dcel, vertex_to_remove);
+            remove_undirected_edge(dcel, e2.as_undirected());
             result
         }
         _ => panic!("Vertex with invalid out edges found. This is a bug."),
@@ -1415,6 +1672,10 @@ mod test {
             vertices: vec![vertex0, vertex1, vertex2],
             faces: vec![face0, face1],
             edges: vec![edge0_1, edge2_3, edge4_5],
+            stable_handles: false,
+            free_lists: Default::default(),
+            generations: Default::default(),
+            vertex_slots: Default::default(),
         }
     }
 
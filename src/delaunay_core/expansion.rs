@@ -0,0 +1,161 @@
+//! Exact floating point arithmetic based on floating point expansions.
+//!
+//! An expansion represents a number as the exact sum of non-overlapping `f64` components,
+//! ordered by increasing magnitude. Sums, differences and products of expansions are exact as
+//! long as no component overflows or underflows.
+//!
+//! For more details, refer to
+//! Jonathan Richard Shewchuk. Adaptive Precision Floating-Point Arithmetic and Fast Robust
+//! Geometric Predicates. <https://doi.org/10.1007/PL00009321>
+
+use alloc::vec::Vec;
+
+/// An exact sum of non-overlapping components, ordered by increasing magnitude. Contains no
+/// zero components.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct Expansion(Vec<f64>);
+
+/// Returns `(x, y)` with `x = fl(a + b)` and `x + y = a + b`.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    let b_roundoff = b - b_virtual;
+    let a_roundoff = a - a_virtual;
+    (x, a_roundoff + b_roundoff)
+}
+
+/// Like [two_sum], but requires `|a| >= |b|`.
+fn fast_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let x = a + b;
+    let b_virtual = x - a;
+    (x, b - b_virtual)
+}
+
+/// Splits a value into two halves with at most 26 significant bits each.
+fn split(a: f64) -> (f64, f64) {
+    const SPLITTER: f64 = 134_217_729.0; // 2^27 + 1
+    let c = SPLITTER * a;
+    let a_big = c - a;
+    let a_hi = c - a_big;
+    (a_hi, a - a_hi)
+}
+
+/// Returns `(x, y)` with `x = fl(a * b)` and `x + y = a * b`.
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let x = a * b;
+    let (a_hi, a_lo) = split(a);
+    let (b_hi, b_lo) = split(b);
+    let err1 = x - a_hi * b_hi;
+    let err2 = err1 - a_lo * b_hi;
+    let err3 = err2 - a_hi * b_lo;
+    (x, a_lo * b_lo - err3)
+}
+
+impl Expansion {
+    pub(crate) fn from_value(value: f64) -> Self {
+        Self::from_components([value])
+    }
+
+    /// Returns the exact difference `a - b`.
+    pub(crate) fn difference(a: f64, b: f64) -> Self {
+        let (x, y) = two_sum(a, -b);
+        Self::from_components([y, x])
+    }
+
+    fn from_components(components: impl IntoIterator<Item = f64>) -> Self {
+        Expansion(components.into_iter().filter(|c| *c != 0.0).collect())
+    }
+
+    /// Returns an approximation of the expansion's value with the correct sign.
+    pub(crate) fn estimate(&self) -> f64 {
+        self.0.iter().sum()
+    }
+
+    pub(crate) fn negate(mut self) -> Self {
+        for component in &mut self.0 {
+            *component = -*component;
+        }
+        self
+    }
+
+    /// Adds a single value to the expansion.
+    fn grow(&self, b: f64) -> Self {
+        let mut result = Vec::with_capacity(self.0.len() + 1);
+        let mut q = b;
+        for &component in &self.0 {
+            let (sum, error) = two_sum(q, component);
+            if error != 0.0 {
+                result.push(error);
+            }
+            q = sum;
+        }
+        if q != 0.0 {
+            result.push(q);
+        }
+        Expansion(result)
+    }
+
+    pub(crate) fn add(&self, other: &Self) -> Self {
+        other
+            .0
+            .iter()
+            .fold(self.clone(), |sum, component| sum.grow(*component))
+    }
+
+    pub(crate) fn sub(&self, other: &Self) -> Self {
+        self.add(&other.clone().negate())
+    }
+
+    /// Multiplies the expansion with a single value.
+    fn scale(&self, b: f64) -> Self {
+        let mut result = Vec::with_capacity(self.0.len() * 2);
+        let mut components = self.0.iter();
+        let Some(&first) = components.next() else {
+            return Expansion::default();
+        };
+
+        let (mut q, error) = two_product(first, b);
+        result.push(error);
+        for &component in components {
+            let (product, product_error) = two_product(component, b);
+            let (sum, error) = two_sum(q, product_error);
+            result.push(error);
+            let (sum, error) = fast_two_sum(product, sum);
+            result.push(error);
+            q = sum;
+        }
+        result.push(q);
+        Self::from_components(result)
+    }
+
+    pub(crate) fn mul(&self, other: &Self) -> Self {
+        other.0.iter().fold(Expansion::default(), |sum, component| {
+            sum.add(&self.scale(*component))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Expansion;
+
+    #[test]
+    fn test_exact_arithmetic() {
+        let big = Expansion::from_value(1.0e20);
+        let small = Expansion::from_value(1.0);
+        let sum = big.add(&small);
+        assert_eq!(sum.sub(&big), small);
+        assert_eq!(sum.sub(&big).sub(&small).estimate(), 0.0);
+
+        // (2^30 + 1)^2 - 2^60 - 2^31 = 1 is not representable with a single f64 operation
+        let a = Expansion::from_value(1073741825.0);
+        let product = a.mul(&a);
+        let difference = product
+            .sub(&Expansion::from_value(1152921504606846976.0))
+            .sub(&Expansion::from_value(2147483648.0));
+        assert_eq!(difference.estimate(), 1.0);
+
+        assert_eq!(Expansion::difference(1.0e20, -1.0).sub(&big), small);
+    }
+}
@@ -1,5 +1,6 @@
 use core::convert::TryInto;
 
+use super::super::dcel::FreeList;
 use super::super::Dcel;
 use super::public_handles::{InnerOuterMarker, PossiblyOuterTag};
 use super::FixedVertexHandle;
@@ -8,8 +9,25 @@ use super::FixedVertexHandle;
 use serde::{Deserialize, Serialize};
 
 pub trait DelaunayElementType: Sized + Default {
+    /// The number of handles that refer to a single storage slot.
+    const HANDLES_PER_SLOT: usize = 1;
+
+    /// Returns the number of element handles, including handles of removed elements.
     fn num_elements<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> usize;
+
+    /// Returns the storage slots of all removed elements.
+    fn removed_slots<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> &FreeList;
+}
+
+/// Element types that support checked handles.
+pub trait CheckedElementType: DelaunayElementType + Copy {
+    /// Returns the generation of the storage slot that contains the handle with the given index.
+    fn generation<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>, index: usize) -> u32;
+
+    /// Returns `true` if the given index refers to an element that has not been removed.
+    fn is_live<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>, index: usize) -> bool;
 }
+
 /// Internal type definition that is only exposed for documentation purposes.
 ///
 /// Rust will currently not generate documentation for type definitions depending on
@@ -36,6 +54,54 @@ impl<Type, InnerOuter: InnerOuterMarker> core::fmt::Debug for FixedHandleImpl<Ty
     }
 }
 
+/// Internal type definition that is only exposed for documentation purposes.
+///
+/// Rust will currently not generate documentation for type definitions depending on
+/// `pub(crate)` types, see [#32077](https://github.com/rust-lang/rust/issues/32077).
+///
+/// Do not use these types. Their removal will not be considered a breaking change.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde")
+)]
+pub struct CheckedHandleImpl<Type, InnerOuter: InnerOuterMarker> {
+    handle: FixedHandleImpl<Type, InnerOuter>,
+    generation: u32,
+}
+
+impl<Type, InnerOuter: InnerOuterMarker> core::fmt::Debug for CheckedHandleImpl<Type, InnerOuter> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CheckedHandle")
+            .field("index", &self.handle.index)
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
+
+impl<Type: Copy, InnerOuter: InnerOuterMarker> CheckedHandleImpl<Type, InnerOuter> {
+    pub(crate) fn new(handle: FixedHandleImpl<Type, InnerOuter>, generation: u32) -> Self {
+        Self { handle, generation }
+    }
+
+    /// Returns the underlying fixed handle.
+    ///
+    /// The fixed handle is returned without checking if the element still exists.
+    pub fn fix(&self) -> FixedHandleImpl<Type, InnerOuter> {
+        self.handle
+    }
+
+    /// Returns the generation of the referenced element.
+    ///
+    /// The generation is increased whenever an element is removed from the triangulation or moved
+    /// to another index. A checked handle is only valid if its generation matches the current
+    /// generation of its element.
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
 pub const fn new_fixed_face_handle(index: usize) -> FixedHandleImpl<FaceTag, PossiblyOuterTag> {
     FixedHandleImpl {
         index: index as u32,
@@ -177,45 +243,143 @@ pub struct UndirectedVoronoiEdgeTag;
 pub struct VoronoiVertexTag;
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Default, Hash)]
 pub struct VoronoiFaceTag;
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Default, Hash)]
+pub struct DirectedPowerEdgeTag;
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Default, Hash)]
+pub struct PowerCellTag;
 
 impl DelaunayElementType for VertexTag {
     fn num_elements<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> usize {
-        dcel.num_vertices()
+        dcel.num_vertex_slots()
+    }
+
+    fn removed_slots<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> &FreeList {
+        &dcel.free_lists.vertices
     }
 }
 
 impl DelaunayElementType for DirectedEdgeTag {
+    const HANDLES_PER_SLOT: usize = 2;
+
     fn num_elements<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> usize {
-        dcel.num_directed_edges()
+        dcel.num_undirected_edge_slots() * 2
+    }
+
+    fn removed_slots<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> &FreeList {
+        &dcel.free_lists.undirected_edges
     }
 }
 
 impl DelaunayElementType for UndirectedEdgeTag {
     fn num_elements<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> usize {
-        dcel.num_undirected_edges()
+        dcel.num_undirected_edge_slots()
+    }
+
+    fn removed_slots<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> &FreeList {
+        &dcel.free_lists.undirected_edges
     }
 }
 
 impl DelaunayElementType for FaceTag {
     fn num_elements<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> usize {
-        dcel.num_faces()
+        dcel.num_face_slots()
+    }
+
+    fn removed_slots<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> &FreeList {
+        &dcel.free_lists.faces
+    }
+}
+
+impl CheckedElementType for VertexTag {
+    fn generation<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>, index: usize) -> u32 {
+        dcel.vertex_generation(FixedVertexHandle::new(index))
+    }
+
+    fn is_live<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>, index: usize) -> bool {
+        dcel.is_vertex_live(FixedVertexHandle::new(index))
+    }
+}
+
+impl CheckedElementType for DirectedEdgeTag {
+    fn generation<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>, index: usize) -> u32 {
+        UndirectedEdgeTag::generation(dcel, index / 2)
+    }
+
+    fn is_live<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>, index: usize) -> bool {
+        UndirectedEdgeTag::is_live(dcel, index / 2)
+    }
+}
+
+impl CheckedElementType for UndirectedEdgeTag {
+    fn generation<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>, index: usize) -> u32 {
+        dcel.undirected_edge_generation(FixedHandleImpl::new(index))
+    }
+
+    fn is_live<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>, index: usize) -> bool {
+        dcel.is_undirected_edge_live(FixedHandleImpl::new(index))
+    }
+}
+
+impl CheckedElementType for FaceTag {
+    fn generation<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>, index: usize) -> u32 {
+        dcel.face_generation(FixedHandleImpl::<FaceTag, PossiblyOuterTag>::new(index))
+    }
+
+    fn is_live<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>, index: usize) -> bool {
+        dcel.is_face_live(FixedHandleImpl::<FaceTag, PossiblyOuterTag>::new(index))
     }
 }
 
 impl DelaunayElementType for VoronoiFaceTag {
     fn num_elements<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> usize {
-        dcel.num_vertices()
+        dcel.num_vertex_slots()
+    }
+
+    fn removed_slots<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> &FreeList {
+        &dcel.free_lists.vertices
     }
 }
 
 impl DelaunayElementType for DirectedVoronoiEdgeTag {
+    const HANDLES_PER_SLOT: usize = 2;
+
     fn num_elements<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> usize {
-        dcel.num_directed_edges()
+        dcel.num_undirected_edge_slots() * 2
+    }
+
+    fn removed_slots<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> &FreeList {
+        &dcel.free_lists.undirected_edges
     }
 }
 
 impl DelaunayElementType for UndirectedVoronoiEdgeTag {
     fn num_elements<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> usize {
-        dcel.num_undirected_edges()
+        dcel.num_undirected_edge_slots()
+    }
+
+    fn removed_slots<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> &FreeList {
+        &dcel.free_lists.undirected_edges
+    }
+}
+
+impl DelaunayElementType for PowerCellTag {
+    fn num_elements<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> usize {
+        dcel.num_vertex_slots()
+    }
+
+    fn removed_slots<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> &FreeList {
+        &dcel.free_lists.vertices
+    }
+}
+
+impl DelaunayElementType for DirectedPowerEdgeTag {
+    const HANDLES_PER_SLOT: usize = 2;
+
+    fn num_elements<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> usize {
+        dcel.num_undirected_edge_slots() * 2
+    }
+
+    fn removed_slots<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> &FreeList {
+        &dcel.free_lists.undirected_edges
     }
 }
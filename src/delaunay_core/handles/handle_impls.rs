@@ -5,7 +5,7 @@ use super::iterators::CircularIterator;
 use super::iterators::NextBackFn;
 use super::public_handles::*;
 use crate::CdtEdge;
-use crate::{HasPosition, LineSideInfo, Point2};
+use crate::{HasPosition, HasWeight, LineSideInfo, Point2};
 use core::cmp::Ordering;
 use core::fmt::Debug;
 use core::hash::{Hash, Hasher};
@@ -78,7 +78,7 @@ impl FixedDirectedEdgeHandle {
     /// For every directed edge pair, one edge is marked as the normalized edge. This information
     /// is used to hook up a directed edge handle with its correct half edge storage.
     #[inline]
-    pub(in super::super) fn is_normalized(self) -> bool {
+    pub(crate) fn is_normalized(self) -> bool {
         // Use the last bit to store if this edge is normalized
         self.index() & 0x1 == 0x0
     }
@@ -182,6 +182,25 @@ impl<V, DE, UE, F, Type: Copy + Default, InnerOuter: InnerOuterMarker>
     }
 }
 
+impl<V, DE, UE, F, Type: CheckedElementType, InnerOuter: InnerOuterMarker>
+    DynamicHandleImpl<'_, V, DE, UE, F, Type, InnerOuter>
+{
+    /// Converts this dynamic handle to a checked handle.
+    ///
+    /// Similar to fixed handles, checked handles do not keep a reference to the triangulation.
+    /// Additionally, they can detect if their element has been removed in the meantime. Use
+    /// [Triangulation::try_vertex](crate::Triangulation::try_vertex) and its siblings to resolve
+    /// them.
+    ///
+    /// *See also the [handles module](crate::handles)*
+    pub fn checked(&self) -> CheckedHandleImpl<Type, InnerOuter> {
+        CheckedHandleImpl::new(
+            self.handle,
+            Type::generation(self.dcel, self.handle.index()),
+        )
+    }
+}
+
 impl FixedFaceHandle<PossiblyOuterTag> {
     /// Returns `true` if this face is the single outer face.
     #[inline]
@@ -326,6 +345,15 @@ impl<'a, V, DE, UE, F> DirectedEdgeHandle<'a, V, DE, UE, F> {
     pub fn as_voronoi_edge(&self) -> DirectedVoronoiEdge<'a, V, DE, UE, F> {
         DirectedVoronoiEdge::new(self.dcel, FixedHandleImpl::new(self.handle.index()))
     }
+
+    /// Converts this edge into its dual power edge.
+    ///
+    /// This is only meaningful for edges of a [RegularTriangulation](crate::RegularTriangulation).
+    ///
+    /// See also [as_delaunay_edge](DirectedPowerEdge::as_delaunay_edge).
+    pub fn as_power_edge(&self) -> DirectedPowerEdge<'a, V, DE, UE, F> {
+        DirectedPowerEdge::new(self.dcel, FixedHandleImpl::new(self.handle.index()))
+    }
 }
 
 impl<V, DE, UE, F> DirectedEdgeHandle<'_, V, DE, UE, F>
@@ -700,6 +728,35 @@ where
     }
 }
 
+impl<V, DE, UE, F> FaceHandle<'_, InnerTag, V, DE, UE, F>
+where
+    V: HasWeight,
+    V::Scalar: Float,
+{
+    /// Returns the face's orthocircle center and the **squared** radius of the orthocircle.
+    ///
+    /// Every vertex can be interpreted as a circle with its weight as squared radius. The
+    /// orthocircle is the unique circle that intersects all three of these circles at a right
+    /// angle. For zero weights, this is identical to the [circumcircle](Self::circumcircle).
+    ///
+    /// The squared radius becomes negative if the vertex circles overlap each other too much.
+    pub fn orthocircle(&self) -> (Point2<V::Scalar>, V::Scalar) {
+        let [v0, v1, v2] = self.vertices();
+        math::orthocenter(
+            self.positions(),
+            [v0.data().weight(), v1.data().weight(), v2.data().weight()],
+        )
+    }
+
+    /// Returns the face's orthocenter.
+    ///
+    /// The orthocenter is the center of the [orthocircle](Self::orthocircle) and a vertex of the
+    /// power diagram. It is the only point with the same power distance to all three vertices.
+    pub fn orthocenter(&self) -> Point2<V::Scalar> {
+        self.orthocircle().0
+    }
+}
+
 impl<V, DE, UE, F> AsRef<V> for VertexHandle<'_, V, DE, UE, F> {
     fn as_ref(&self) -> &V {
         self.data()
@@ -773,6 +830,12 @@ impl<'a, V, DE, UE, F> VertexHandle<'a, V, DE, UE, F> {
     pub fn as_voronoi_face(&self) -> VoronoiFace<'a, V, DE, UE, F> {
         VoronoiFace::new(self.dcel, FixedHandleImpl::new(self.handle.index()))
     }
+
+    /// Returns the power cell that corresponds to this vertex of a
+    /// [RegularTriangulation](crate::RegularTriangulation).
+    pub fn as_power_cell(&self) -> PowerCell<'a, V, DE, UE, F> {
+        PowerCell::new(self.dcel, FixedHandleImpl::new(self.handle.index()))
+    }
 }
 
 impl<V, DE, UE, F> DirectedEdgeHandle<'_, V, DE, UE, F>
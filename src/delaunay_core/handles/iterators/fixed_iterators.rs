@@ -1,63 +1,199 @@
 use core::marker::PhantomData;
 
+use alloc::borrow::Cow;
+
 use super::super::handle_defs::{DelaunayElementType, DynamicHandleImpl, FixedHandleImpl};
 use super::super::InnerOuterMarker;
 
+use crate::delaunay_core::dcel::{count_bits, is_bit_set};
 use crate::delaunay_core::Dcel;
 
-pub struct FixedHandleIterator<Type, InnerOuter> {
+/// Iterates over all handle indices of a storage range while skipping the slots of removed
+/// elements.
+struct SlotIterator<'s, Type, InnerOuter> {
     range: core::ops::Range<usize>,
+    // Bit set of all removed storage slots. Only non-empty if stable handles are enabled.
+    removed_slots: Cow<'s, [u64]>,
+    // The number of handles in `range` that don't belong to a removed slot.
+    len: usize,
     ty: PhantomData<Type>,
     inner_outer: PhantomData<InnerOuter>,
 }
 
-impl<Type: Default, InnerOuter: InnerOuterMarker> FixedHandleIterator<Type, InnerOuter> {
-    pub(crate) fn new(to: usize) -> Self {
+impl<'s, Type: DelaunayElementType, InnerOuter: InnerOuterMarker>
+    SlotIterator<'s, Type, InnerOuter>
+{
+    /// The number of handles that are skipped at once when looking for the `n`-th element.
+    const BLOCK_SIZE: usize = 64 * Type::HANDLES_PER_SLOT;
+
+    fn new<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>, removed_slots: Cow<'s, [u64]>) -> Self {
+        let num_elements = Type::num_elements(dcel);
         Self {
-            range: 0..to,
+            range: 0..num_elements,
+            removed_slots,
+            len: num_elements - Type::removed_slots(dcel).len() * Type::HANDLES_PER_SLOT,
             ty: Default::default(),
             inner_outer: Default::default(),
         }
     }
+
+    fn is_removed(&self, index: usize) -> bool {
+        is_bit_set(&self.removed_slots, index / Type::HANDLES_PER_SLOT)
+    }
+
+    /// Returns the number of handles within `range` that belong to a removed slot.
+    fn num_removed(&self, range: core::ops::Range<usize>) -> usize {
+        let per_slot = Type::HANDLES_PER_SLOT;
+        if range.is_empty() || self.len == self.range.len() {
+            return 0;
+        }
+        let first_slot = range.start / per_slot;
+        let last_slot = (range.end - 1) / per_slot;
+        let mut result = count_bits(&self.removed_slots, first_slot..last_slot + 1) * per_slot;
+        // The first and the last slot may only partially overlap the range
+        if is_bit_set(&self.removed_slots, first_slot) {
+            result -= range.start - first_slot * per_slot;
+        }
+        if is_bit_set(&self.removed_slots, last_slot) {
+            result -= (last_slot + 1) * per_slot - range.end;
+        }
+        result
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            let index = self.range.next()?;
+            if !self.is_removed(index) {
+                self.len -= 1;
+                return Some(index);
+            }
+        }
+    }
+
+    fn next_back(&mut self) -> Option<usize> {
+        loop {
+            let index = self.range.next_back()?;
+            if !self.is_removed(index) {
+                self.len -= 1;
+                return Some(index);
+            }
+        }
+    }
+
+    fn nth(&mut self, mut n: usize) -> Option<usize> {
+        if n >= self.len {
+            self.range.start = self.range.end;
+            self.len = 0;
+            return None;
+        }
+
+        // Skips whole blocks in front of the result. Each block is covered by a single word of
+        // the bit set.
+        loop {
+            let start = self.range.start;
+            let block_end = ((start / Self::BLOCK_SIZE + 1) * Self::BLOCK_SIZE).min(self.range.end);
+            let num_live = block_end - start - self.num_removed(start..block_end);
+            if num_live > n {
+                break;
+            }
+            n -= num_live;
+            self.len -= num_live;
+            self.range.start = block_end;
+        }
+
+        for _ in 0..n {
+            self.next();
+        }
+        self.next()
+    }
+
+    fn nth_back(&mut self, mut n: usize) -> Option<usize> {
+        if n >= self.len {
+            self.range.end = self.range.start;
+            self.len = 0;
+            return None;
+        }
+
+        // Same as `nth` but skips blocks behind the result.
+        loop {
+            let end = self.range.end;
+            let block_start =
+                ((end - 1) / Self::BLOCK_SIZE * Self::BLOCK_SIZE).max(self.range.start);
+            let num_live = end - block_start - self.num_removed(block_start..end);
+            if num_live > n {
+                break;
+            }
+            n -= num_live;
+            self.len -= num_live;
+            self.range.end = block_start;
+        }
+
+        for _ in 0..n {
+            self.next_back();
+        }
+        self.next_back()
+    }
+}
+
+pub struct FixedHandleIterator<Type, InnerOuter> {
+    // Fixed iterators don't borrow the triangulation and need to own a copy of the removed slots.
+    // This copy is empty (and doesn't allocate) unless elements have been removed with stable
+    // handles enabled.
+    slots: SlotIterator<'static, Type, InnerOuter>,
+}
+
+impl<Type: DelaunayElementType, InnerOuter: InnerOuterMarker>
+    FixedHandleIterator<Type, InnerOuter>
+{
+    pub(crate) fn new<V, DE, UE, F>(dcel: &Dcel<V, DE, UE, F>) -> Self {
+        Self {
+            slots: SlotIterator::new(dcel, Cow::Owned(Type::removed_slots(dcel).bits().to_vec())),
+        }
+    }
 }
 
-impl<Type: Default, InnerOuter: InnerOuterMarker> Iterator
+impl<Type: DelaunayElementType, InnerOuter: InnerOuterMarker> Iterator
     for FixedHandleIterator<Type, InnerOuter>
 {
     type Item = FixedHandleImpl<Type, InnerOuter>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.range.next().map(FixedHandleImpl::new)
+        self.slots.next().map(FixedHandleImpl::new)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.range.size_hint()
+        let len = self.slots.len();
+        (len, Some(len))
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.range.nth(n).map(FixedHandleImpl::new)
+        self.slots.nth(n).map(FixedHandleImpl::new)
     }
 }
 
-impl<Type: Default, InnerOuter: InnerOuterMarker> ExactSizeIterator
+impl<Type: DelaunayElementType, InnerOuter: InnerOuterMarker> ExactSizeIterator
     for FixedHandleIterator<Type, InnerOuter>
 {
 }
 
-impl<Type: Default, InnerOuter: InnerOuterMarker> DoubleEndedIterator
+impl<Type: DelaunayElementType, InnerOuter: InnerOuterMarker> DoubleEndedIterator
     for FixedHandleIterator<Type, InnerOuter>
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.range.next_back().map(FixedHandleImpl::new)
+        self.slots.next_back().map(FixedHandleImpl::new)
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        self.range.nth_back(n).map(FixedHandleImpl::new)
+        self.slots.nth_back(n).map(FixedHandleImpl::new)
     }
 }
 
 pub struct DynamicHandleIterator<'a, V, DE, UE, F, Type, InnerOuter> {
-    fixed_iterator: FixedHandleIterator<Type, InnerOuter>,
+    slots: SlotIterator<'a, Type, InnerOuter>,
     dcel: &'a Dcel<V, DE, UE, F>,
     inner_outer: PhantomData<InnerOuter>,
 }
@@ -69,7 +205,7 @@ where
 {
     pub(crate) fn new(dcel: &'a Dcel<V, DE, UE, F>) -> Self {
         DynamicHandleIterator {
-            fixed_iterator: FixedHandleIterator::new(Type::num_elements(dcel)),
+            slots: SlotIterator::new(dcel, Cow::Borrowed(Type::removed_slots(dcel).bits())),
             dcel,
             inner_outer: PhantomData,
         }
@@ -85,19 +221,20 @@ where
     type Item = DynamicHandleImpl<'a, V, DE, UE, F, Type, InnerOuter>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.fixed_iterator
+        self.slots
             .next()
-            .map(|handle| DynamicHandleImpl::new(self.dcel, handle.adjust_inner_outer()))
+            .map(|index| DynamicHandleImpl::new(self.dcel, FixedHandleImpl::new(index)))
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        self.fixed_iterator.size_hint()
+        let len = self.slots.len();
+        (len, Some(len))
     }
 
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.fixed_iterator
+        self.slots
             .nth(n)
-            .map(|handle| DynamicHandleImpl::new(self.dcel, handle.adjust_inner_outer()))
+            .map(|index| DynamicHandleImpl::new(self.dcel, FixedHandleImpl::new(index)))
     }
 }
 
@@ -108,15 +245,15 @@ where
     InnerOuter: InnerOuterMarker,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.fixed_iterator
+        self.slots
             .next_back()
-            .map(|handle| DynamicHandleImpl::new(self.dcel, handle.adjust_inner_outer()))
+            .map(|index| DynamicHandleImpl::new(self.dcel, FixedHandleImpl::new(index)))
     }
 
     fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
-        self.fixed_iterator
+        self.slots
             .nth_back(n)
-            .map(|handle| DynamicHandleImpl::new(self.dcel, handle.adjust_inner_outer()))
+            .map(|index| DynamicHandleImpl::new(self.dcel, FixedHandleImpl::new(index)))
     }
 }
 
@@ -21,7 +21,8 @@ macro_rules! type_handle_doc {
             "(crate::Triangulation::",
             $item_type,
             ").\n\n",
-            "*Note: This iterator specializes `nth` and `nth_back` - those operations run in O(1)*"
+            "*Note: This iterator specializes `nth` and `nth_back` - those operations run in O(1), or in ",
+            "O(log n) if elements have been removed while stable handles are enabled*"
         )
     };
 }
@@ -59,6 +60,26 @@ pub type DirectedVoronoiEdgeIterator<'a, V, DE, UE, F> =
 pub type UndirectedVoronoiEdgeIterator<'a, V, DE, UE, F> =
     DynamicHandleIterator<'a, V, DE, UE, F, UndirectedVoronoiEdgeTag, InnerTag>;
 
+/// An iterator over power cells.
+///
+/// This iterator is created by
+/// [RegularTriangulation::power_cells()](crate::RegularTriangulation::power_cells).
+///
+/// *Note: This iterator specializes `nth` and `nth_back` - those operations run in O(1), or in
+/// O(log n) if elements have been removed while stable handles are enabled*
+pub type PowerCellIterator<'a, V, DE, UE, F> =
+    DynamicHandleIterator<'a, V, DE, UE, F, PowerCellTag, PossiblyOuterTag>;
+
+/// An iterator over directed power edges.
+///
+/// This iterator is created by
+/// [RegularTriangulation::directed_power_edges()](crate::RegularTriangulation::directed_power_edges).
+///
+/// *Note: This iterator specializes `nth` and `nth_back` - those operations run in O(1), or in
+/// O(log n) if elements have been removed while stable handles are enabled*
+pub type DirectedPowerEdgeIterator<'a, V, DE, UE, F> =
+    DynamicHandleIterator<'a, V, DE, UE, F, DirectedPowerEdgeTag, InnerTag>;
+
 #[doc = type_handle_doc!("faces", "all_faces")]
 pub type FaceIterator<'a, V, DE, UE, F> =
     DynamicHandleIterator<'a, V, DE, UE, F, FaceTag, PossiblyOuterTag>;
@@ -1,10 +1,14 @@
 use crate::{
-    delaunay_core::dcel_operations::{self},
-    HasPosition, Point2,
+    delaunay_core::{
+        clipping,
+        dcel_operations::{self},
+    },
+    HasPosition, HasWeight, Point2,
 };
 
 pub use super::handle_defs::*;
 
+use alloc::vec::Vec;
 use num_traits::Float;
 
 #[cfg(feature = "serde")]
@@ -75,6 +79,28 @@ pub type FixedDirectedEdgeHandle = FixedHandleImpl<DirectedEdgeTag, InnerTag>;
 /// *See also the [handles](crate::handles) module.*
 pub type FixedUndirectedEdgeHandle = FixedHandleImpl<UndirectedEdgeTag, InnerTag>;
 
+/// Checked handle to a vertex.
+///
+/// *See also the [handles](crate::handles) module.*
+pub type CheckedVertexHandle = CheckedHandleImpl<VertexTag, InnerTag>;
+
+/// Checked handle to a directed edge.
+///
+/// *See also the [handles](crate::handles) module.*
+pub type CheckedDirectedEdgeHandle = CheckedHandleImpl<DirectedEdgeTag, InnerTag>;
+
+/// Checked handle to an undirected edge.
+///
+/// *See also the [handles](crate::handles) module.*
+pub type CheckedUndirectedEdgeHandle = CheckedHandleImpl<UndirectedEdgeTag, InnerTag>;
+
+/// Checked handle to a face.
+///
+/// The type parameter is either [InnerTag] or [PossiblyOuterTag], depending on the face type.
+///
+/// *See also the [handles](crate::handles) module.*
+pub type CheckedFaceHandle<InnerOuter> = CheckedHandleImpl<FaceTag, InnerOuter>;
+
 /// "Fixed handle to a face.
 ///
 /// The type parameter is either [InnerTag] or [PossiblyOuterTag], depending on the face type.
@@ -169,6 +195,26 @@ pub type UndirectedVoronoiEdge<'a, V, DE, UE, F> =
 pub type VoronoiFace<'a, V, DE, UE, F> =
     DynamicHandleImpl<'a, V, DE, UE, F, VoronoiFaceTag, PossiblyOuterTag>;
 
+/// A handle to a directed edge of the power diagram.
+///
+/// The power diagram (also known as *Laguerre diagram*) is the dual graph of a
+/// [RegularTriangulation](crate::RegularTriangulation), just like the Voronoi diagram is the dual
+/// graph of a Delaunay triangulation. Navigation works identical to [DirectedVoronoiEdge].
+pub type DirectedPowerEdge<'a, V, DE, UE, F> =
+    DynamicHandleImpl<'a, V, DE, UE, F, DirectedPowerEdgeTag, InnerTag>;
+
+/// A handle to a cell of the power diagram.
+///
+/// Each cell belongs to a vertex of a [RegularTriangulation](crate::RegularTriangulation) and
+/// contains all points whose power distance to that vertex is smaller than the power distance
+/// to any other vertex. The power distance of a point `p` and a vertex `v` is
+/// `|p - v|² - weight(v)`.
+///
+/// Cells of vertices on the convex hull are unbounded. Use
+/// [clipped_polygon](PowerCell::clipped_polygon) to get their shape within a bounding box.
+pub type PowerCell<'a, V, DE, UE, F> =
+    DynamicHandleImpl<'a, V, DE, UE, F, PowerCellTag, PossiblyOuterTag>;
+
 /// A handle to a vertex of the voronoi diagram.
 ///
 /// Refer to [DelaunayTriangulation](crate::DelaunayTriangulation) for an example on how
@@ -359,3 +405,247 @@ where
         Point2::new(-diff.y, diff.x)
     }
 }
+
+/// A handle to a vertex of the power diagram.
+///
+/// This is the weighted counterpart of a [VoronoiVertex].
+pub enum PowerVertex<'a, V, DE, UE, F> {
+    /// Refers to an inner vertex of the power diagram.
+    ///
+    /// An inner vertex refers to an *inner face* of the regular triangulation. Its position is
+    /// the [orthocenter](crate::handles::FaceHandle::orthocenter()) of that face.
+    Inner(
+        /// The inner face handle to which this power vertex refers.
+        FaceHandle<'a, InnerTag, V, DE, UE, F>,
+    ),
+
+    /// Refers to an outer vertex of the power diagram.
+    ///
+    /// These vertices are placed infinitely far away, see [VoronoiVertex::Outer].
+    Outer(
+        /// The outer directed edge handle dual to this power vertex.
+        DirectedPowerEdge<'a, V, DE, UE, F>,
+    ),
+}
+
+impl<'a, V, DE, UE, F> PowerVertex<'a, V, DE, UE, F>
+where
+    V: HasWeight,
+    V::Scalar: Float,
+{
+    /// The position of this power vertex.
+    ///
+    /// Returns `None` if this vertex is an outer power vertex.
+    /// Otherwise, the returned position is the
+    /// [orthocenter](crate::handles::FaceHandle::orthocenter())
+    /// of the dual face.
+    pub fn position(&self) -> Option<Point2<V::Scalar>> {
+        self.as_delaunay_face().map(|face| face.orthocenter())
+    }
+}
+
+impl<'a, V, DE, UE, F> PowerVertex<'a, V, DE, UE, F> {
+    /// Returns the dual face of the regular triangulation.
+    ///
+    /// Returns `None` if this is an outer power vertex.
+    pub fn as_delaunay_face(&self) -> Option<FaceHandle<'a, InnerTag, V, DE, UE, F>> {
+        match self {
+            PowerVertex::Inner(face) => Some(*face),
+            PowerVertex::Outer(_) => None,
+        }
+    }
+
+    /// Returns all directed power edges going out of this vertex.
+    ///
+    /// The edges are returned in counterclockwise order. Returns `None` if this is an outer
+    /// power vertex.
+    pub fn out_edges(&self) -> Option<[DirectedPowerEdge<'a, V, DE, UE, F>; 3]> {
+        self.as_delaunay_face().map(|face| {
+            let [e1, e2, e3] = face.adjacent_edges();
+            [e1.as_power_edge(), e2.as_power_edge(), e3.as_power_edge()]
+        })
+    }
+
+    /// Returns a power edge going out of this vertex.
+    pub fn out_edge(&self) -> DirectedPowerEdge<'a, V, DE, UE, F> {
+        match self {
+            PowerVertex::Inner(face) => face.adjacent_edge().as_power_edge(),
+            PowerVertex::Outer(edge) => *edge,
+        }
+    }
+}
+
+impl<'a, V, DE, UE, F> PowerCell<'a, V, DE, UE, F> {
+    /// Converts this cell into its dual vertex of the regular triangulation.
+    pub fn as_delaunay_vertex(&self) -> VertexHandle<'a, V, DE, UE, F> {
+        VertexHandle::new(self.dcel, FixedVertexHandle::new(self.handle.index()))
+    }
+
+    /// Returns an iterator that returns all edges adjacent to this cell.
+    ///
+    /// The edges are returned in clockwise order.
+    pub fn adjacent_edges(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = DirectedPowerEdge<'a, V, DE, UE, F>> {
+        self.as_delaunay_vertex()
+            .out_edges()
+            .map(|edge| edge.as_power_edge())
+    }
+
+    /// Returns the power vertices of this cell in clockwise order.
+    ///
+    /// Unbounded cells will contain two [outer vertices](PowerVertex::Outer).
+    pub fn vertices(&self) -> impl DoubleEndedIterator<Item = PowerVertex<'a, V, DE, UE, F>> {
+        self.adjacent_edges().map(|edge| edge.from())
+    }
+
+    /// Returns `true` if this cell has a finite area.
+    ///
+    /// Only cells of vertices on the convex hull are unbounded.
+    pub fn is_bounded(&self) -> bool {
+        let mut edges = self.as_delaunay_vertex().out_edges().peekable();
+        edges.peek().is_some() && edges.all(|edge| !edge.is_outer_edge())
+    }
+}
+
+impl<V, DE, UE, F> PowerCell<'_, V, DE, UE, F>
+where
+    V: HasWeight,
+    V::Scalar: Float,
+{
+    /// Returns the area of this cell.
+    ///
+    /// Returns `None` if the cell is [unbounded](Self::is_bounded).
+    pub fn area(&self) -> Option<V::Scalar> {
+        if !self.is_bounded() {
+            return None;
+        }
+        let polygon = self
+            .vertices()
+            .flat_map(|vertex| vertex.position())
+            .collect::<Vec<_>>();
+        Some(clipping::polygon_area(&polygon))
+    }
+
+    /// Returns the cell's polygon clipped to an axis aligned rectangle.
+    ///
+    /// The rectangle is given by its `lower` and `upper` corner. Swapped coordinates are
+    /// accepted and describe the same rectangle. The returned polygon is convex, its vertices are in counterclockwise order and it is empty if the cell doesn't
+    /// overlap the rectangle.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{HasPosition, HasWeight, Point2, RegularTriangulation, Triangulation};
+    ///
+    /// struct Grain {
+    ///     position: Point2<f64>,
+    ///     weight: f64,
+    /// }
+    ///
+    /// impl HasPosition for Grain {
+    ///     type Scalar = f64;
+    ///     fn position(&self) -> Point2<f64> { self.position }
+    /// }
+    ///
+    /// impl HasWeight for Grain {
+    ///     fn weight(&self) -> f64 { self.weight }
+    /// }
+    ///
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let mut triangulation = RegularTriangulation::<Grain>::new();
+    /// triangulation.insert(Grain { position: Point2::new(0.0, 0.0), weight: 0.0 })?;
+    /// triangulation.insert(Grain { position: Point2::new(2.0, 0.0), weight: 0.0 })?;
+    ///
+    /// let (lower, upper) = (Point2::new(-1.0, -1.0), Point2::new(3.0, 1.0));
+    /// let cell = triangulation.power_cells().next().unwrap();
+    /// assert_eq!(cell.clipped_area(lower, upper), 4.0);
+    /// # Ok(()) }
+    /// ```
+    pub fn clipped_polygon(
+        &self,
+        lower: Point2<V::Scalar>,
+        upper: Point2<V::Scalar>,
+    ) -> Vec<Point2<V::Scalar>> {
+        let vertex = self.as_delaunay_vertex();
+        clipping::clip_power_cell(
+            &clipping::CellBounds::Rectangle { lower, upper }.to_ccw_polygon(),
+            vertex.position(),
+            vertex.data().weight(),
+            vertex.out_edges().map(|edge| {
+                let neighbor = edge.to();
+                (neighbor.position(), neighbor.data().weight())
+            }),
+        )
+    }
+
+    /// Returns the area of the cell's polygon clipped to an axis aligned rectangle.
+    ///
+    /// *See [clipped_polygon](Self::clipped_polygon)*
+    pub fn clipped_area(&self, lower: Point2<V::Scalar>, upper: Point2<V::Scalar>) -> V::Scalar {
+        clipping::polygon_area(&self.clipped_polygon(lower, upper))
+    }
+}
+
+impl<'a, V, DE, UE, F> DirectedPowerEdge<'a, V, DE, UE, F> {
+    /// Returns the power edge's destination.
+    pub fn to(&self) -> PowerVertex<'a, V, DE, UE, F> {
+        self.rev().from()
+    }
+
+    /// Returns the power vertex from which this edge originates.
+    pub fn from(&self) -> PowerVertex<'a, V, DE, UE, F> {
+        if let Some(face) = self.as_delaunay_edge().face().as_inner() {
+            PowerVertex::Inner(face)
+        } else {
+            PowerVertex::Outer(*self)
+        }
+    }
+
+    /// Returns the power cell to the left of this power edge.
+    pub fn face(&self) -> PowerCell<'a, V, DE, UE, F> {
+        self.as_delaunay_edge().from().as_power_cell()
+    }
+
+    /// Returns the directed dual edge of the underlying regular triangulation.
+    ///
+    /// The dual edge is always orthogonal to this edge.
+    pub fn as_delaunay_edge(&self) -> DirectedEdgeHandle<'a, V, DE, UE, F> {
+        DirectedEdgeHandle::new(self.dcel, FixedDirectedEdgeHandle::new(self.handle.index()))
+    }
+
+    /// Returns this edge with its direction reversed.
+    pub fn rev(&self) -> Self {
+        self.as_delaunay_edge().rev().as_power_edge()
+    }
+
+    /// Returns the edge that is connected to this edge in counterclockwise order.
+    ///
+    /// See also [prev](Self::prev)
+    pub fn next(&self) -> DirectedPowerEdge<'a, V, DE, UE, F> {
+        self.as_delaunay_edge().ccw().as_power_edge()
+    }
+
+    /// Returns the edge that is connected to this edge in clockwise order.
+    ///
+    /// See also [next](Self::next)
+    pub fn prev(&self) -> DirectedPowerEdge<'a, V, DE, UE, F> {
+        self.as_delaunay_edge().cw().as_power_edge()
+    }
+}
+
+impl<V, DE, UE, F> DirectedPowerEdge<'_, V, DE, UE, F>
+where
+    V: HasPosition,
+{
+    /// Returns a vector that is parallel to the power edge.
+    ///
+    /// This vector is obtained by rotating the dual edge by 90° degree.
+    /// The returned vector is not necessarily normalized.
+    pub fn direction_vector(&self) -> Point2<V::Scalar> {
+        let from = self.as_delaunay_edge().from().position();
+        let to = self.as_delaunay_edge().to().position();
+        let diff = Point2::sub(&to, from);
+
+        Point2::new(-diff.y, diff.x)
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{HasPosition, Point2};
+
+/// The number of bits used per axis when mapping positions onto the Hilbert curve.
+const ORDER: u32 = 16;
+
+/// Sorts elements along a [Hilbert curve](https://en.wikipedia.org/wiki/Hilbert_curve).
+///
+/// Consecutive elements of the sorted slice will usually be close to each other. This is used to
+/// speed up incremental insertion (the previously inserted vertex becomes a good hint) and to
+/// improve the memory locality of a triangulation.
+pub fn hilbert_sort_by_key<T, K>(elements: &mut [T], position: K)
+where
+    K: Fn(&T) -> Point2<f64>,
+{
+    let Some((min, max)) = bounding_box(elements.iter().map(&position)) else {
+        return;
+    };
+
+    let extent = max.sub(min);
+    let extent = if extent.x > extent.y {
+        extent.x
+    } else {
+        extent.y
+    };
+
+    elements.sort_by_cached_key(|element| hilbert_index(position(element), min, extent));
+}
+
+/// Sorts vertices along a Hilbert curve.
+///
+/// *See [hilbert_sort_by_key]*
+pub fn hilbert_sort<V: HasPosition>(elements: &mut [V]) {
+    hilbert_sort_by_key(elements, |element| element.position().to_f64());
+}
+
+fn bounding_box(
+    mut positions: impl Iterator<Item = Point2<f64>>,
+) -> Option<(Point2<f64>, Point2<f64>)> {
+    let first = positions.next()?;
+    Some(positions.fold((first, first), |(min, max), p| {
+        (
+            Point2::new(min.x.min(p.x), min.y.min(p.y)),
+            Point2::new(max.x.max(p.x), max.y.max(p.y)),
+        )
+    }))
+}
+
+fn hilbert_index(position: Point2<f64>, min: Point2<f64>, extent: f64) -> u64 {
+    let side = 1u64 << ORDER;
+    let max_cell = (side - 1) as f64;
+
+    let to_cell = |value: f64, min: f64| {
+        if extent > 0.0 {
+            ((value - min) / extent * max_cell) as u64
+        } else {
+            0
+        }
+    };
+
+    let mut x = to_cell(position.x, min.x);
+    let mut y = to_cell(position.y, min.y);
+
+    let mut result = 0;
+    let mut s = side / 2;
+    while s > 0 {
+        let rx = u64::from(x & s > 0);
+        let ry = u64::from(y & s > 0);
+        result += s * s * ((3 * rx) ^ ry);
+
+        // Rotate the quadrant
+        if ry == 0 {
+            if rx == 1 {
+                x = side - 1 - x;
+                y = side - 1 - y;
+            }
+            core::mem::swap(&mut x, &mut y);
+        }
+        s /= 2;
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::hilbert_sort;
+    use crate::Point2;
+
+    use alloc::vec;
+
+    #[test]
+    fn test_hilbert_sort() {
+        let mut points = vec![
+            Point2::new(1.0, 0.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(1.0, 1.0),
+            Point2::new(0.0, 0.0),
+        ];
+        hilbert_sort(&mut points);
+        assert_eq!(
+            points,
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(0.0, 1.0),
+                Point2::new(1.0, 1.0),
+                Point2::new(1.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hilbert_sort_degenerate() {
+        let mut points = vec![Point2::new(2.0, 2.0); 3];
+        hilbert_sort(&mut points);
+        assert_eq!(points.len(), 3);
+
+        let mut empty: [Point2<f64>; 0] = [];
+        hilbert_sort(&mut empty);
+    }
+}
@@ -45,6 +45,32 @@ pub trait HintGenerator<S: SpadeNum>: Default {
         vertex_position: Point2<S>,
     );
 
+    /// Notifies the hint generator that a vertex was removed while
+    /// [stable handles](Triangulation::set_stable_handles) are enabled.
+    ///
+    /// No other vertex is moved into the removed vertex' slot. The slot may be reused by a
+    /// subsequently inserted vertex.
+    ///
+    /// The default implementation calls [notify_vertex_removed](HintGenerator::notify_vertex_removed).
+    fn notify_vertex_slot_freed(&mut self, vertex: FixedVertexHandle, vertex_position: Point2<S>) {
+        self.notify_vertex_removed(None, vertex, vertex_position);
+    }
+
+    /// Notifies the hint generator that two vertices have swapped their handles.
+    ///
+    /// Contains the positions of the vertices that are referred to by `v0` and `v1` after the swap
+    /// or `None` if a handle refers to a removed vertex.
+    ///
+    /// The default implementation does nothing.
+    fn notify_vertices_swapped(
+        &mut self,
+        _v0: FixedVertexHandle,
+        _v0_position: Option<Point2<S>>,
+        _v1: FixedVertexHandle,
+        _v1_position: Option<Point2<S>>,
+    ) {
+    }
+
     /// Creates a new hint generator initialized to give hints for a specific triangulation
     fn initialize_from_triangulation<TR, V>(triangulation: &TR) -> Self
     where
@@ -131,6 +157,9 @@ impl<S: SpadeNum> HintGenerator<S> for LastUsedVertexHintGenerator {
 /// few layers of sparsely populated Delaunay Triangulations. These layers can then be quickly traversed
 /// before diving deeper into the next, more detailed layer where the search is furthermore refined.
 ///
+/// If [stable handles](Triangulation::set_stable_handles) are enabled, the hierarchy keeps the
+/// slots of removed vertices empty as well. Generated hints never refer to a removed vertex.
+///
 /// # Type parameters
 ///  - `S`: The scalar type used by the triangulation
 ///
@@ -175,7 +204,13 @@ impl<S: SpadeNum, const BRANCH_FACTOR: u32> HintGenerator<S>
     fn get_hint(&self, position: Point2<S>) -> FixedVertexHandle {
         let mut nearest = FixedVertexHandle::new(0);
         for layer in self.hierarchy.iter().rev().skip(1) {
-            nearest = layer.walk_to_nearest_neighbor(nearest, position).fix();
+            if layer.num_vertices() == 0 {
+                // Can only happen if stable handles are enabled
+                continue;
+            }
+            // Layer vertices may have been removed if stable handles are enabled
+            let start = layer.validate_vertex_handle(nearest);
+            nearest = layer.walk_to_nearest_neighbor(start, position).fix();
             let hint_generator: &LastUsedVertexHintGenerator = layer.hint_generator();
             <LastUsedVertexHintGenerator as HintGenerator<S>>::notify_vertex_lookup(
                 hint_generator,
@@ -200,24 +235,58 @@ impl<S: SpadeNum, const BRANCH_FACTOR: u32> HintGenerator<S>
             index /= BRANCH_FACTOR;
 
             if remainder == 0 {
-                triangulation.insert(vertex_position).unwrap();
+                insert_into_slot(
+                    triangulation,
+                    FixedVertexHandle::new(index as usize),
+                    vertex_position,
+                );
             } else {
                 break;
             }
         }
 
-        if remainder == 0 {
+        // Slots of removed vertices are reused if stable handles are enabled. Re-inserting the
+        // vertex at index 0 must not add another layer.
+        if remainder == 0 && (vertex.index() > 0 || self.hierarchy.is_empty()) {
             let mut new_layer = DelaunayTriangulation::new();
-            let position_of_vertex_0 = self
+            let vertex_0 = self
                 .hierarchy
                 .first()
-                .map(|layer| layer.vertex(FixedVertexHandle::new(0)).position())
-                .unwrap_or(vertex_position);
-            new_layer.insert(position_of_vertex_0).unwrap();
+                .map(|layer| layer.s().get_vertex(FixedVertexHandle::new(0)));
+            match vertex_0 {
+                Some(Some(vertex_0)) => {
+                    new_layer.insert(vertex_0.position()).unwrap();
+                }
+                None => {
+                    new_layer.insert(vertex_position).unwrap();
+                }
+                Some(None) => {
+                    // Vertex 0 has been removed. Its slot must remain empty.
+                    new_layer.insert(vertex_position).unwrap();
+                    new_layer.set_stable_handles(true);
+                    new_layer.remove(FixedVertexHandle::new(0));
+                }
+            }
             self.hierarchy.push(new_layer);
         }
     }
 
+    fn notify_vertex_slot_freed(&mut self, vertex: FixedVertexHandle, _vertex_position: Point2<S>) {
+        self.num_elements_of_base_triangulation -= 1;
+
+        // Leaves the corresponding slots of all layers empty. This keeps the mapping between
+        // layer and base handles intact.
+        let mut divisor = BRANCH_FACTOR as usize;
+        for triangulation in &mut self.hierarchy {
+            if !vertex.index().is_multiple_of(divisor) {
+                break;
+            }
+            let layer_vertex = FixedVertexHandle::new(vertex.index() / divisor);
+            update_layer_slot(triangulation, layer_vertex, None);
+            divisor *= BRANCH_FACTOR as usize;
+        }
+    }
+
     fn notify_vertex_removed(
         &mut self,
         mut swapped_in_point: Option<Point2<S>>,
@@ -235,7 +304,7 @@ impl<S: SpadeNum, const BRANCH_FACTOR: u32> HintGenerator<S>
             let index_to_remove = index / current_divisor;
             current_divisor *= BRANCH_FACTOR;
 
-            if remainder == 0 {
+            if remainder == 0 && (index_to_remove as usize) < triangulation.num_vertices() {
                 // The current handle is part of this layer and must be removed.
                 if let Some(swapped_point) = swapped_in_point.as_ref() {
                     if (triangulation.num_vertices() - 1) * (BRANCH_FACTOR as usize)
@@ -269,6 +338,34 @@ impl<S: SpadeNum, const BRANCH_FACTOR: u32> HintGenerator<S>
         }
     }
 
+    fn notify_vertices_swapped(
+        &mut self,
+        v0: FixedVertexHandle,
+        v0_position: Option<Point2<S>>,
+        v1: FixedVertexHandle,
+        v1_position: Option<Point2<S>>,
+    ) {
+        let mut divisor = BRANCH_FACTOR as usize;
+        for triangulation in &mut self.hierarchy {
+            let layer_handle = |vertex: FixedVertexHandle| {
+                let index = vertex.index() / divisor;
+                (vertex.index().is_multiple_of(divisor)
+                    && index < triangulation.s().num_vertex_slots())
+                .then(|| FixedVertexHandle::new(index))
+            };
+
+            match (layer_handle(v0), layer_handle(v1)) {
+                (Some(l0), Some(l1)) => triangulation.s_mut().swap_vertices(l0, l1),
+                // The layer vertex needs to be updated to the vertex that has been swapped in.
+                (Some(l0), None) => update_layer_slot(triangulation, l0, v0_position),
+                (None, Some(l1)) => update_layer_slot(triangulation, l1, v1_position),
+                // Higher layers only contain a subset of this layer's vertices
+                (None, None) => return,
+            }
+            divisor *= BRANCH_FACTOR as usize;
+        }
+    }
+
     fn initialize_from_triangulation<TR, V>(triangulation: &TR) -> Self
     where
         TR: Triangulation<Vertex = V>,
@@ -282,13 +379,47 @@ impl<S: SpadeNum, const BRANCH_FACTOR: u32> HintGenerator<S>
     }
 }
 
+/// Inserts a vertex into a layer and moves it into the given slot if the slot exists.
+fn insert_into_slot<S: SpadeNum>(
+    layer: &mut DelaunayTriangulation<Point2<S>>,
+    slot: FixedVertexHandle,
+    position: Point2<S>,
+) {
+    let new_vertex = layer.insert(position).unwrap();
+    if new_vertex != slot && slot.index() < layer.s().num_vertex_slots() {
+        layer.s_mut().swap_vertices(new_vertex, slot);
+    }
+}
+
+/// Updates a layer slot to contain the given position or to be empty if the corresponding vertex
+/// of the base triangulation has been removed.
+fn update_layer_slot<S: SpadeNum>(
+    layer: &mut DelaunayTriangulation<Point2<S>>,
+    slot: FixedVertexHandle,
+    position: Option<Point2<S>>,
+) {
+    match (layer.s().is_vertex_live(slot), position) {
+        (true, Some(position)) => {
+            // Moving can fail if the layer still contains an outdated vertex at this position.
+            // The outdated vertex is kept as it still serves as a hint.
+            let _ = layer.move_vertex(slot, position);
+        }
+        (true, None) => {
+            layer.set_stable_handles(true);
+            layer.remove(slot);
+        }
+        (false, Some(position)) => insert_into_slot(layer, slot, position),
+        (false, None) => {}
+    }
+}
+
 #[cfg(test)]
 mod test {
     use rand::{seq::IndexedRandom as _, RngCore, SeedableRng};
 
     use crate::{
-        handles::FixedVertexHandle, test_utilities, DelaunayTriangulation, InsertionError, Point2,
-        Triangulation, TriangulationExt,
+        handles::FixedVertexHandle, test_utilities, DelaunayTriangulation, HintGenerator,
+        InsertionError, MoveVertexError, Point2, Triangulation, TriangulationExt,
     };
 
     use alloc::vec::Vec;
@@ -313,6 +444,26 @@ mod test {
     }
 
     fn hierarchy_sanity_check(triangulation: &HierarchyTriangulation) {
+        // Slots of removed vertices must be empty in all layers
+        for index in 0..triangulation.s().num_vertex_slots() {
+            let mut power = BRANCH_FACTOR as usize;
+            if triangulation
+                .s()
+                .is_vertex_live(FixedVertexHandle::new(index))
+            {
+                continue;
+            }
+            for layer in &triangulation.hint_generator().hierarchy {
+                if index % power != 0 {
+                    break;
+                }
+                assert!(!layer
+                    .s()
+                    .is_vertex_live(FixedVertexHandle::new(index / power)));
+                power *= BRANCH_FACTOR as usize;
+            }
+        }
+
         for vertex in triangulation.vertices() {
             let position = vertex.position();
             let base_index = vertex.fix().index() as u32;
@@ -355,6 +506,22 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn hierarchy_hint_generator_move_vertex_test() -> Result<(), MoveVertexError> {
+        let vertices = test_utilities::random_points_with_seed(300, test_utilities::SEED);
+        let mut triangulation = HierarchyTriangulation::bulk_load(vertices)?;
+
+        let new_positions = test_utilities::random_points_with_seed(100, test_utilities::SEED2);
+        for (index, new_position) in new_positions.into_iter().enumerate() {
+            // Moving a vertex far away requires re-inserting it
+            let vertex = FixedVertexHandle::new((index * BRANCH_FACTOR as usize) % 300);
+            triangulation.move_vertex(vertex, new_position.mul(10.0))?;
+            hierarchy_sanity_check(&triangulation);
+        }
+        triangulation.sanity_check();
+        Ok(())
+    }
+
     #[test]
     fn hierarchy_hint_generator_removal_test() -> Result<(), InsertionError> {
         let vertices = test_utilities::random_points_with_seed(300, test_utilities::SEED);
@@ -371,4 +538,42 @@ mod test {
         }
         Ok(())
     }
+
+    #[test]
+    fn hierarchy_hint_generator_stable_removal_test() -> Result<(), InsertionError> {
+        let vertices = test_utilities::random_points_with_seed(300, test_utilities::SEED);
+        let mut triangulation = HierarchyTriangulation::bulk_load(vertices)?;
+        triangulation.set_stable_handles(true);
+
+        let mut rng = rand::rngs::StdRng::from_seed(*test_utilities::SEED2);
+        let mut new_vertices = test_utilities::random_points_with_seed(200, test_utilities::SEED);
+        let queries = test_utilities::random_points_with_seed(100, test_utilities::SEED2);
+        for _ in 0..10 {
+            // Removes vertex 0 and other vertices that are part of multiple layers
+            for index in [0, 9, 27, 54] {
+                let vertex = FixedVertexHandle::new(index);
+                if triangulation.s().is_vertex_live(vertex) {
+                    triangulation.remove(vertex);
+                }
+            }
+            for _ in 0..20 {
+                let vertices: Vec<_> = triangulation.fixed_vertices().collect();
+                triangulation.remove(*vertices.choose(&mut rng).unwrap());
+            }
+            hierarchy_sanity_check(&triangulation);
+
+            for query in &queries {
+                let hint = HintGenerator::get_hint(triangulation.hint_generator(), *query);
+                assert!(triangulation.s().is_vertex_live(hint));
+            }
+
+            // Fills the gaps of removed vertices
+            for vertex in new_vertices.drain(..20) {
+                triangulation.insert(vertex.mul(2.0))?;
+                hierarchy_sanity_check(&triangulation);
+            }
+        }
+        triangulation.sanity_check();
+        Ok(())
+    }
 }
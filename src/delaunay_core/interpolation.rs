@@ -629,11 +629,11 @@ where
     where
         I: Fn(VertexHandle<V, DE, UE, F>) -> <V as HasPosition>::Scalar,
     {
-        let grads = self
-            .triangulation
-            .vertices()
-            .map(|v| self.estimate_gradient(v, &i))
-            .collect::<Vec<_>>();
+        // Indexed by vertex handle. Removed vertices leave gaps if stable handles are enabled.
+        let mut grads = alloc::vec![[zero(); 2]; self.triangulation.s().num_vertex_slots()];
+        for v in self.triangulation.vertices() {
+            grads[v.index()] = self.estimate_gradient(v, &i);
+        }
 
         move |v: VertexHandle<V, DE, UE, F>| grads[v.index()]
     }
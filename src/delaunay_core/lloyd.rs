@@ -0,0 +1,535 @@
+#[cfg(not(feature = "std"))]
+use hashbrown::HashSet;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+use alloc::vec::Vec;
+
+use num_traits::{Float, Zero};
+
+use crate::{
+    delaunay_core::math,
+    handles::{FixedVertexHandle, VertexHandle},
+    CellBounds, DelaunayTriangulation, FloatTriangulation, HasPosition, HasPositionMut,
+    HintGenerator, MoveVertexError, Point2, Triangulation,
+};
+
+/// Contains details about the outcome of a Lloyd relaxation.
+///
+/// *See [DelaunayTriangulation::lloyd_relax]*
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelaxationResult<S> {
+    /// The number of performed iterations.
+    pub iterations: usize,
+
+    /// The largest distance that any vertex has been moved during the last iteration.
+    pub max_displacement: S,
+
+    /// Set to `true` if the relaxation stopped because `max_displacement` fell below the given
+    /// tolerance.
+    ///
+    /// This will be `false` if the relaxation ran out of iterations.
+    pub converged: bool,
+}
+
+impl<V, DE, UE, F, L> DelaunayTriangulation<V, DE, UE, F, L>
+where
+    V: HasPositionMut,
+    V::Scalar: Float,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+{
+    /// Performs a [Lloyd relaxation](https://en.wikipedia.org/wiki/Lloyd%27s_algorithm) of this
+    /// triangulation.
+    ///
+    /// Each iteration moves every vertex to the centroid of its
+    /// [clipped Voronoi cell](crate::FloatTriangulation::voronoi_cells_clipped) and rebuilds the
+    /// triangulation afterward. Repeating this converges towards a *centroidal Voronoi tessellation*
+    /// which evenly distributes the vertices within `bounds`. This is useful to generate blue noise
+    /// sample sets or well-shaped meshes.
+    ///
+    /// The relaxation stops after `iterations` iterations or once no vertex has been moved farther
+    /// than `tolerance` during an iteration.
+    ///
+    /// Vertex handles and vertex data (except for their position) are kept. Vertices whose cell
+    /// doesn't overlap `bounds` will not be moved. Edge and face data is reset to its default value.
+    ///
+    /// If [stable handles](crate::Triangulation::set_stable_handles) are enabled, the vertices are
+    /// moved one by one with [move_vertex](crate::Triangulation::move_vertex) instead of rebuilding
+    /// the triangulation. This is slower but keeps all gaps left by previously removed vertices.
+    ///
+    /// *See also [lloyd_relax_with_pinned_vertices](Self::lloyd_relax_with_pinned_vertices)*
+    ///
+    /// # Duplicate handling
+    ///
+    /// Vertices are never moved onto the position of another vertex. If a vertex' new position is
+    /// already occupied, the vertex is kept in place for this iteration instead. This should not
+    /// happen for any reasonable input.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{CellBounds, DelaunayTriangulation, Point2, Triangulation};
+    ///
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let vertices = vec![
+    ///     Point2::new(0.1, 0.1),
+    ///     Point2::new(0.2, 0.15),
+    ///     Point2::new(0.15, 0.3),
+    ///     Point2::new(0.9, 0.8),
+    /// ];
+    /// let mut triangulation = DelaunayTriangulation::<Point2<f64>>::bulk_load_stable(vertices)?;
+    ///
+    /// let bounds = CellBounds::Rectangle {
+    ///     lower: Point2::new(0.0, 0.0),
+    ///     upper: Point2::new(1.0, 1.0),
+    /// };
+    /// let result = triangulation.lloyd_relax(&bounds, 100, 1.0e-6);
+    /// assert!(result.converged);
+    /// assert_eq!(triangulation.num_vertices(), 4);
+    /// # Ok(()) }
+    /// ```
+    pub fn lloyd_relax(
+        &mut self,
+        bounds: &CellBounds<V::Scalar>,
+        iterations: usize,
+        tolerance: V::Scalar,
+    ) -> RelaxationResult<V::Scalar> {
+        self.lloyd_relax_with_pinned_vertices(bounds, iterations, tolerance, |_| false)
+    }
+
+    /// Performs a Lloyd relaxation while keeping some vertices at their position.
+    ///
+    /// `is_pinned` is called once per vertex and iteration. Vertices for which it returns `true`
+    /// will not be moved. Pinned vertices still affect the cells of their neighbors.
+    ///
+    /// *See [lloyd_relax](Self::lloyd_relax) for more information.*
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{CellBounds, DelaunayTriangulation, Point2, Triangulation};
+    ///
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let vertices = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(0.2, 0.15),
+    ///     Point2::new(0.15, 0.3),
+    ///     Point2::new(0.9, 0.8),
+    /// ];
+    /// let mut triangulation = DelaunayTriangulation::<Point2<f64>>::bulk_load_stable(vertices)?;
+    ///
+    /// let bounds = CellBounds::Rectangle {
+    ///     lower: Point2::new(0.0, 0.0),
+    ///     upper: Point2::new(1.0, 1.0),
+    /// };
+    /// // Keeps the vertex at the origin in place
+    /// triangulation.lloyd_relax_with_pinned_vertices(&bounds, 10, 1.0e-6, |vertex| {
+    ///     vertex.position() == Point2::new(0.0, 0.0)
+    /// });
+    /// assert_eq!(*triangulation.vertices().next().unwrap().data(), Point2::new(0.0, 0.0));
+    /// # Ok(()) }
+    /// ```
+    pub fn lloyd_relax_with_pinned_vertices(
+        &mut self,
+        bounds: &CellBounds<V::Scalar>,
+        iterations: usize,
+        tolerance: V::Scalar,
+        mut is_pinned: impl FnMut(VertexHandle<'_, V, DE, UE, F>) -> bool,
+    ) -> RelaxationResult<V::Scalar> {
+        let mut result = RelaxationResult {
+            iterations: 0,
+            max_displacement: V::Scalar::zero(),
+            converged: false,
+        };
+
+        while result.iterations < iterations {
+            let mut new_positions = Vec::with_capacity(self.num_vertices());
+            for cell in self.voronoi_cells_clipped(bounds) {
+                let vertex = self.vertex(cell.vertex);
+                let new_position = match cell.centroid {
+                    Some(centroid)
+                        if math::validate_vertex(&centroid).is_ok() && !is_pinned(vertex) =>
+                    {
+                        centroid
+                    }
+                    _ => vertex.position(),
+                };
+                new_positions.push((cell.vertex, new_position));
+            }
+
+            let max_displacement_2 = if self.has_stable_handles() {
+                self.move_vertices(new_positions)
+            } else {
+                self.rebuild_with_positions(new_positions)
+            };
+
+            result.iterations += 1;
+            result.max_displacement = max_displacement_2.sqrt();
+
+            if result.max_displacement <= tolerance {
+                result.converged = true;
+                break;
+            }
+        }
+        result
+    }
+
+    /// Moves all vertices to their new position and rebuilds the triangulation.
+    ///
+    /// Like [move_vertices](Self::move_vertices), vertices whose new position is already
+    /// occupied are kept in place. This keeps all vertices and their handles.
+    ///
+    /// Returns the largest squared displacement.
+    fn rebuild_with_positions(
+        &mut self,
+        mut new_positions: Vec<(FixedVertexHandle, Point2<V::Scalar>)>,
+    ) -> V::Scalar {
+        self.keep_occupied_positions(&mut new_positions);
+        let max_displacement_2 = new_positions
+            .iter()
+            .map(|(vertex, position)| self.vertex(*vertex).position().distance_2(*position))
+            .fold(V::Scalar::zero(), |max, displacement_2| {
+                max.max(displacement_2)
+            });
+
+        if max_displacement_2 > V::Scalar::zero() {
+            let vertex_generations: Vec<_> = self
+                .fixed_vertices()
+                .map(|vertex| self.s().vertex_generation(vertex))
+                .collect();
+
+            // Invalidates all handles to the previous triangulation
+            let mut vertices = self.dcel.take_vertices();
+            for (vertex, (_, position)) in vertices.iter_mut().zip(new_positions) {
+                vertex.set_position(position);
+            }
+            // All positions have been validated and are distinct, bulk loading cannot fail
+            let mut rebuilt = Self::bulk_load_stable(vertices).expect("Invalid vertex position");
+            core::mem::swap(&mut rebuilt.dcel.generations, &mut self.dcel.generations);
+            *self = rebuilt;
+
+            // Vertices keep their handles as no duplicates have been removed
+            for (index, generation) in vertex_generations.into_iter().enumerate() {
+                self.s_mut()
+                    .set_vertex_generation(FixedVertexHandle::new(index), generation);
+            }
+        }
+        max_displacement_2
+    }
+
+    /// Resets the new position of vertices to their old position if they would be moved onto
+    /// another vertex.
+    ///
+    /// The vertices are moved in order, the same way as [move_vertices](Self::move_vertices) does.
+    fn keep_occupied_positions(
+        &self,
+        new_positions: &mut [(FixedVertexHandle, Point2<V::Scalar>)],
+    ) {
+        // Positions are valid and can be compared by their bits. Adding zero turns -0.0 into 0.0.
+        let key = |position: Point2<V::Scalar>| {
+            let position = position.to_f64();
+            [position.x + 0.0, position.y + 0.0].map(f64::to_bits)
+        };
+
+        let mut occupied: HashSet<_> = self.vertices().map(|v| key(v.position())).collect();
+        for (vertex, position) in new_positions {
+            let old_position = self.vertex(*vertex).position();
+            if old_position == *position {
+                continue;
+            }
+            if occupied.insert(key(*position)) {
+                occupied.remove(&key(old_position));
+            } else {
+                *position = old_position;
+            }
+        }
+    }
+
+    /// Moves the vertices one by one. Vertices whose new position is already occupied are kept
+    /// in place.
+    ///
+    /// Returns the largest squared displacement of all moved vertices.
+    fn move_vertices(
+        &mut self,
+        new_positions: Vec<(FixedVertexHandle, Point2<V::Scalar>)>,
+    ) -> V::Scalar {
+        let mut max_displacement_2 = V::Scalar::zero();
+        for (vertex, position) in new_positions {
+            let old_position = self.vertex(vertex).position();
+            if old_position == position {
+                continue;
+            }
+            match self.move_vertex(vertex, position) {
+                Ok(_) => {}
+                // Moving onto another vertex is not allowed. Keep this vertex in place instead.
+                Err(MoveVertexError::OccupiedPosition(_)) => continue,
+                // All positions have been validated
+                Err(error) => panic!("Invalid vertex position: {error}"),
+            }
+            max_displacement_2 = max_displacement_2.max(old_position.distance_2(position));
+        }
+        max_displacement_2
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_utilities::{random_points_in_range, SEED};
+    use crate::{
+        handles::FixedVertexHandle, CellBounds, DelaunayTriangulation, FloatTriangulation,
+        HasPosition, HasPositionMut, InsertionError, Point2, Triangulation, TriangulationExt,
+    };
+
+    use alloc::vec::Vec;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Particle {
+        position: Point2<f64>,
+        id: usize,
+    }
+
+    impl HasPosition for Particle {
+        type Scalar = f64;
+
+        fn position(&self) -> Point2<f64> {
+            self.position
+        }
+    }
+
+    impl HasPositionMut for Particle {
+        fn set_position(&mut self, position: Point2<f64>) {
+            self.position = position;
+        }
+    }
+
+    #[test]
+    fn test_lloyd_relax() -> Result<(), InsertionError> {
+        let vertices = random_points_in_range(0.5, 100, SEED)
+            .into_iter()
+            .enumerate()
+            .map(|(id, position)| Particle { position, id })
+            .collect::<Vec<_>>();
+        let mut triangulation = DelaunayTriangulation::<_>::bulk_load_stable(vertices)?;
+
+        let bounds = CellBounds::Rectangle {
+            lower: Point2::new(-1.0, -1.0),
+            upper: Point2::new(1.0, 1.0),
+        };
+        let result = triangulation.lloyd_relax(&bounds, 20, 0.0);
+        assert_eq!(result.iterations, 20);
+        assert!(!result.converged);
+
+        triangulation.sanity_check();
+        assert_eq!(triangulation.num_vertices(), 100);
+        for (index, vertex) in triangulation.vertices().enumerate() {
+            assert_eq!(vertex.data().id, index);
+            let position = vertex.position();
+            assert!(position.x.abs() <= 1.0 && position.y.abs() <= 1.0);
+        }
+
+        let result = triangulation.lloyd_relax(&bounds, 1000, 1.0e-4);
+        assert!(result.converged);
+        assert!(result.iterations < 1000);
+        assert!(result.max_displacement <= 1.0e-4);
+
+        // The vertices should have spread out evenly
+        let cells = triangulation.voronoi_cells_clipped(&bounds);
+        let (min, max) = cells.iter().fold((f64::MAX, 0.0f64), |(min, max), cell| {
+            (min.min(cell.area), max.max(cell.area))
+        });
+        assert!(max / min < 2.0);
+        for cell in cells {
+            let position = triangulation.vertex(cell.vertex).position();
+            assert!(position.distance_2(cell.centroid.unwrap()) <= 1.0e-8);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_lloyd_relax_invalidates_checked_handles() -> Result<(), InsertionError> {
+        let vertices = random_points_in_range(0.5, 100, SEED);
+        let mut triangulation = DelaunayTriangulation::<_>::bulk_load_stable(vertices)?;
+
+        // Removing and re-inserting a vertex increases the generations of some slots
+        let removed = triangulation.remove(FixedVertexHandle::new(99));
+        triangulation.insert(removed)?;
+
+        let vertices: Vec<_> = triangulation.vertices().map(|v| v.checked()).collect();
+        let faces: Vec<_> = triangulation.inner_faces().map(|f| f.checked()).collect();
+        let edges: Vec<_> = triangulation
+            .undirected_edges()
+            .map(|e| e.checked())
+            .collect();
+        let directed_edges: Vec<_> = triangulation
+            .directed_edges()
+            .map(|e| e.checked())
+            .collect();
+
+        let bounds = CellBounds::Rectangle {
+            lower: Point2::new(-1.0, -1.0),
+            upper: Point2::new(1.0, 1.0),
+        };
+        triangulation.lloyd_relax(&bounds, 1, 0.0);
+
+        for vertex in vertices {
+            assert!(triangulation.try_vertex(vertex).is_some());
+        }
+        for face in faces {
+            assert!(triangulation.try_face(face).is_none());
+        }
+        for edge in edges {
+            assert!(triangulation.try_undirected_edge(edge).is_none());
+        }
+        for edge in directed_edges {
+            assert!(triangulation.try_directed_edge(edge).is_none());
+        }
+        assert!(triangulation
+            .inner_faces()
+            .all(|face| triangulation.try_face(face.checked()).is_some()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lloyd_relax_with_pinned_vertices() -> Result<(), InsertionError> {
+        let vertices = random_points_in_range(1.0, 50, SEED);
+        let mut triangulation = DelaunayTriangulation::<_>::bulk_load_stable(vertices.clone())?;
+
+        let bounds = CellBounds::ConvexPolygon(alloc::vec![
+            Point2::new(-1.0, -1.0),
+            Point2::new(1.0, -1.0),
+            Point2::new(0.0, 1.0),
+        ]);
+        triangulation.lloyd_relax_with_pinned_vertices(&bounds, 5, 0.0, |vertex| {
+            vertex.fix().index() % 2 == 0
+        });
+        triangulation.sanity_check();
+
+        let mut num_moved = 0;
+        for (vertex, original) in triangulation.vertices().zip(vertices) {
+            if vertex.fix().index() % 2 == 0 {
+                assert_eq!(vertex.position(), original);
+            } else if vertex.position() != original {
+                num_moved += 1;
+            }
+        }
+        assert!(num_moved > 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_lloyd_relax_with_stable_handles() -> Result<(), InsertionError> {
+        let vertices = random_points_in_range(0.5, 100, SEED)
+            .into_iter()
+            .enumerate()
+            .map(|(id, position)| Particle { position, id })
+            .collect::<Vec<_>>();
+        let mut triangulation = DelaunayTriangulation::<_>::bulk_load_stable(vertices)?;
+        triangulation.set_stable_handles(true);
+        for index in (0..100).step_by(4) {
+            triangulation.remove(FixedVertexHandle::from_index(index));
+        }
+
+        let bounds = CellBounds::Rectangle {
+            lower: Point2::new(-1.0, -1.0),
+            upper: Point2::new(1.0, 1.0),
+        };
+        triangulation.lloyd_relax(&bounds, 10, 0.0);
+        triangulation.sanity_check();
+        assert!(triangulation.has_stable_handles());
+        assert_eq!(triangulation.num_vertices(), 75);
+        for vertex in triangulation.vertices() {
+            assert_eq!(vertex.data().id, vertex.fix().index());
+            assert_ne!(vertex.fix().index() % 4, 0);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_lloyd_relax_max_displacement() -> Result<(), InsertionError> {
+        let bounds = CellBounds::Rectangle {
+            lower: Point2::new(-1.0, -1.0),
+            upper: Point2::new(1.0, 1.0),
+        };
+        for stable_handles in [false, true] {
+            let vertices = random_points_in_range(0.5, 50, SEED);
+            let mut triangulation = DelaunayTriangulation::<_>::bulk_load_stable(vertices)?;
+            triangulation.set_stable_handles(stable_handles);
+
+            let old_positions = triangulation
+                .vertices()
+                .map(|vertex| vertex.position())
+                .collect::<Vec<_>>();
+            let result = triangulation
+                .lloyd_relax_with_pinned_vertices(&bounds, 1, 0.0, |v| v.fix().index() % 3 == 0);
+            let max_displacement = triangulation
+                .vertices()
+                .zip(old_positions)
+                .map(|(vertex, old_position)| vertex.position().distance_2(old_position))
+                .fold(0.0f64, f64::max)
+                .sqrt();
+            assert!(max_displacement > 0.0);
+            assert_eq!(result.max_displacement, max_displacement);
+
+            // Pinned vertices are not moved and don't count as displaced
+            let result = triangulation.lloyd_relax_with_pinned_vertices(&bounds, 10, 0.0, |_| true);
+            assert!(result.converged);
+            assert_eq!(result.iterations, 1);
+            assert_eq!(result.max_displacement, 0.0);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_lloyd_relax_keeps_colliding_vertices() -> Result<(), InsertionError> {
+        let vertices = alloc::vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(1.0, 1.0),
+        ];
+        let v = FixedVertexHandle::from_index;
+        // v1 collides with v0, v2 with v1's old position. v3 takes v0's old position.
+        let new_positions = alloc::vec![
+            (v(0), Point2::new(0.5, 0.5)),
+            (v(1), Point2::new(0.5, 0.5)),
+            (v(2), Point2::new(1.0, 0.0)),
+            (v(3), Point2::new(-0.0, 0.0)),
+        ];
+        let expected = [
+            Point2::new(0.5, 0.5),
+            Point2::new(1.0, 0.0),
+            Point2::new(0.0, 1.0),
+            Point2::new(0.0, 0.0),
+        ];
+
+        for stable_handles in [false, true] {
+            let mut triangulation = DelaunayTriangulation::<_>::bulk_load_stable(vertices.clone())?;
+            triangulation.set_stable_handles(stable_handles);
+            let max_displacement_2 = if stable_handles {
+                triangulation.move_vertices(new_positions.clone())
+            } else {
+                triangulation.rebuild_with_positions(new_positions.clone())
+            };
+
+            triangulation.sanity_check();
+            assert_eq!(max_displacement_2, 2.0);
+            let positions: Vec<_> = triangulation.vertices().map(|v| v.position()).collect();
+            assert_eq!(positions, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_lloyd_relax_empty() {
+        let mut triangulation = DelaunayTriangulation::<Point2<f64>>::new();
+        let bounds = CellBounds::Rectangle {
+            lower: Point2::new(0.0, 0.0),
+            upper: Point2::new(1.0, 1.0),
+        };
+        let result = triangulation.lloyd_relax(&bounds, 10, 0.0);
+        assert!(result.converged);
+        assert_eq!(result.iterations, 1);
+        assert_eq!(triangulation.num_vertices(), 0);
+    }
+}
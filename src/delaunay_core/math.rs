@@ -1,4 +1,5 @@
-use crate::{HasPosition, LineSideInfo, Point2, SpadeNum};
+use super::expansion::Expansion;
+use crate::{handles::FixedVertexHandle, HasPosition, LineSideInfo, Point2, SpadeNum};
 use num_traits::{zero, Float};
 
 /// Indicates a point's projected position relative to an edge.
@@ -14,8 +15,8 @@ pub struct PointProjection<S> {
 
 /// The error type used for inserting elements into a triangulation.
 ///
-/// Errors during insertion can only originate from an invalid vertex position. Vertices can
-/// be checked for validity by using [validate_vertex].
+/// Errors during insertion originate from an invalid vertex position. Vertices can be checked for
+/// validity by using [validate_vertex].
 #[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug, Hash)]
 pub enum InsertionError {
     /// A coordinate value was too small.
@@ -43,6 +44,35 @@ impl core::fmt::Display for InsertionError {
 #[cfg(feature = "std")]
 impl std::error::Error for InsertionError {}
 
+/// The error type used for moving a vertex of a triangulation.
+///
+/// *See [crate::Triangulation::move_vertex]*
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug, Hash)]
+#[non_exhaustive]
+pub enum MoveVertexError {
+    /// The target position is not valid.
+    InsertionError(InsertionError),
+
+    /// Another vertex is already located at the target position. Contains the handle of that
+    /// vertex.
+    OccupiedPosition(FixedVertexHandle),
+}
+
+impl core::fmt::Display for MoveVertexError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <Self as core::fmt::Debug>::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MoveVertexError {}
+
+impl From<InsertionError> for MoveVertexError {
+    fn from(error: InsertionError) -> Self {
+        MoveVertexError::InsertionError(error)
+    }
+}
+
 /// The smallest allowed coordinate value greater than zero that can be inserted into Delaunay
 /// triangulations. This value is equal to 2<sup>-142</sup>.
 ///
@@ -287,6 +317,139 @@ where
     robust::incircle(v3, v2, v1, p) < 0.0
 }
 
+/// Weighted variant of [contained_in_circumference] used for regular (power) triangulations.
+///
+/// Each point `(x, y)` with weight `w` is lifted onto the paraboloid `(x, y, x² + y² - w)`.
+/// Returns `true` if the lifted `p` lies strictly below the plane spanned by the lifted vertices
+/// `v1`, `v2` and `v3`. The vertices must be ordered ccw.
+pub fn contained_in_power_circle<S>(
+    v1: Point2<S>,
+    v2: Point2<S>,
+    v3: Point2<S>,
+    p: Point2<S>,
+    weights: [S; 4],
+) -> bool
+where
+    S: SpadeNum,
+{
+    power_circle_determinant(v1, v2, v3, p, weights) > 0.0
+}
+
+/// Returns the lifted orientation determinant used by [contained_in_power_circle].
+///
+/// The result is positive if `p` lies within the power circle of `v1`, `v2` and `v3` and equals
+/// the product of the triangle's doubled area and the vertical distance between the lifted `p` and
+/// the plane through the lifted triangle.
+///
+/// The sign of the result is exact. The determinant is first evaluated with regular floating point
+/// arithmetic. If the result is too close to zero to be certain about its sign, it is evaluated
+/// again with exact arithmetic. Like for [contained_in_circumference], this assumes that no
+/// intermediate result overflows or underflows.
+pub fn power_circle_determinant<S>(
+    v1: Point2<S>,
+    v2: Point2<S>,
+    v3: Point2<S>,
+    p: Point2<S>,
+    weights: [S; 4],
+) -> f64
+where
+    S: SpadeNum,
+{
+    let [v1, v2, v3, p] = [v1, v2, v3, p].map(|v| v.to_f64());
+    let [w1, w2, w3, wp] = weights.map(|w| w.into());
+
+    // Lifted coordinates relative to p
+    let relative = |v: Point2<f64>, w: f64| {
+        let d = v.sub(p);
+        (d, d.length2() - (w - wp))
+    };
+    let (a, a_lift) = relative(v1, w1);
+    let (b, b_lift) = relative(v2, w2);
+    let (c, c_lift) = relative(v3, w3);
+
+    let det = a_lift * (b.x * c.y - b.y * c.x)
+        + b_lift * (c.x * a.y - c.y * a.x)
+        + c_lift * (a.x * b.y - a.y * b.x);
+
+    let permanent = (a.x * a.x + a.y * a.y + (w1 - wp).abs())
+        * ((b.x * c.y).abs() + (b.y * c.x).abs())
+        + (b.x * b.x + b.y * b.y + (w2 - wp).abs()) * ((c.x * a.y).abs() + (c.y * a.x).abs())
+        + (c.x * c.x + c.y * c.y + (w3 - wp).abs()) * ((a.x * b.y).abs() + (a.y * b.x).abs());
+    let error_bound = 16.0 * f64::EPSILON * permanent;
+    if det.abs() > error_bound {
+        return det;
+    }
+
+    exact_power_circle_determinant([v1, v2, v3, p], [w1, w2, w3, wp]).estimate()
+}
+
+fn exact_power_circle_determinant(
+    [v1, v2, v3, p]: [Point2<f64>; 4],
+    [w1, w2, w3, wp]: [f64; 4],
+) -> Expansion {
+    let relative = |v: Point2<f64>, w: f64| {
+        let x = Expansion::difference(v.x, p.x);
+        let y = Expansion::difference(v.y, p.y);
+        let lift = x.mul(&x).add(&y.mul(&y)).sub(&Expansion::difference(w, wp));
+        (x, y, lift)
+    };
+    let (ax, ay, a_lift) = relative(v1, w1);
+    let (bx, by, b_lift) = relative(v2, w2);
+    let (cx, cy, c_lift) = relative(v3, w3);
+
+    let bc = bx.mul(&cy).sub(&by.mul(&cx));
+    let ca = cx.mul(&ay).sub(&cy.mul(&ax));
+    let ab = ax.mul(&by).sub(&ay.mul(&bx));
+
+    a_lift.mul(&bc).add(&b_lift.mul(&ca)).add(&c_lift.mul(&ab))
+}
+
+/// Returns `true` if the lifted `p` lies on or above the line through the lifted `from` and `to`.
+///
+/// All three points are assumed to be collinear and `p` is expected to lie between `from` and
+/// `to`. See [contained_in_power_circle] for more information about lifting. The result is
+/// calculated with exact arithmetic.
+pub fn is_redundant_on_segment<S>(
+    from: Point2<S>,
+    to: Point2<S>,
+    p: Point2<S>,
+    [w_from, w_to, w_p]: [S; 3],
+) -> bool
+where
+    S: SpadeNum,
+{
+    let [from, to, p] = [from, to, p].map(|v| v.to_f64());
+    let [w_from, w_to, w_p] = [w_from, w_to, w_p].map(|w| w.into());
+
+    // Lifted heights relative to the lifted `from`
+    let lift = |point: Point2<f64>, weight: f64| {
+        let x = Expansion::difference(point.x, from.x);
+        let y = Expansion::difference(point.y, from.y);
+        let height = x
+            .mul(&x)
+            .add(&y.mul(&y))
+            .sub(&Expansion::difference(weight, w_from))
+            .add(
+                &Expansion::from_value(2.0).mul(
+                    &x.mul(&Expansion::from_value(from.x))
+                        .add(&y.mul(&Expansion::from_value(from.y))),
+                ),
+            );
+        (x, y, height)
+    };
+    let (dir_x, dir_y, to_height) = lift(to, w_to);
+    let (p_x, p_y, p_height) = lift(p, w_p);
+
+    // p is redundant if p_height * |dir|² >= to_height * ((p - from) · dir)
+    let length_2 = dir_x.mul(&dir_x).add(&dir_y.mul(&dir_y));
+    let factor = p_x.mul(&dir_x).add(&p_y.mul(&dir_y));
+    p_height
+        .mul(&length_2)
+        .sub(&to_height.mul(&factor))
+        .estimate()
+        >= 0.0
+}
+
 pub fn is_ordered_ccw<S>(p1: Point2<S>, p2: Point2<S>, query_point: Point2<S>) -> bool
 where
     S: SpadeNum,
@@ -376,6 +539,33 @@ where
     (result.add(v0), x * x + y * y)
 }
 
+/// Returns the center and the squared radius of the circle that is orthogonal to the three
+/// weighted circles given by `positions` and `weights`.
+///
+/// The squared radius is negative if the three weighted circles overlap each other. For zero
+/// weights, this is identical to the circumcircle.
+pub fn orthocenter<S>(positions: [Point2<S>; 3], weights: [S; 3]) -> (Point2<S>, S)
+where
+    S: SpadeNum + Float,
+{
+    let [v0, v1, v2] = positions;
+    let [w0, w1, w2] = weights;
+    let b = v1.sub(v0);
+    let c = v2.sub(v0);
+
+    let one = S::one();
+    let two = one + one;
+    let d = two * (b.x * c.y - c.x * b.y);
+    let len_b = b.dot(b) - (w1 - w0);
+    let len_c = c.dot(c) - (w2 - w0);
+    let d_inv: S = one / d;
+
+    let x = (len_b * c.y - len_c * b.y) * d_inv;
+    let y = (-len_b * c.x + len_c * b.x) * d_inv;
+    let result = Point2::new(x, y);
+    (result.add(v0), x * x + y * y - w0)
+}
+
 pub fn triangle_area<S>(positions: [Point2<S>; 3]) -> S
 where
     S: SpadeNum,
@@ -628,4 +818,78 @@ mod test {
             Point2::new(0f64, -0.5f64)
         ));
     }
+
+    #[test]
+    fn test_power_circle_determinant_is_exact() {
+        use super::power_circle_determinant;
+
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = move || {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            ((seed >> 38) as i64) - (1 << 24)
+        };
+
+        for _ in 0..1000 {
+            let [a, b, c] = [(); 3].map(|_| (next() as i128, next() as i128));
+            let orientation_c = a.0 * b.1 - a.1 * b.0;
+            if orientation_c == 0 {
+                continue;
+            }
+            let lift = |(x, y): (i128, i128)| x * x + y * y;
+            let incircle = lift(a) * (b.0 * c.1 - b.1 * c.0)
+                + lift(b) * (c.0 * a.1 - c.1 * a.0)
+                + lift(c) * orientation_c;
+
+            // Choose c's weight such that the lifted points are almost coplanar
+            let base_weight = incircle / orientation_c;
+            if base_weight.abs() >= 1 << 52 {
+                // Not exactly representable as f64
+                continue;
+            }
+            for weight in [base_weight - 1, base_weight, base_weight + 1] {
+                let expected = (incircle - weight * orientation_c).signum();
+                let to_point = |(x, y): (i128, i128)| Point2::new(x as f64, y as f64);
+                let determinant = power_circle_determinant(
+                    to_point(a),
+                    to_point(b),
+                    to_point(c),
+                    Point2::new(0.0, 0.0),
+                    [0.0, 0.0, weight as f64, 0.0],
+                );
+                let sign = if determinant > 0.0 {
+                    1
+                } else if determinant < 0.0 {
+                    -1
+                } else {
+                    0
+                };
+                assert_eq!(sign, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_orthocenter() {
+        use super::{circumcenter, orthocenter};
+
+        let positions = [
+            Point2::new(0.0f64, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(1.0, 3.0),
+        ];
+        let (center, radius_2) = orthocenter(positions, [0.0; 3]);
+        let (expected_center, expected_radius_2) = circumcenter(positions);
+        assert_relative_eq!(center.x, expected_center.x);
+        assert_relative_eq!(center.y, expected_center.y);
+        assert_relative_eq!(radius_2, expected_radius_2);
+
+        let weights = [1.0, 2.0, 0.5];
+        let (center, radius_2) = orthocenter(positions, weights);
+        for (position, weight) in positions.iter().zip(weights) {
+            // All weighted circles must have the same power distance to the center
+            assert_relative_eq!(position.distance_2(center) - weight, radius_2);
+        }
+    }
 }
@@ -1,13 +1,23 @@
+mod boolean;
 mod bulk_load;
+mod clipping;
 
 #[cfg(test)]
 mod bulk_load_fuzz_tests;
 
 mod dcel;
 pub mod dcel_operations;
+mod expansion;
 mod handles;
+mod hilbert;
 mod hint_generator;
 mod line_side_info;
+mod lloyd;
+#[cfg(feature = "rayon")]
+mod parallel_bulk_load;
+mod polygon_fill;
+mod regions;
+mod remapping;
 mod triangulation_ext;
 
 pub mod refinement;
@@ -16,18 +26,28 @@ pub mod interpolation;
 pub mod math;
 
 pub use bulk_load::{bulk_load, bulk_load_cdt, bulk_load_stable};
+#[cfg(feature = "rayon")]
+pub use parallel_bulk_load::bulk_load_parallel;
 
 pub use triangulation_ext::{RemovalResult, TriangulationExt};
 
+pub(crate) use clipping::clip_power_cell;
+pub use clipping::{CellBounds, ClippedCell};
 pub use dcel::Dcel;
+pub(crate) use hilbert::{hilbert_sort, hilbert_sort_by_key};
 pub use hint_generator::{
     HierarchyHintGenerator, HierarchyHintGeneratorWithBranchFactor, HintGenerator,
     LastUsedVertexHintGenerator,
 };
 
-pub use refinement::{AngleLimit, RefinementParameters, RefinementResult};
+pub use refinement::{AngleLimit, RefinementParameters, RefinementResult, SteinerVertexLocation};
 
+pub use boolean::{boolean_operation, BooleanOperation, BooleanResult, PolygonSet};
 pub use line_side_info::LineSideInfo;
+pub use lloyd::RelaxationResult;
+pub use polygon_fill::FillRule;
+pub use regions::HasRegion;
+pub use remapping::HandleRemapping;
 
 pub use handles::iterators;
 pub use handles::*;
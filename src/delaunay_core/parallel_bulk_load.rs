@@ -0,0 +1,737 @@
+use core::cmp::Ordering;
+
+use rayon::prelude::*;
+
+use crate::{HasPosition, InsertionError, Point2, SpadeNum, Triangulation};
+
+use super::dcel::{Dcel, EdgeEntry, FaceEntry, HalfEdgeEntry, VertexEntry};
+use super::handles::{FixedDirectedEdgeHandle, FixedFaceHandle, FixedVertexHandle};
+use super::{dcel_operations, math};
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Sub problems with fewer vertices are triangulated sequentially.
+const MIN_PARALLEL_SIZE: usize = 1 << 14;
+
+/// Marks the origin of a deleted edge.
+const DELETED: u32 = u32::MAX;
+
+/// The largest number of vertices whose half edges can be indexed with an `u32`.
+///
+/// A triangulation with `n` vertices contains less than `3 * n` edges. Since `Edges` reuses the
+/// slots of deleted edges, it never stores more than `6 * n` half edges.
+const MAX_VERTICES: usize = (DELETED / 6) as usize;
+
+/// Implements a parallel divide and conquer algorithm for bulk loading Delaunay triangulations.
+///
+/// The algorithm is described in:
+///
+/// Primitives for the manipulation of general subdivisions and the computation of Voronoi diagrams
+/// Leonidas Guibas, Jorge Stolfi
+/// ACM Transactions on Graphics,
+/// Volume 4, Issue 2,
+/// 1985,
+/// <https://doi.org/10.1145/282918.282923>
+///
+/// Sub problems are split alternately along the x and y axis, similar to:
+///
+/// A faster divide-and-conquer algorithm for constructing Delaunay triangulations
+/// Rex A. Dwyer
+/// Algorithmica,
+/// Volume 2,
+/// 1987,
+/// <https://doi.org/10.1007/BF01840356>
+///
+/// This avoids long and thin sub triangulations whose edges would mostly be removed again when
+/// they are merged.
+///
+/// # Overview
+///
+///  1. Validate all vertices and remove duplicates.
+///  2. Partition the vertices recursively: Each range of vertices is split at its median along the
+///     current axis. Both halves are then partitioned along the other axis. This is comparable to
+///     building a k-d tree.
+///  3. Triangulate both halves of the partition recursively and merge them by connecting them
+///     along their lower common tangent and "zipping" them together. Edges of either half that
+///     are not Delaunay anymore are removed during this step.
+///  4. Convert the resulting edges into a `Dcel`.
+///
+/// Steps 1 to 3 are run in parallel until the sub problems become smaller than
+/// `MIN_PARALLEL_SIZE`.
+///
+/// The recursion uses a simplified [quad edge](https://en.wikipedia.org/wiki/Quad-edge) data
+/// structure (`Edges`) as it requires edge removals that would be expensive to do on a `Dcel`.
+pub fn bulk_load_parallel<V, T>(mut elements: Vec<V>) -> Result<T, InsertionError>
+where
+    V: HasPosition + Send + Sync,
+    V::Scalar: Send + Sync,
+    T: Triangulation<Vertex = V>,
+{
+    if elements.len() > MAX_VERTICES {
+        // Edge indices would not fit into an `u32`
+        return super::bulk_load(elements);
+    }
+
+    elements.par_iter().try_for_each(crate::validate_vertex)?;
+
+    elements.par_sort_unstable_by(|l, r| Axis::X.compare(l.position(), r.position()));
+    elements.dedup_by(|l, r| l.position() == r.position());
+
+    if elements.len() < 2 {
+        let mut result = T::new();
+        for element in elements {
+            result.insert(element)?;
+        }
+        return Ok(result);
+    }
+
+    partition(&mut elements, Axis::X);
+
+    let positions: Vec<_> = elements
+        .par_iter()
+        .map(|element| element.position())
+        .collect();
+    let (edges, _) = triangulate_parallel(&positions, 0, positions.len(), Axis::X);
+
+    let dcel = edges.into_dcel(elements, &positions);
+    Ok(T::from_parts(dcel, Default::default(), 0))
+}
+
+/// The axis along which a range of vertices is split.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Axis {
+    X,
+    Y,
+}
+
+impl Axis {
+    fn other(self) -> Self {
+        match self {
+            Axis::X => Axis::Y,
+            Axis::Y => Axis::X,
+        }
+    }
+
+    /// Compares two positions lexicographically along this axis.
+    ///
+    /// `Axis::Y` compares positions as if they were rotated by 90 degrees clockwise. The result
+    /// of all geometric predicates is invariant under this rotation.
+    fn compare<S: SpadeNum>(self, l: Point2<S>, r: Point2<S>) -> Ordering {
+        // All coordinates have been validated. Comparing them can't fail.
+        let compare = |l: S, r: S| l.partial_cmp(&r).unwrap();
+        match self {
+            Axis::X => compare(l.x, r.x).then_with(|| compare(l.y, r.y)),
+            Axis::Y => compare(l.y, r.y).then_with(|| compare(r.x, l.x)),
+        }
+    }
+}
+
+/// Reorders the vertices into the order in which they are split during triangulation.
+///
+/// Vertices must not contain duplicates.
+fn partition<V>(elements: &mut [V], axis: Axis)
+where
+    V: HasPosition + Send,
+{
+    let compare = |l: &V, r: &V| axis.compare(l.position(), r.position());
+    if elements.len() <= 3 {
+        elements.sort_unstable_by(compare);
+        return;
+    }
+
+    let mid = elements.len() / 2;
+    elements.select_nth_unstable_by(mid, compare);
+    let (left, right) = elements.split_at_mut(mid);
+    if left.len() < MIN_PARALLEL_SIZE {
+        partition(left, axis.other());
+        partition(right, axis.other());
+    } else {
+        rayon::join(
+            || partition(left, axis.other()),
+            || partition(right, axis.other()),
+        );
+    }
+}
+
+fn triangulate_parallel<S>(
+    positions: &[Point2<S>],
+    from: usize,
+    to: usize,
+    axis: Axis,
+) -> (Edges, u32)
+where
+    S: SpadeNum + Send + Sync,
+{
+    if to - from < MIN_PARALLEL_SIZE {
+        let mut edges = Edges::with_capacity(to - from);
+        let hull_edge = edges.triangulate(positions, from, to, axis);
+        return (edges, hull_edge);
+    }
+
+    let mid = from + (to - from) / 2;
+    let ((mut edges, left_hull_edge), (right_edges, right_hull_edge)) = rayon::join(
+        || triangulate_parallel(positions, from, mid, axis.other()),
+        || triangulate_parallel(positions, mid, to, axis.other()),
+    );
+
+    let offset = edges.append(right_edges);
+    let hull_edge = edges.merge(positions, axis, left_hull_edge, right_hull_edge + offset);
+    (edges, hull_edge)
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct HalfEdge {
+    origin: u32,
+    /// The next edge counterclockwise around `origin`.
+    onext: u32,
+    /// The next edge clockwise around `origin`.
+    oprev: u32,
+}
+
+/// Stores all edges created during the divide and conquer algorithm.
+///
+/// The half edges `e` and `e ^ 1` always belong to the same undirected edge. Unlike a `Dcel`,
+/// this structure only stores the edge order around each vertex and no faces.
+#[derive(Clone, Debug, Default)]
+struct Edges {
+    half_edges: Vec<HalfEdge>,
+    /// Deleted edges whose slots can be reused by `make_edge`.
+    free_edges: Vec<u32>,
+}
+
+impl Edges {
+    fn with_capacity(num_vertices: usize) -> Self {
+        Self {
+            half_edges: Vec::with_capacity(num_vertices * 6),
+            free_edges: Vec::new(),
+        }
+    }
+
+    fn append(&mut self, other: Edges) -> u32 {
+        let offset = self.half_edges.len() as u32;
+        self.half_edges
+            .extend(other.half_edges.into_iter().map(|edge| HalfEdge {
+                origin: edge.origin,
+                onext: edge.onext + offset,
+                oprev: edge.oprev + offset,
+            }));
+        self.free_edges
+            .extend(other.free_edges.into_iter().map(|edge| edge + offset));
+        offset
+    }
+
+    fn sym(edge: u32) -> u32 {
+        edge ^ 1
+    }
+
+    fn org(&self, edge: u32) -> u32 {
+        self.half_edges[edge as usize].origin
+    }
+
+    fn dest(&self, edge: u32) -> u32 {
+        self.org(Self::sym(edge))
+    }
+
+    fn onext(&self, edge: u32) -> u32 {
+        self.half_edges[edge as usize].onext
+    }
+
+    fn oprev(&self, edge: u32) -> u32 {
+        self.half_edges[edge as usize].oprev
+    }
+
+    /// Returns the next edge counterclockwise around the left face.
+    fn lnext(&self, edge: u32) -> u32 {
+        self.oprev(Self::sym(edge))
+    }
+
+    /// Returns the previous edge counterclockwise around the left face.
+    fn lprev(&self, edge: u32) -> u32 {
+        Self::sym(self.onext(edge))
+    }
+
+    /// Returns the previous edge counterclockwise around the right face.
+    fn rprev(&self, edge: u32) -> u32 {
+        self.onext(Self::sym(edge))
+    }
+
+    fn make_edge(&mut self, from: u32, to: u32) -> u32 {
+        let edge = match self.free_edges.pop() {
+            Some(edge) => edge,
+            None => {
+                self.half_edges
+                    .resize(self.half_edges.len() + 2, HalfEdge::default());
+                self.half_edges.len() as u32 - 2
+            }
+        };
+
+        self.half_edges[edge as usize] = HalfEdge {
+            origin: from,
+            onext: edge,
+            oprev: edge,
+        };
+        self.half_edges[edge as usize + 1] = HalfEdge {
+            origin: to,
+            onext: edge + 1,
+            oprev: edge + 1,
+        };
+        edge
+    }
+
+    /// Exchanges the edge rings around the origins of `a` and `b`.
+    ///
+    /// This either joins two separate rings or splits a single ring into two.
+    fn splice(&mut self, a: u32, b: u32) {
+        let alpha = self.onext(a);
+        let beta = self.onext(b);
+        self.half_edges[a as usize].onext = beta;
+        self.half_edges[b as usize].onext = alpha;
+        self.half_edges[alpha as usize].oprev = b;
+        self.half_edges[beta as usize].oprev = a;
+    }
+
+    /// Adds a new edge from the destination of `a` to the origin of `b`.
+    ///
+    /// The new edge will have the same left face as `a` and `b`.
+    fn connect(&mut self, a: u32, b: u32) -> u32 {
+        let edge = self.make_edge(self.dest(a), self.org(b));
+        self.splice(edge, self.lnext(a));
+        self.splice(Self::sym(edge), b);
+        edge
+    }
+
+    fn delete(&mut self, edge: u32) {
+        let sym = Self::sym(edge);
+        self.splice(edge, self.oprev(edge));
+        self.splice(sym, self.oprev(sym));
+        self.half_edges[edge as usize].origin = DELETED;
+        self.half_edges[sym as usize].origin = DELETED;
+        self.free_edges.push(edge.min(sym));
+    }
+
+    fn position<S: SpadeNum>(&self, positions: &[Point2<S>], vertex: u32) -> Point2<S> {
+        positions[vertex as usize]
+    }
+
+    fn is_left_of<S: SpadeNum>(&self, positions: &[Point2<S>], vertex: u32, edge: u32) -> bool {
+        math::side_query(
+            self.position(positions, self.org(edge)),
+            self.position(positions, self.dest(edge)),
+            self.position(positions, vertex),
+        )
+        .is_on_left_side()
+    }
+
+    fn is_right_of<S: SpadeNum>(&self, positions: &[Point2<S>], vertex: u32, edge: u32) -> bool {
+        math::side_query(
+            self.position(positions, self.org(edge)),
+            self.position(positions, self.dest(edge)),
+            self.position(positions, vertex),
+        )
+        .is_on_right_side()
+    }
+
+    /// Returns `true` if `d` lies inside the circumcircle of the counterclockwise triangle
+    /// `a, b, c`.
+    fn in_circle<S: SpadeNum>(positions: &[Point2<S>], [a, b, c, d]: [u32; 4]) -> bool {
+        let [a, b, c, d] = [a, b, c, d].map(|vertex| positions[vertex as usize]);
+        math::contained_in_circumference(a, b, c, d)
+    }
+
+    /// Triangulates the vertices `from..to`. At least two vertices are required.
+    ///
+    /// The vertices must have been reordered by `partition`. Returns a clockwise convex hull edge,
+    /// i.e. an edge whose left face is the outer face.
+    fn triangulate<S: SpadeNum>(
+        &mut self,
+        positions: &[Point2<S>],
+        from: usize,
+        to: usize,
+        axis: Axis,
+    ) -> u32 {
+        let [s0, s1, s2] = [from, from + 1, from + 2].map(|vertex| vertex as u32);
+        match to - from {
+            2 => Self::sym(self.make_edge(s0, s1)),
+            3 => {
+                // The vertices are sorted along the axis. s1 lies between s0 and s2 if all
+                // vertices are collinear.
+                let a = self.make_edge(s0, s1);
+                let b = self.make_edge(s1, s2);
+                self.splice(Self::sym(a), b);
+
+                let side =
+                    math::side_query(positions[from], positions[from + 1], positions[from + 2]);
+                if side.is_on_left_side() {
+                    self.connect(b, a);
+                    Self::sym(b)
+                } else if side.is_on_right_side() {
+                    self.connect(b, a)
+                } else {
+                    Self::sym(b)
+                }
+            }
+            _ => {
+                let mid = from + (to - from) / 2;
+                let left_hull_edge = self.triangulate(positions, from, mid, axis.other());
+                let right_hull_edge = self.triangulate(positions, mid, to, axis.other());
+                self.merge(positions, axis, left_hull_edge, right_hull_edge)
+            }
+        }
+    }
+
+    /// Walks along the convex hull and returns its counterclockwise edge out of the smallest
+    /// vertex and its clockwise edge out of the largest vertex along the given axis.
+    ///
+    /// `hull_edge` can be any clockwise convex hull edge.
+    fn extreme_hull_edges<S: SpadeNum>(
+        &self,
+        positions: &[Point2<S>],
+        axis: Axis,
+        hull_edge: u32,
+    ) -> [u32; 2] {
+        let position = |vertex: u32| positions[vertex as usize];
+
+        let mut min_edge = Self::sym(hull_edge);
+        let mut max_edge = hull_edge;
+        let mut current = self.lnext(hull_edge);
+        while current != hull_edge {
+            if axis
+                .compare(position(self.dest(current)), position(self.org(min_edge)))
+                .is_lt()
+            {
+                min_edge = Self::sym(current);
+            }
+            if axis
+                .compare(position(self.org(current)), position(self.org(max_edge)))
+                .is_gt()
+            {
+                max_edge = current;
+            }
+            current = self.lnext(current);
+        }
+        [min_edge, max_edge]
+    }
+
+    /// Merges two triangulations that are separated along the given axis.
+    ///
+    /// All vertices of the left triangulation must be smaller than the vertices of the right
+    /// triangulation. Both triangulations are given by one of their clockwise convex hull edges.
+    /// Returns a clockwise convex hull edge of the merged triangulation.
+    fn merge<S: SpadeNum>(
+        &mut self,
+        positions: &[Point2<S>],
+        axis: Axis,
+        left_hull_edge: u32,
+        right_hull_edge: u32,
+    ) -> u32 {
+        // The variable names follow the paper: "ldi" is the left triangulation's clockwise hull
+        // edge out of its rightmost vertex, "rdi" the right triangulation's counterclockwise hull
+        // edge out of its leftmost vertex.
+        let [_, mut ldi] = self.extreme_hull_edges(positions, axis, left_hull_edge);
+        let [mut rdi, _] = self.extreme_hull_edges(positions, axis, right_hull_edge);
+
+        // Find the lower common tangent of both halves
+        loop {
+            if self.is_left_of(positions, self.org(rdi), ldi) {
+                ldi = self.lnext(ldi);
+            } else if self.is_right_of(positions, self.org(ldi), rdi) {
+                rdi = self.rprev(rdi);
+            } else {
+                break;
+            }
+        }
+
+        let mut base = self.connect(Self::sym(rdi), ldi);
+
+        // Zip both halves together, beginning at the lower common tangent
+        loop {
+            let is_valid = |edges: &Self, candidate: u32| {
+                edges.is_right_of(positions, edges.dest(candidate), base)
+            };
+
+            let mut left_candidate = self.onext(Self::sym(base));
+            if is_valid(self, left_candidate) {
+                while Self::in_circle(
+                    positions,
+                    [
+                        self.dest(base),
+                        self.org(base),
+                        self.dest(left_candidate),
+                        self.dest(self.onext(left_candidate)),
+                    ],
+                ) {
+                    let next = self.onext(left_candidate);
+                    self.delete(left_candidate);
+                    left_candidate = next;
+                }
+            }
+
+            let mut right_candidate = self.oprev(base);
+            if is_valid(self, right_candidate) {
+                while Self::in_circle(
+                    positions,
+                    [
+                        self.dest(base),
+                        self.org(base),
+                        self.dest(right_candidate),
+                        self.dest(self.oprev(right_candidate)),
+                    ],
+                ) {
+                    let next = self.oprev(right_candidate);
+                    self.delete(right_candidate);
+                    right_candidate = next;
+                }
+            }
+
+            let is_left_valid = is_valid(self, left_candidate);
+            let is_right_valid = is_valid(self, right_candidate);
+            if !is_left_valid && !is_right_valid {
+                // base is the upper common tangent
+                break;
+            }
+
+            let use_right = !is_left_valid
+                || (is_right_valid
+                    && Self::in_circle(
+                        positions,
+                        [
+                            self.dest(left_candidate),
+                            self.org(left_candidate),
+                            self.org(right_candidate),
+                            self.dest(right_candidate),
+                        ],
+                    ));
+
+            base = if use_right {
+                self.connect(right_candidate, Self::sym(base))
+            } else {
+                self.connect(Self::sym(base), Self::sym(left_candidate))
+            };
+        }
+
+        // base has become the upper common tangent, pointing from right to left
+        Self::sym(base)
+    }
+
+    /// Returns the edge with the smallest index of the inner face left of `edge`.
+    ///
+    /// Returns `None` if `edge` belongs to the outer face. All loops of three edges in
+    /// counterclockwise order are inner faces.
+    fn inner_face_representative<S: SpadeNum>(
+        &self,
+        positions: &[Point2<S>],
+        edge: u32,
+    ) -> Option<u32> {
+        let next = self.lnext(edge);
+        let prev = self.lnext(next);
+        if self.lnext(prev) != edge {
+            return None;
+        }
+        let [v0, v1, v2] = [edge, next, prev].map(|e| positions[self.org(e) as usize]);
+        math::side_query(v0, v1, v2)
+            .is_on_left_side()
+            .then(|| edge.min(next).min(prev))
+    }
+
+    fn into_dcel<V, DE, UE, F>(
+        self,
+        vertices: Vec<V>,
+        positions: &[Point2<V::Scalar>],
+    ) -> Dcel<V, DE, UE, F>
+    where
+        V: HasPosition,
+        V::Scalar: Send + Sync,
+        DE: Default,
+        UE: Default,
+        F: Default,
+    {
+        let num_half_edges = self.half_edges.len() as u32;
+        let is_live = |edge: u32| self.org(edge) != DELETED;
+
+        // Assigns the final index to each remaining undirected edge
+        let mut undirected_indices = vec![DELETED; self.half_edges.len() / 2];
+        let mut num_undirected_edges = 0;
+        for (index, undirected_index) in undirected_indices.iter_mut().enumerate() {
+            if is_live(index as u32 * 2) {
+                *undirected_index = num_undirected_edges;
+                num_undirected_edges += 1;
+            }
+        }
+
+        let directed_handle = |edge: u32| {
+            let undirected = undirected_indices[(edge >> 1) as usize] as usize;
+            FixedDirectedEdgeHandle::new(undirected * 2 + (edge & 1) as usize)
+        };
+
+        // Assigns the final index to each inner face
+        let is_representative: Vec<_> = (0..num_half_edges)
+            .into_par_iter()
+            .map(|edge| {
+                is_live(edge) && self.inner_face_representative(positions, edge) == Some(edge)
+            })
+            .collect();
+
+        let mut dcel = dcel_operations::new();
+        let mut face_indices = vec![0u32; self.half_edges.len()];
+        for (edge, is_representative) in is_representative.into_iter().enumerate() {
+            if is_representative {
+                face_indices[edge] = dcel.faces.len() as u32;
+                dcel.faces.push(FaceEntry {
+                    adjacent_edge: Some(directed_handle(edge as u32)),
+                    data: F::default(),
+                });
+            }
+        }
+
+        let outer_edge = (0..num_half_edges)
+            .find(|edge| {
+                is_live(*edge) && self.inner_face_representative(positions, *edge).is_none()
+            })
+            .expect("Triangulation without outer edge");
+        dcel.faces[dcel_operations::OUTER_FACE_HANDLE.index()].adjacent_edge =
+            Some(directed_handle(outer_edge));
+
+        let half_edge_entry = |edge: u32| HalfEdgeEntry {
+            next: directed_handle(self.lnext(edge)),
+            prev: directed_handle(self.lprev(edge)),
+            face: FixedFaceHandle::new(
+                self.inner_face_representative(positions, edge)
+                    .map_or(0, |representative| {
+                        face_indices[representative as usize] as usize
+                    }),
+            ),
+            origin: FixedVertexHandle::new(self.org(edge) as usize),
+        };
+
+        let half_edge_entries: Vec<_> = (0..num_half_edges / 2)
+            .into_par_iter()
+            .map(|undirected| undirected * 2)
+            .filter(|edge| is_live(*edge))
+            .map(|edge| [half_edge_entry(edge), half_edge_entry(Self::sym(edge))])
+            .collect();
+
+        dcel.edges = half_edge_entries
+            .into_iter()
+            .map(|[normalized, not_normalized]| EdgeEntry::new(normalized, not_normalized))
+            .collect();
+
+        let mut out_edges = vec![None; vertices.len()];
+        for edge in (0..num_half_edges).filter(|edge| is_live(*edge)) {
+            out_edges[self.org(edge) as usize].get_or_insert(directed_handle(edge));
+        }
+
+        dcel.vertices = vertices
+            .into_iter()
+            .zip(out_edges)
+            .map(|(data, out_edge)| VertexEntry { data, out_edge })
+            .collect();
+        dcel
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{partition, triangulate_parallel, Axis, DELETED, MAX_VERTICES, MIN_PARALLEL_SIZE};
+    use crate::test_utilities::{random_points_with_seed, SEED, SEED2};
+    use crate::{DelaunayTriangulation, InsertionError, Point2, Triangulation, TriangulationExt};
+
+    use alloc::{vec, vec::Vec};
+
+    fn sorted_edges(triangulation: &DelaunayTriangulation<Point2<f64>>) -> Vec<[Point2<f64>; 2]> {
+        let mut edges: Vec<_> = triangulation
+            .undirected_edges()
+            .map(|edge| {
+                let [from, to] = edge.positions();
+                if from < to {
+                    [from, to]
+                } else {
+                    [to, from]
+                }
+            })
+            .collect();
+        edges.sort_by(|l, r| l.partial_cmp(r).unwrap());
+        edges
+    }
+
+    fn check_against_bulk_load(vertices: Vec<Point2<f64>>) -> Result<(), InsertionError> {
+        let expected = DelaunayTriangulation::<_>::bulk_load(vertices.clone())?;
+        let triangulation = DelaunayTriangulation::<_>::bulk_load_parallel(vertices)?;
+        triangulation.sanity_check();
+        assert_eq!(sorted_edges(&triangulation), sorted_edges(&expected));
+        assert_eq!(triangulation.num_inner_faces(), expected.num_inner_faces());
+        Ok(())
+    }
+
+    #[test]
+    fn test_small_inputs() -> Result<(), InsertionError> {
+        for size in 0..20 {
+            check_against_bulk_load(random_points_with_seed(size, SEED))?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_collinear_vertices() -> Result<(), InsertionError> {
+        let vertices = (0..10)
+            .map(|i| Point2::new(i as f64, 2.0 * i as f64))
+            .collect();
+        check_against_bulk_load(vertices)
+    }
+
+    #[test]
+    fn test_duplicates() -> Result<(), InsertionError> {
+        let mut vertices = random_points_with_seed(100, SEED);
+        vertices.extend_from_within(10..50);
+        let triangulation = DelaunayTriangulation::<_>::bulk_load_parallel(vertices)?;
+        triangulation.sanity_check();
+        assert_eq!(triangulation.num_vertices(), 100);
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_vertex() {
+        let vertices = vec![Point2::new(0.0, 0.0), Point2::new(f64::NAN, 1.0)];
+        let result = DelaunayTriangulation::<_>::bulk_load_parallel(vertices);
+        assert_eq!(result.err(), Some(InsertionError::NAN));
+    }
+
+    #[test]
+    fn test_reuse_deleted_edges() {
+        let positions = random_points_with_seed(MIN_PARALLEL_SIZE * 3, SEED);
+        let mut elements = positions.clone();
+        partition(&mut elements, Axis::X);
+        let (edges, _) = triangulate_parallel(&elements, 0, elements.len(), Axis::X);
+
+        // Required for the edge indices to fit into an u32, see MAX_VERTICES
+        assert!(edges.half_edges.len() <= elements.len() * 6);
+        let num_deleted = (0..edges.half_edges.len() as u32)
+            .filter(|edge| edges.org(*edge) == DELETED)
+            .count();
+        assert_eq!(num_deleted, edges.free_edges.len() * 2);
+        for edge in &edges.free_edges {
+            assert_eq!(edges.org(*edge), DELETED);
+            assert_eq!(edges.org(*edge + 1), DELETED);
+        }
+        assert!(MAX_VERTICES * 6 <= DELETED as usize);
+    }
+
+    #[test]
+    fn test_parallel_merge() -> Result<(), InsertionError> {
+        check_against_bulk_load(random_points_with_seed(MIN_PARALLEL_SIZE * 3, SEED2))
+    }
+
+    #[test]
+    fn test_parallel_merge_on_grid() -> Result<(), InsertionError> {
+        // Contains many cocircular and collinear vertices. The Delaunay triangulation isn't unique.
+        let vertices: Vec<_> = (0..MIN_PARALLEL_SIZE * 3)
+            .map(|index| Point2::new((index % 200) as f64, (index / 200) as f64))
+            .collect();
+        let expected = DelaunayTriangulation::<_>::bulk_load(vertices.clone())?;
+        let triangulation = DelaunayTriangulation::<_>::bulk_load_parallel(vertices)?;
+        triangulation.sanity_check();
+        assert_eq!(triangulation.num_vertices(), expected.num_vertices());
+        assert_eq!(triangulation.num_inner_faces(), expected.num_inner_faces());
+        Ok(())
+    }
+}
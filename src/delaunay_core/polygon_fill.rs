@@ -0,0 +1,469 @@
+#[cfg(not(feature = "std"))]
+use hashbrown::HashMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use alloc::vec::Vec;
+
+use num_traits::Float;
+
+use crate::cdt::ConstraintChains;
+use crate::flood_fill_iterator::{flood_fill_faces, FloodFillStep};
+use crate::{
+    CdtEdge, ConstrainedDelaunayTriangulation, HasPosition, HintGenerator, InsertionError, Point2,
+    Triangulation,
+};
+
+use super::refinement::calculate_outer_faces;
+use super::{
+    FaceHandle, FixedDirectedEdgeHandle, FixedFaceHandle, FixedUndirectedEdgeHandle,
+    FixedVertexHandle, InnerTag, PossiblyOuterTag,
+};
+
+/// Determines which areas are considered to be inside of a set of (possibly overlapping) polygons.
+///
+/// Polygon rings contribute to the _winding number_ of any point they enclose. Outer rings count
+/// as `+1` and holes as `-1`, regardless of the direction in which their vertices are specified.
+///
+/// *See [ConstrainedDelaunayTriangulation::from_polygons]*
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FillRule {
+    /// A point is inside if it is enclosed by an odd number of rings.
+    ///
+    /// This ignores whether a ring is an outer ring or a hole. Overlapping outer rings cancel out
+    /// each other.
+    #[default]
+    EvenOdd,
+    /// A point is inside if its winding number is not zero.
+    ///
+    /// Overlapping outer rings will be merged. A hole only removes area if it is not covered by
+    /// more outer rings than holes.
+    NonZero,
+}
+
+impl FillRule {
+    pub(crate) fn is_inside(self, winding_number: i32) -> bool {
+        match self {
+            FillRule::EvenOdd => winding_number % 2 != 0,
+            FillRule::NonZero => winding_number != 0,
+        }
+    }
+}
+
+impl<V, DE, UE, F, L> ConstrainedDelaunayTriangulation<V, DE, UE, F, L>
+where
+    V: HasPosition,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+{
+    /// Returns all inner faces that are enclosed by an odd number of closed constraint edge loops.
+    ///
+    /// For triangulations created with [ConstrainedDelaunayTriangulation::from_polygons], these
+    /// are exactly the faces covered by the input polygons.
+    ///
+    /// This method runs in `O(n)` for `n` faces.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, Triangulation};
+    ///
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::new();
+    /// cdt.add_constraint_edges(
+    ///     [
+    ///         Point2::new(0.0, 0.0),
+    ///         Point2::new(4.0, 0.0),
+    ///         Point2::new(4.0, 4.0),
+    ///         Point2::new(0.0, 4.0),
+    ///     ],
+    ///     true,
+    /// )?;
+    /// // Vertices outside of the loop
+    /// cdt.insert(Point2::new(6.0, 2.0))?;
+    /// cdt.insert(Point2::new(-3.0, -1.0))?;
+    ///
+    /// let area: f64 = cdt.interior_faces().map(|face| face.area()).sum();
+    /// assert_eq!(area, 16.0);
+    /// # Ok(()) }
+    /// ```
+    pub fn interior_faces(
+        &self,
+    ) -> impl Iterator<Item = FaceHandle<'_, InnerTag, V, DE, CdtEdge<UE>, F>> {
+        let outer_faces = calculate_outer_faces(self);
+        self.inner_faces()
+            .filter(move |face| !outer_faces.contains(&face.fix()))
+    }
+
+    /// Returns the constraint edges connecting two vertices that were connected by a constraint
+    /// edge before it got split by other constraints.
+    pub(crate) fn constraint_path(
+        &self,
+        from: FixedVertexHandle,
+        to: FixedVertexHandle,
+    ) -> Vec<FixedDirectedEdgeHandle> {
+        if let Some(edge) = self.get_edge_from_neighbors(from, to) {
+            return alloc::vec![edge.fix()];
+        }
+
+        // Split vertices may not lie exactly on the original line. Follow the constraint edge
+        // that points most directly toward the target instead.
+        let target = self.vertex(to).position().to_f64();
+        let mut result = Vec::new();
+        let mut current = self.vertex(from);
+        while current.fix() != to && result.len() < self.num_vertices() {
+            let origin = current.position().to_f64();
+            let direction = target.sub(origin);
+            let next = current
+                .out_edges()
+                .filter(|edge| edge.is_constraint_edge())
+                .map(|edge| {
+                    let edge_direction = edge.to().position().to_f64().sub(origin);
+                    let cos = edge_direction.dot(direction) / edge_direction.length2().sqrt();
+                    (edge, cos)
+                })
+                .max_by(|(_, cos0), (_, cos1)| cos0.total_cmp(cos1))
+                .map(|(edge, _)| edge);
+
+            let Some(next) = next else {
+                break;
+            };
+            result.push(next.fix());
+            current = next.to();
+        }
+        debug_assert_eq!(
+            current.fix(),
+            to,
+            "Constraint path does not reach its end vertex"
+        );
+        result
+    }
+}
+
+impl<V, DE, UE, F, L> ConstrainedDelaunayTriangulation<V, DE, UE, F, L>
+where
+    V: HasPosition + From<Point2<<V as HasPosition>::Scalar>>,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+    <V as HasPosition>::Scalar: Float,
+{
+    /// Creates a triangulation of polygons with holes.
+    ///
+    /// Every ring is inserted as a closed loop of constraint edges. The last vertex of a ring
+    /// is connected to its first vertex. Rings may be specified in clockwise or counterclockwise
+    /// order and may intersect each other - intersecting constraint edges are split with a
+    /// vertex created by `V::from`.
+    ///
+    /// Which areas are covered is determined by the given [FillRule]. Afterwards, only constraint
+    /// edges that separate covered from uncovered areas are kept. Use
+    /// [interior_faces](ConstrainedDelaunayTriangulation::interior_faces) to iterate over all
+    /// covered faces.
+    ///
+    /// Returns an error if any vertex has an invalid position.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{ConstrainedDelaunayTriangulation, FillRule, Point2, Triangulation};
+    ///
+    /// let outer_ring = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(10.0, 0.0),
+    ///     Point2::new(10.0, 10.0),
+    ///     Point2::new(0.0, 10.0),
+    /// ];
+    /// // A courtyard
+    /// let hole = vec![
+    ///     Point2::new(4.0, 4.0),
+    ///     Point2::new(6.0, 4.0),
+    ///     Point2::new(6.0, 6.0),
+    ///     Point2::new(4.0, 6.0),
+    /// ];
+    ///
+    /// let cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::from_polygons(
+    ///     vec![outer_ring],
+    ///     vec![hole],
+    ///     FillRule::NonZero,
+    /// )?;
+    ///
+    /// let area: f64 = cdt.interior_faces().map(|face| face.area()).sum();
+    /// assert_eq!(area, 96.0);
+    /// # Ok(()) }
+    /// ```
+    pub fn from_polygons(
+        outer_rings: Vec<Vec<V>>,
+        holes: Vec<Vec<V>>,
+        fill_rule: FillRule,
+    ) -> Result<Self, InsertionError> {
+        let mut result = Self::new();
+
+        let rings = outer_rings
+            .into_iter()
+            .map(|ring| (ring, [1]))
+            .chain(holes.into_iter().map(|ring| (ring, [-1])));
+        let winding_numbers = result.insert_rings(rings)?;
+
+        result.remove_non_separating_constraints(|face| {
+            fill_rule.is_inside(winding_numbers[face.index()][0])
+        });
+
+        Ok(result)
+    }
+
+    /// Inserts rings as closed loops of constraint edges and returns the winding numbers of
+    /// all faces, indexed by face index.
+    ///
+    /// Each ring is given together with its contribution to `N` independent winding numbers
+    /// (e.g. `+1` for outer rings and `-1` for holes).
+    pub(crate) fn insert_rings<const N: usize>(
+        &mut self,
+        rings: impl IntoIterator<Item = (Vec<V>, [i32; N])>,
+    ) -> Result<Vec<[i32; N]>, InsertionError> {
+        // All vertices are inserted first. Inserting a vertex onto a constraint edge splits it,
+        // which would not be reflected by the chains below.
+        let mut inserted_rings = Vec::new();
+        for (ring, winding) in rings {
+            let counterclockwise = signed_area(&ring) >= 0.0;
+            let mut handles = Vec::with_capacity(ring.len());
+            for vertex in ring {
+                handles.push(self.insert(vertex)?);
+            }
+            if !counterclockwise {
+                handles.reverse();
+            }
+            inserted_rings.push((handles, winding));
+        }
+
+        // Every ring edge is inserted as a chain of constraint edges. The chain can later be split
+        // further by intersections with other rings. The winding number is only calculated after
+        // all rings have been inserted.
+        let mut chains = ConstraintChains::new();
+        let mut windings = Vec::new();
+        for (handles, winding) in inserted_rings {
+            for (index, from) in handles.iter().copied().enumerate() {
+                let to = handles[(index + 1) % handles.len()];
+                self.add_constraint_and_split_into_chains(&mut chains, from, to, V::from);
+                windings.push(winding);
+            }
+        }
+
+        // Stores the change of the winding number when crossing an undirected edge from the right
+        // to the left side of its normalized directed edge.
+        let mut winding_changes = HashMap::<FixedUndirectedEdgeHandle, [i32; N]>::new();
+        for (chain, winding) in chains.into_edges(self).zip(windings) {
+            for edge in chain {
+                let change = winding_changes
+                    .entry(edge.as_undirected())
+                    .or_insert([0; N]);
+                for (change, winding) in change.iter_mut().zip(winding) {
+                    if edge.is_normalized() {
+                        *change += winding;
+                    } else {
+                        *change -= winding;
+                    }
+                }
+            }
+        }
+
+        let winding_change = |edge: FixedDirectedEdgeHandle| {
+            let change = winding_changes
+                .get(&edge.as_undirected())
+                .copied()
+                .unwrap_or([0; N]);
+            if edge.is_normalized() {
+                change
+            } else {
+                change.map(|change| -change)
+            }
+        };
+
+        // Flood fill all faces, starting at the outer face. Crossing `edge` from its right to its
+        // left side changes the winding number.
+        let winding_numbers = flood_fill_faces(
+            self,
+            [(self.outer_face().fix(), [0; N])],
+            |edge, winding_number| {
+                let mut next = winding_number;
+                for (next, change) in next.iter_mut().zip(winding_change(edge.fix())) {
+                    *next += change;
+                }
+                Some(FloodFillStep::Immediate(next))
+            },
+        );
+
+        Ok(winding_numbers
+            .into_iter()
+            .map(|winding_number| winding_number.unwrap_or([0; N]))
+            .collect())
+    }
+
+    /// Removes all constraint edges that don't separate an inside face from an outside face.
+    pub(crate) fn remove_non_separating_constraints(
+        &mut self,
+        is_inside: impl Fn(FixedFaceHandle<PossiblyOuterTag>) -> bool,
+    ) {
+        let non_separating_edges = self
+            .undirected_edges()
+            .filter(|edge| edge.is_constraint_edge())
+            .filter(|edge| {
+                let edge = edge.as_directed();
+                is_inside(edge.face().fix()) == is_inside(edge.rev().face().fix())
+            })
+            .map(|edge| edge.fix())
+            .collect::<Vec<_>>();
+
+        for edge in non_separating_edges {
+            self.remove_constraint_edge(edge);
+        }
+    }
+}
+
+pub(super) fn signed_area<V: HasPosition>(ring: &[V]) -> f64 {
+    let mut result = 0.0;
+    for (index, vertex) in ring.iter().enumerate() {
+        let p0 = vertex.position().to_f64();
+        let p1 = ring[(index + 1) % ring.len()].position().to_f64();
+        result += p0.x * p1.y - p1.x * p0.y;
+    }
+    result * 0.5
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::{vec, vec::Vec};
+    use approx::assert_abs_diff_eq;
+
+    use crate::test_utilities::square;
+    use crate::{
+        ConstrainedDelaunayTriangulation, FillRule, InsertionError, Point2, Triangulation,
+    };
+
+    type Cdt = ConstrainedDelaunayTriangulation<Point2<f64>>;
+
+    fn interior_area(cdt: &Cdt) -> f64 {
+        cdt.interior_faces().map(|face| face.area()).sum()
+    }
+
+    #[test]
+    fn test_polygon_with_holes() -> Result<(), InsertionError> {
+        let mut hole = square(1.0, 2.0);
+        // Holes may be specified in any orientation
+        hole.reverse();
+        let holes = vec![hole, square(3.0, 5.0)];
+
+        for fill_rule in [FillRule::EvenOdd, FillRule::NonZero] {
+            let cdt = Cdt::from_polygons(vec![square(0.0, 6.0)], holes.clone(), fill_rule)?;
+            cdt.cdt_sanity_check();
+            assert_eq!(interior_area(&cdt), 36.0 - 1.0 - 4.0);
+            assert_eq!(cdt.num_constraints(), 12);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlapping_rings() -> Result<(), InsertionError> {
+        let rings = vec![square(0.0, 2.0), square(1.0, 3.0)];
+
+        let even_odd = Cdt::from_polygons(rings.clone(), Vec::new(), FillRule::EvenOdd)?;
+        even_odd.cdt_sanity_check();
+        assert_eq!(interior_area(&even_odd), 6.0);
+        // Both rings are kept including both intersection vertices
+        assert_eq!(even_odd.num_vertices(), 10);
+        assert_eq!(even_odd.num_constraints(), 12);
+
+        let non_zero = Cdt::from_polygons(rings, Vec::new(), FillRule::NonZero)?;
+        non_zero.cdt_sanity_check();
+        assert_eq!(interior_area(&non_zero), 7.0);
+        // The edges within the overlap do not separate inside from outside
+        assert_eq!(non_zero.num_constraints(), 8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_outer_rings() -> Result<(), InsertionError> {
+        let outer_rings = vec![square(0.0, 10.0), square(2.0, 8.0), square(4.0, 6.0)];
+
+        let even_odd = Cdt::from_polygons(outer_rings.clone(), Vec::new(), FillRule::EvenOdd)?;
+        assert_eq!(interior_area(&even_odd), 100.0 - 36.0 + 4.0);
+
+        let non_zero = Cdt::from_polygons(outer_rings, Vec::new(), FillRule::NonZero)?;
+        assert_eq!(interior_area(&non_zero), 100.0);
+        assert_eq!(non_zero.num_constraints(), 4);
+
+        // A hole that is covered by two outer rings does not remove any area
+        let non_zero = Cdt::from_polygons(
+            vec![square(0.0, 10.0), square(2.0, 8.0)],
+            vec![square(4.0, 6.0)],
+            FillRule::NonZero,
+        )?;
+        assert_eq!(interior_area(&non_zero), 100.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_shared_edges() -> Result<(), InsertionError> {
+        // Two adjacent parcels sharing an edge
+        let left = square(0.0, 1.0);
+        let right = vec![
+            Point2::new(1.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 1.0),
+            Point2::new(1.0, 1.0),
+        ];
+
+        for fill_rule in [FillRule::EvenOdd, FillRule::NonZero] {
+            let cdt = Cdt::from_polygons(vec![left.clone(), right.clone()], Vec::new(), fill_rule)?;
+            cdt.cdt_sanity_check();
+            assert_eq!(interior_area(&cdt), 2.0);
+            assert_eq!(cdt.num_constraints(), 6);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_self_intersecting_ring() -> Result<(), InsertionError> {
+        // A pentagram
+        let ring = (0..5)
+            .map(|index| {
+                let angle =
+                    core::f64::consts::FRAC_PI_2 + index as f64 * 4.0 * core::f64::consts::PI / 5.0;
+                Point2::new(angle.cos(), angle.sin())
+            })
+            .collect::<Vec<_>>();
+
+        let inner_radius =
+            36f64.to_radians().cos() - 36f64.to_radians().sin() * 36f64.to_radians().tan();
+        let pentagon_area = 2.5 * inner_radius * inner_radius * 72f64.to_radians().sin();
+        let star_area = 5.0 * inner_radius * 36f64.to_radians().sin();
+
+        let even_odd = Cdt::from_polygons(vec![ring.clone()], Vec::new(), FillRule::EvenOdd)?;
+        even_odd.cdt_sanity_check();
+        assert_eq!(even_odd.num_vertices(), 10);
+        assert_abs_diff_eq!(
+            interior_area(&even_odd),
+            star_area - pentagon_area,
+            epsilon = 1e-10
+        );
+
+        let non_zero = Cdt::from_polygons(vec![ring], Vec::new(), FillRule::NonZero)?;
+        non_zero.cdt_sanity_check();
+        assert_abs_diff_eq!(interior_area(&non_zero), star_area, epsilon = 1e-10);
+        assert_eq!(non_zero.num_constraints(), 10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_degenerate_rings() -> Result<(), InsertionError> {
+        let cdt = Cdt::from_polygons(Vec::new(), Vec::new(), FillRule::EvenOdd)?;
+        assert_eq!(cdt.interior_faces().count(), 0);
+
+        let line = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)];
+        let cdt = Cdt::from_polygons(vec![line], Vec::new(), FillRule::NonZero)?;
+        assert_eq!(cdt.interior_faces().count(), 0);
+        assert_eq!(cdt.num_constraints(), 0);
+        Ok(())
+    }
+}
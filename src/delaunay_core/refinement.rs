@@ -4,10 +4,12 @@ use hashbrown::{HashMap, HashSet};
 use std::collections::{HashMap, HashSet};
 
 use alloc::collections::VecDeque;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 
-use num_traits::Float;
+use num_traits::{Float, One, Zero};
 
+use crate::flood_fill_iterator::{flood_fill_faces, FloodFillStep};
 use crate::{
     delaunay_core::math, CdtEdge, ConstrainedDelaunayTriangulation, HasPosition, HintGenerator,
     Point2, PositionInTriangulation, SpadeNum, Triangulation,
@@ -25,9 +27,9 @@ use super::{
 pub struct RefinementResult {
     /// A `Vec` containing all outer faces that were excluded from refinement.
     ///
-    /// This `Vec` will be empty unless [RefinementParameters::exclude_outer_faces] has been set.
-    /// In this case, the `Vec` contains all finite outer faces, including any additional outer faces
-    /// that were created during the refinement.
+    /// This `Vec` will be empty unless [RefinementParameters::exclude_outer_faces] or a refinement region
+    /// (see [RefinementParameters::restrict_to_faces]) has been set. In this case, the `Vec` contains all
+    /// excluded faces, including any additional excluded faces that were created during the refinement.
     pub excluded_faces: Vec<FixedFaceHandle<InnerTag>>,
 
     /// Set to `true` if the refinement could be completed regularly.
@@ -117,6 +119,20 @@ impl AngleLimit {
             radius_to_shortest_edge_limit: ratio,
         }
     }
+
+    /// Returns the distance of an off-center to the midpoint of a face's shortest edge, relative to that edge's
+    /// length. Returns `None` if no angle limit is applied.
+    ///
+    /// A point at this distance forms an isosceles triangle with the shortest edge whose apex angle is slightly
+    /// larger than the angle limit. The factor 0.475 (instead of 0.5) is taken from Shewchuk's Triangle.
+    fn off_center_distance(&self) -> Option<f64> {
+        let sin = 0.5 / self.radius_to_shortest_edge_limit;
+        if sin <= 0.0 || sin > 1.0 {
+            return None;
+        }
+        let cos = (1.0 - sin * sin).sqrt();
+        Some(0.475 * (1.0 + cos) / sin)
+    }
 }
 
 impl alloc::fmt::Debug for AngleLimit {
@@ -145,6 +161,76 @@ enum RefinementHint {
     MustRefine,
 }
 
+/// Describes where a steiner point is inserted during refinement.
+///
+/// The contained weights are the barycentric coordinates of the steiner point relative to the
+/// face's or edge's vertices. They sum up to 1.
+///
+/// *See [ConstrainedDelaunayTriangulation::refine_with_vertex_constructor]*
+pub enum SteinerVertexLocation<'a, V, DE, UE, F>
+where
+    V: HasPosition,
+{
+    /// The steiner point is inserted into a face.
+    OnFace {
+        /// The face that contains the steiner point.
+        face: FaceHandle<'a, InnerTag, V, DE, UE, F>,
+        /// The weights of the face's vertices, in the order returned by
+        /// [FaceHandle::vertices].
+        weights: [V::Scalar; 3],
+    },
+    /// The steiner point splits an edge.
+    OnEdge {
+        /// The edge that is split.
+        edge: DirectedEdgeHandle<'a, V, DE, UE, F>,
+        /// The weights of the edge's `from` and `to` vertex.
+        weights: [V::Scalar; 2],
+    },
+}
+
+impl<'a, V, DE, UE, F> SteinerVertexLocation<'a, V, DE, UE, F>
+where
+    V: HasPosition,
+    V::Scalar: Float,
+{
+    fn on_face(face: FaceHandle<'a, InnerTag, V, DE, UE, F>, position: Point2<V::Scalar>) -> Self {
+        let weights = face.barycentric_interpolation(position);
+        SteinerVertexLocation::OnFace { face, weights }
+    }
+
+    fn on_edge(edge: DirectedEdgeHandle<'a, V, DE, UE, F>, position: Point2<V::Scalar>) -> Self {
+        let [from, to] = edge.positions();
+        let direction = to.sub(from);
+        let weight = position.sub(from).dot(direction) / direction.length2();
+        SteinerVertexLocation::OnEdge {
+            edge,
+            weights: [V::Scalar::one() - weight, weight],
+        }
+    }
+
+    /// Returns the weighted sum of a value over all vertices of the face or edge.
+    ///
+    /// This linearly interpolates the value at the steiner point's position.
+    pub fn interpolate(&self, value: impl Fn(&V) -> V::Scalar) -> V::Scalar {
+        match self {
+            SteinerVertexLocation::OnFace { face, weights } => face
+                .vertices()
+                .iter()
+                .zip(weights)
+                .fold(V::Scalar::zero(), |sum, (vertex, weight)| {
+                    sum + value(vertex.data()) * *weight
+                }),
+            SteinerVertexLocation::OnEdge { edge, weights } => edge
+                .vertices()
+                .iter()
+                .zip(weights)
+                .fold(V::Scalar::zero(), |sum, (vertex, weight)| {
+                    sum + value(vertex.data()) * *weight
+                }),
+        }
+    }
+}
+
 /// Controls how Delaunay refinement is performed.
 ///
 /// Refer to [ConstrainedDelaunayTriangulation::refine] and methods implemented by this type for more details
@@ -173,8 +259,165 @@ pub struct RefinementParameters<S: SpadeNum + Float> {
     angle_limit: AngleLimit,
     min_area: Option<S>,
     max_area: Option<S>,
+    max_edge_length: Option<S>,
+    size_function: Option<SizeFunction<S>>,
+    size_gradation: Option<S>,
     keep_constraint_edges: bool,
     exclude_outer_faces: bool,
+    region: Option<RefinementRegion<S>>,
+    split_convex_hull: bool,
+    use_off_centers: bool,
+}
+
+/// Selects the faces that are refined, see [RefinementParameters::restrict_to_faces],
+/// [RefinementParameters::restrict_to_faces_where] and [RefinementParameters::restrict_to_polygon].
+#[derive(Debug, PartialEq, Clone)]
+enum RefinementRegion<S> {
+    Faces(HashSet<FixedFaceHandle<InnerTag>>),
+    Polygon(Vec<Point2<S>>),
+}
+
+impl<S: SpadeNum + Float> RefinementRegion<S> {
+    /// Returns the boundary of the region.
+    fn boundary<V, DE, UE, F, L>(
+        &self,
+        cdt: &ConstrainedDelaunayTriangulation<V, DE, UE, F, L>,
+    ) -> RegionBoundary<S>
+    where
+        V: HasPosition<Scalar = S>,
+        DE: Default,
+        UE: Default,
+        F: Default,
+        L: HintGenerator<S>,
+    {
+        let segments = match self {
+            RefinementRegion::Faces(faces) => {
+                let is_selected = |edge: DirectedEdgeHandle<V, DE, CdtEdge<UE>, F>| {
+                    edge.face()
+                        .as_inner()
+                        .is_some_and(|face| faces.contains(&face.fix()))
+                };
+                cdt.directed_edges()
+                    .filter(|edge| is_selected(*edge) && !is_selected(edge.rev()))
+                    .map(|edge| edge.positions())
+                    .collect()
+            }
+            RefinementRegion::Polygon(polygon) => polygon
+                .iter()
+                .enumerate()
+                .map(|(index, from)| [*from, polygon[(index + 1) % polygon.len()]])
+                .collect(),
+        };
+        RegionBoundary::new(segments)
+    }
+}
+
+/// The boundary segments of a refinement region, sorted into horizontal bands.
+///
+/// Point queries only need to look at the segments of a single band instead of the whole
+/// boundary. The band height is chosen such that every segment is stored in about two bands on
+/// average.
+#[derive(Debug)]
+struct RegionBoundary<S> {
+    min_y: S,
+    max_y: S,
+    band_height: S,
+    bands: Vec<Vec<[Point2<S>; 2]>>,
+}
+
+impl<S: SpadeNum + Float> RegionBoundary<S> {
+    fn new(segments: Vec<[Point2<S>; 2]>) -> Self {
+        let mut min_y = S::infinity();
+        let mut max_y = S::neg_infinity();
+        for [p0, p1] in &segments {
+            min_y = min_y.min(p0.y.min(p1.y));
+            max_y = max_y.max(p0.y.max(p1.y));
+        }
+
+        let height = max_y - min_y;
+        let total_segment_height = segments
+            .iter()
+            .fold(S::zero(), |sum, [p0, p1]| sum + (p1.y - p0.y).abs());
+
+        // Long segments are stored in many bands. Use fewer bands if the segments span the
+        // region's height many times.
+        let num_bands = if height > S::zero() {
+            let coverage = (total_segment_height / height).max(S::one());
+            let num_segments: S = num_traits::NumCast::from(segments.len()).unwrap();
+            (num_segments / coverage)
+                .ceil()
+                .to_usize()
+                .unwrap_or(1)
+                .clamp(1, segments.len())
+        } else {
+            1
+        };
+        let band_height = height / num_traits::NumCast::from(num_bands).unwrap();
+
+        let mut result = Self {
+            min_y,
+            max_y,
+            band_height,
+            bands: Vec::new(),
+        };
+        result.bands.resize(num_bands, Vec::new());
+        for segment in segments {
+            let [p0, p1] = segment;
+            let first = result.band_index(p0.y.min(p1.y));
+            let last = result.band_index(p0.y.max(p1.y));
+            for band in &mut result.bands[first..=last] {
+                band.push(segment);
+            }
+        }
+        result
+    }
+
+    fn band_index(&self, y: S) -> usize {
+        if self.band_height > S::zero() {
+            ((y - self.min_y) / self.band_height)
+                .to_usize()
+                .unwrap_or(0)
+                .min(self.bands.len() - 1)
+        } else {
+            0
+        }
+    }
+
+    /// Returns `true` if a point is enclosed by an odd number of boundary segments.
+    fn contains(&self, point: Point2<S>) -> bool {
+        if self.bands.is_empty() || !(self.min_y..=self.max_y).contains(&point.y) {
+            return false;
+        }
+
+        // Count the segments that cross a ray from the point towards positive x. Any such segment
+        // spans `point.y` and is thus stored in the point's band.
+        let mut is_inside = false;
+        for [p0, p1] in &self.bands[self.band_index(point.y)] {
+            if (p0.y > point.y) != (p1.y > point.y) {
+                let x = p0.x + (point.y - p0.y) / (p1.y - p0.y) * (p1.x - p0.x);
+                if point.x < x {
+                    is_inside = !is_inside;
+                }
+            }
+        }
+        is_inside
+    }
+}
+
+/// A spatially varying target edge length, see [RefinementParameters::with_size_function].
+#[derive(Clone)]
+struct SizeFunction<S>(Arc<dyn Fn(Point2<S>) -> S + Send + Sync>);
+
+impl<S> core::fmt::Debug for SizeFunction<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("SizeFunction")
+    }
+}
+
+impl<S> PartialEq for SizeFunction<S> {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
 }
 
 impl<S: SpadeNum + Float> Default for RefinementParameters<S> {
@@ -184,8 +427,14 @@ impl<S: SpadeNum + Float> Default for RefinementParameters<S> {
             angle_limit: AngleLimit::from_radius_to_shortest_edge_ratio(1.0),
             min_area: None,
             max_area: None,
+            max_edge_length: None,
+            size_function: None,
+            size_gradation: None,
             exclude_outer_faces: false,
             keep_constraint_edges: false,
+            region: None,
+            split_convex_hull: true,
+            use_off_centers: false,
         }
     }
 }
@@ -195,9 +444,12 @@ impl<S: SpadeNum + Float> RefinementParameters<S> {
     ///
     /// The following values will be used by `new` and `Self::default`:
     /// * `exclude_outer_faces`: disabled - all faces are used for refinement
+    /// * `restrict_to_faces` and `restrict_to_polygon`: disabled - all faces are used for refinement
     /// * `keep_constraint_edges`: disabled
     /// * `min_required_area`: disabled - no lower area limit is used
     /// * `max_allowed_area`: disabled - no upper area limit is used
+    /// * `max_allowed_edge_length`: disabled - no upper edge length limit is used
+    /// * `size_function`: disabled - no spatially varying edge length limit is used
     /// * `angle_limit`: 30 degrees by default.
     /// * `num_additional_vertices`: 10 times the number of vertices in the triangulation
     pub fn new() -> Self {
@@ -229,6 +481,22 @@ impl<S: SpadeNum + Float> RefinementParameters<S> {
         self
     }
 
+    /// Inserts off-centers instead of circumcenters to resolve skinny triangles.
+    ///
+    /// The off-center of a triangle lies on the perpendicular bisector of its shortest edge, between the edge and
+    /// the circumcenter. It is chosen such that the new triangle formed with the shortest edge just satisfies the
+    /// [AngleLimit]. The circumcenter is used if it is closer to the shortest edge than the off-center.
+    ///
+    /// The same angle limit is achieved but the resulting mesh usually requires noticeably fewer vertices, especially
+    /// around small features. See Üngör, Alper (2004). "Off-centers: A new type of Steiner points for computing
+    /// size-optimal quality-guaranteed Delaunay triangulations" for more details.
+    ///
+    /// Defaults to `false`.
+    pub fn use_off_centers(mut self, use_off_centers: bool) -> Self {
+        self.use_off_centers = use_off_centers;
+        self
+    }
+
     /// Specifies a lower bound for a triangles area.
     ///
     /// The algorithm will attempt to ignore any triangle with an area below this limit. This can also prevent an
@@ -265,6 +533,78 @@ impl<S: SpadeNum + Float> RefinementParameters<S> {
         self
     }
 
+    /// Specifies an upper bound for the length of all edges in the triangulation.
+    ///
+    /// In contrast to [Self::with_max_allowed_area], this also prevents long and thin triangles,
+    /// e.g. along the boundary of a shape. Any face with a longer edge will be split. Constraint
+    /// edges and edges of the convex hull that are too long are split as well. This does not apply
+    /// to edges that are only adjacent to [excluded faces](Self::exclude_outer_faces) and to
+    /// constraint edges if [Self::keep_constraint_edges] is used. In the latter case, faces
+    /// adjacent to long constraint edges may also keep edges above the limit.
+    ///
+    /// Note that long edges are split in halves. The resulting edges can be considerably shorter
+    /// than this bound.
+    pub fn with_max_allowed_edge_length(mut self, max_edge_length: S) -> Self {
+        self.max_edge_length = Some(max_edge_length);
+        self
+    }
+
+    /// Specifies a spatially varying upper bound for edge lengths.
+    ///
+    /// The size function returns the desired maximum edge length at any given position. A face
+    /// will be split if its longest edge is longer than the size at the face's center. This allows
+    /// to create fine meshes close to features of interest and coarse meshes elsewhere.
+    ///
+    /// For a desired maximum triangle area `a`, use the edge length of an equilateral triangle
+    /// with that area, `(4.0 * a / 3.0.sqrt()).sqrt()`.
+    ///
+    /// The size function is only evaluated at a finite set of positions. Small features of the
+    /// size function may be missed if the triangulation is very coarse. Use
+    /// [with_size_gradation](Self::with_size_gradation) to make sure that edge lengths change
+    /// smoothly. Non-positive sizes will be refined until the
+    /// [maximum number of additional vertices](Self::with_max_additional_vertices) is reached.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, RefinementParameters, Triangulation};
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::new();
+    /// cdt.add_constraint_edges(
+    ///     [(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)].map(|(x, y)| Point2::new(x, y)),
+    ///     true,
+    /// )?;
+    ///
+    /// // Small faces close to the line y = 0, larger faces elsewhere
+    /// let params = RefinementParameters::new()
+    ///     .with_size_function(|p: Point2<f64>| 0.5 + p.y)
+    ///     .with_size_gradation(0.5)
+    ///     .with_max_additional_vertices(5000);
+    /// let result = cdt.refine(params);
+    /// assert!(result.refinement_complete);
+    /// # Ok(()) }
+    /// ```
+    pub fn with_size_function<SF>(mut self, size_function: SF) -> Self
+    where
+        SF: Fn(Point2<S>) -> S + Send + Sync + 'static,
+    {
+        self.size_function = Some(SizeFunction(Arc::new(size_function)));
+        self
+    }
+
+    /// Limits how quickly the edge length given by [Self::with_size_function] may grow.
+    ///
+    /// The size at any position is limited to the size at a nearby vertex plus `gradation` times
+    /// their distance. For example, a gradation of `0.3` allows the edge length to grow by `0.3`
+    /// per unit of distance. This prevents abrupt transitions between fine and coarse parts of the
+    /// mesh.
+    ///
+    /// Has no effect unless a size function is specified. If this method is not called, the size
+    /// function is used as is.
+    pub fn with_size_gradation(mut self, gradation: S) -> Self {
+        self.size_gradation = Some(gradation);
+        self
+    }
+
     /// Specifies how many additional vertices may be inserted during Delaunay refinement.
     ///
     /// Refinement may, in some cases, fail to terminate if the angle limit is set too high
@@ -320,9 +660,149 @@ impl<S: SpadeNum + Float> RefinementParameters<S> {
         self
     }
 
+    /// Restricts the refinement to the given faces.
+    ///
+    /// Any other face is excluded from the refinement, similar to
+    /// [exclude_outer_faces](Self::exclude_outer_faces): Excluded faces are never the cause of a
+    /// subdivision. They may still change if a neighboring edge needs to be split or if a new
+    /// vertex requires flipping some of their edges in order to restore the Delaunay property.
+    /// The selection refers to the area covered by the given faces when the refinement starts:
+    /// any face is only refined if its center lies within that area.
+    ///
+    /// This can be combined with [exclude_outer_faces](Self::exclude_outer_faces). Replaces any
+    /// region set by [restrict_to_polygon](Self::restrict_to_polygon) or
+    /// [restrict_to_faces_where](Self::restrict_to_faces_where).
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, RefinementParameters, Triangulation};
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::bulk_load(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(10.0, 0.0),
+    ///     Point2::new(10.0, 10.0),
+    ///     Point2::new(0.0, 10.0),
+    ///     Point2::new(5.0, 5.0),
+    /// ])?;
+    ///
+    /// // Select faces with an arbitrary predicate
+    /// let selected_faces = cdt
+    ///     .inner_faces()
+    ///     .filter(|face| face.center().x < 5.0)
+    ///     .map(|face| face.fix());
+    ///
+    /// let params = RefinementParameters::new()
+    ///     .restrict_to_faces(selected_faces)
+    ///     .with_max_allowed_area(1.0);
+    /// let result = cdt.refine(params);
+    ///
+    /// // Faces in the right half are left untouched
+    /// assert!(cdt.inner_faces().any(|face| face.area() > 1.0));
+    /// assert!(!result.excluded_faces.is_empty());
+    /// # Ok(()) }
+    /// ```
+    pub fn restrict_to_faces(
+        mut self,
+        faces: impl IntoIterator<Item = FixedFaceHandle<InnerTag>>,
+    ) -> Self {
+        self.region = Some(RefinementRegion::Faces(faces.into_iter().collect()));
+        self
+    }
+
+    /// Restricts the refinement to all inner faces of `cdt` that fulfill a predicate.
+    ///
+    /// The predicate is evaluated once for every inner face of `cdt` when this method is called.
+    /// The selected faces are treated like the faces passed to
+    /// [restrict_to_faces](Self::restrict_to_faces). Faces created during the refinement are
+    /// refined if their center lies within the selected area.
+    ///
+    /// Replaces any region set by [restrict_to_faces](Self::restrict_to_faces) or
+    /// [restrict_to_polygon](Self::restrict_to_polygon).
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, RefinementParameters, Triangulation};
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::bulk_load(vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(10.0, 0.0),
+    ///     Point2::new(10.0, 10.0),
+    ///     Point2::new(0.0, 10.0),
+    ///     Point2::new(5.0, 5.0),
+    /// ])?;
+    ///
+    /// // Only refine the lower triangle
+    /// let params = RefinementParameters::new()
+    ///     .restrict_to_faces_where(&cdt, |face| face.center().y < 5.0)
+    ///     .with_max_allowed_area(1.0);
+    /// let result = cdt.refine(params);
+    ///
+    /// assert!(cdt.num_vertices() > 5);
+    /// assert!(cdt.inner_faces().any(|face| face.area() > 1.0));
+    /// assert!(!result.excluded_faces.is_empty());
+    /// # Ok(()) }
+    /// ```
+    pub fn restrict_to_faces_where<V, DE, UE, F, L>(
+        mut self,
+        cdt: &ConstrainedDelaunayTriangulation<V, DE, UE, F, L>,
+        mut predicate: impl FnMut(FaceHandle<InnerTag, V, DE, CdtEdge<UE>, F>) -> bool,
+    ) -> Self
+    where
+        V: HasPosition<Scalar = S>,
+        DE: Default,
+        UE: Default,
+        F: Default,
+        L: HintGenerator<S>,
+    {
+        let faces = cdt
+            .inner_faces()
+            .filter(|face| predicate(*face))
+            .map(|face| face.fix())
+            .collect();
+        self.region = Some(RefinementRegion::Faces(faces));
+        self
+    }
+
+    /// Restricts the refinement to faces whose center lies within the given polygon.
+    ///
+    /// The last vertex of the polygon is connected to its first vertex. Self-intersecting polygons
+    /// are evaluated with the even-odd rule.
+    ///
+    /// Replaces any region set by [restrict_to_faces](Self::restrict_to_faces) or
+    /// [restrict_to_faces_where](Self::restrict_to_faces_where). See there for how faces outside
+    /// of the region are treated.
+    pub fn restrict_to_polygon(mut self, polygon: Vec<Point2<S>>) -> Self {
+        self.region = Some(RefinementRegion::Polygon(polygon));
+        self
+    }
+
+    fn is_encroachment_candidate<V, DE, UE, F>(
+        &self,
+        edge: UndirectedEdgeHandle<V, DE, CdtEdge<UE>, F>,
+    ) -> bool {
+        if self.keep_constraint_edges {
+            edge.is_part_of_convex_hull()
+        } else if self.split_convex_hull {
+            edge.is_constraint_edge() || edge.is_part_of_convex_hull()
+        } else {
+            edge.is_constraint_edge()
+        }
+    }
+
+    fn is_too_long<V, DE, UE, F>(&self, edge: UndirectedEdgeHandle<V, DE, CdtEdge<UE>, F>) -> bool
+    where
+        V: HasPosition<Scalar = S>,
+    {
+        self.is_encroachment_candidate(edge)
+            && self
+                .max_edge_length
+                .is_some_and(|max_edge_length| edge.length_2() > max_edge_length * max_edge_length)
+    }
+
     fn get_refinement_hint<V, DE, UE, F>(
         &self,
         face: FaceHandle<InnerTag, V, DE, UE, F>,
+        max_edge_length: Option<S>,
     ) -> RefinementHint
     where
         V: HasPosition<Scalar = S>,
@@ -333,6 +813,22 @@ impl<S: SpadeNum + Float> RefinementParameters<S> {
             }
         }
 
+        if self.max_edge_length.is_some() || max_edge_length.is_some() {
+            let longest_edge = face
+                .adjacent_edges()
+                .map(|edge| edge.length_2())
+                .into_iter()
+                .fold(S::zero(), |a, b| a.max(b));
+            for max_edge_length in [self.max_edge_length, max_edge_length]
+                .into_iter()
+                .flatten()
+            {
+                if longest_edge > max_edge_length * max_edge_length {
+                    return RefinementHint::MustRefine;
+                }
+            }
+        }
+
         if let Some(min_area) = self.min_area {
             if face.area() < min_area {
                 return RefinementHint::Ignore;
@@ -460,6 +956,76 @@ where
     #[doc(alias = "Refinement")]
     #[doc(alias = "Delaunay Refinement")]
     pub fn refine(&mut self, parameters: RefinementParameters<V::Scalar>) -> RefinementResult {
+        self.refine_with_vertex_constructor(parameters, |position, _| position.into())
+    }
+}
+
+impl<V, DE, UE, F, L> ConstrainedDelaunayTriangulation<V, DE, UE, F, L>
+where
+    V: HasPosition,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+    <V as HasPosition>::Scalar: Float,
+{
+    /// Refines a triangulation and creates all new vertices with a custom constructor.
+    ///
+    /// This works like [refine](Self::refine) but doesn't require the vertex type to implement
+    /// `From<Point2<...>>`. Instead, every steiner point is created by calling `vertex_constructor`
+    /// with the steiner point's position and a [SteinerVertexLocation]. The location contains the
+    /// face or edge on which the vertex will be inserted along with the barycentric weights of its
+    /// position. This allows to interpolate any vertex data (e.g. an elevation) from the
+    /// surrounding vertices.
+    ///
+    /// The constructed vertex must be placed at the given position.
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{ConstrainedDelaunayTriangulation, HasPosition, Point2, RefinementParameters, Triangulation};
+    ///
+    /// struct TinVertex {
+    ///     position: Point2<f64>,
+    ///     height: f64,
+    /// }
+    ///
+    /// impl HasPosition for TinVertex {
+    ///     type Scalar = f64;
+    ///
+    ///     fn position(&self) -> Point2<f64> {
+    ///         self.position
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<TinVertex>::bulk_load(vec![
+    ///     TinVertex { position: Point2::new(0.0, 0.0), height: 0.0 },
+    ///     TinVertex { position: Point2::new(10.0, 0.0), height: 10.0 },
+    ///     TinVertex { position: Point2::new(10.0, 10.0), height: 20.0 },
+    ///     TinVertex { position: Point2::new(0.0, 10.0), height: 10.0 },
+    /// ])?;
+    ///
+    /// let params = RefinementParameters::new().with_max_allowed_area(1.0);
+    /// cdt.refine_with_vertex_constructor(params, |position, location| TinVertex {
+    ///     position,
+    ///     height: location.interpolate(|vertex| vertex.height),
+    /// });
+    ///
+    /// // The height of every new vertex lies on the plane defined by the initial vertices
+    /// for vertex in cdt.vertices() {
+    ///     let position = vertex.position();
+    ///     assert!((vertex.data().height - position.x - position.y).abs() < 1.0e-10);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    pub fn refine_with_vertex_constructor<C>(
+        &mut self,
+        parameters: RefinementParameters<V::Scalar>,
+        mut vertex_constructor: C,
+    ) -> RefinementResult
+    where
+        C: FnMut(Point2<V::Scalar>, SteinerVertexLocation<V, DE, CdtEdge<UE>, F>) -> V,
+    {
         use PositionInTriangulation::*;
 
         let mut excluded_faces = if parameters.exclude_outer_faces {
@@ -468,6 +1034,23 @@ where
             HashSet::new()
         };
 
+        // Faces may be flipped across the region's boundary during refinement. Thus, any face is
+        // checked against the region's initial shape before it is refined.
+        let region_boundary = parameters
+            .region
+            .as_ref()
+            .map(|region| region.boundary(self));
+        let is_outside_region = |position| {
+            region_boundary
+                .as_ref()
+                .is_some_and(|boundary| !boundary.contains(position))
+        };
+        excluded_faces.extend(
+            self.inner_faces()
+                .filter(|face| is_outside_region(face.center()))
+                .map(|face| face.fix()),
+        );
+
         let mut legalize_edges_buffer = Vec::with_capacity(20);
         let mut forcibly_split_segments_buffer = Vec::with_capacity(5);
 
@@ -482,16 +1065,21 @@ where
 
         encroached_segment_candidates.extend(
             self.undirected_edges()
-                .filter(|edge| {
-                    if parameters.keep_constraint_edges {
-                        edge.is_part_of_convex_hull()
-                    } else {
-                        Self::is_fixed_edge(*edge)
-                    }
-                })
+                .filter(|edge| parameters.is_encroachment_candidate(*edge))
                 .map(|edge| edge.fix()),
         );
 
+        let off_center_distance = parameters
+            .angle_limit
+            .off_center_distance()
+            .filter(|_| parameters.use_off_centers)
+            .map(|distance| V::Scalar::from(distance as f32));
+
+        let mut size_field = parameters
+            .size_function
+            .as_ref()
+            .map(|size_function| SizeField::new(size_function, parameters.size_gradation, self));
+
         // Stores all faces that should be checked for their area and angles ("skinniness").
         let mut skinny_triangle_candidates: VecDeque<_> = self.fixed_inner_faces().collect();
 
@@ -540,11 +1128,13 @@ where
             // Step 1: Check for forcibly split segments.
             if let Some(forcibly_split_segment) = forcibly_split_segments_buffer.pop() {
                 self.resolve_encroachment(
+                    &mut vertex_constructor,
                     &mut encroached_segment_candidates,
                     &mut skinny_triangle_candidates,
                     &mut constraint_edge_map,
                     forcibly_split_segment,
                     &mut excluded_faces,
+                    parameters.split_convex_hull,
                 );
                 continue;
             }
@@ -558,13 +1148,29 @@ where
                     let is_excluded = edge
                         .face()
                         .as_inner()
-                        .map(|face| excluded_faces.contains(&face.fix()))
+                        .map(|face| {
+                            excluded_faces.contains(&face.fix()) || is_outside_region(face.center())
+                        })
                         .unwrap_or(true);
 
                     if is_excluded {
                         continue;
                     }
 
+                    if parameters.is_too_long(edge.as_undirected()) {
+                        // Segments that exceed the maximum edge length are split right away.
+                        self.resolve_encroachment(
+                            &mut vertex_constructor,
+                            &mut encroached_segment_candidates,
+                            &mut skinny_triangle_candidates,
+                            &mut constraint_edge_map,
+                            segment_candidate,
+                            &mut excluded_faces,
+                            parameters.split_convex_hull,
+                        );
+                        break;
+                    }
+
                     if let Some(opposite_position) = edge.opposite_position() {
                         if is_encroaching_edge(
                             edge.from().position(),
@@ -573,11 +1179,13 @@ where
                         ) {
                             // The edge is encroaching
                             self.resolve_encroachment(
+                                &mut vertex_constructor,
                                 &mut encroached_segment_candidates,
                                 &mut skinny_triangle_candidates,
                                 &mut constraint_edge_map,
                                 segment_candidate,
                                 &mut excluded_faces,
+                                parameters.split_convex_hull,
                             );
                         }
                     }
@@ -594,9 +1202,24 @@ where
 
                 let face = self.face(face);
 
+                if is_outside_region(face.center()) {
+                    continue;
+                }
+
                 let (shortest_edge, _) = face.shortest_edge();
 
-                let refinement_hint = parameters.get_refinement_hint(face);
+                let max_edge_length = size_field.as_mut().map(|size_field| {
+                    let size = size_field.face_size(self, face);
+                    for vertex in size_field.reduced_vertices.drain(..) {
+                        skinny_triangle_candidates.extend(
+                            self.vertex(vertex)
+                                .out_edges()
+                                .flat_map(|edge| edge.face().fix().as_inner()),
+                        );
+                    }
+                    size
+                });
+                let refinement_hint = parameters.get_refinement_hint(face, max_edge_length);
 
                 if refinement_hint == RefinementHint::Ignore {
                     // Triangle is fine as is and can be skipped
@@ -630,7 +1253,19 @@ where
                 }
 
                 // Continue to resolve the skinny face
-                let circumcenter = face.circumcenter();
+                let mut circumcenter = face.circumcenter();
+                if let Some(off_center_distance) = off_center_distance {
+                    // Replace the circumcenter by the off-center if it's closer to the shortest edge.
+                    // The off-center lies inside the circumcircle, inserting it will still destroy the face.
+                    let [from, to] = shortest_edge.positions();
+                    let direction = to.sub(from);
+                    let midpoint = from.add(direction.mul(0.5.into()));
+                    let off_center = midpoint
+                        .add(Point2::new(-direction.y, direction.x).mul(off_center_distance));
+                    if off_center.distance_2(midpoint) < circumcenter.distance_2(midpoint) {
+                        circumcenter = off_center;
+                    }
+                }
 
                 let locate_hint = face.vertices()[0].fix();
 
@@ -643,7 +1278,8 @@ where
                 //
                 // First step: fill `legalize_edges_buffer` with the initial set of edges that would need to be legalized
                 // if the triangle's circumcenter would be inserted.
-                match self.locate_with_hint(circumcenter, locate_hint) {
+                let circumcenter_location = self.locate_with_hint(circumcenter, locate_hint);
+                match circumcenter_location {
                     OnEdge(edge) => {
                         let edge = self.directed_edge(edge);
                         if parameters.keep_constraint_edges && edge.is_constraint_edge() {
@@ -665,7 +1301,9 @@ where
                         }
                     }
                     OnFace(face_under_circumcenter) => {
-                        if excluded_faces.contains(&face_under_circumcenter) {
+                        if excluded_faces.contains(&face_under_circumcenter)
+                            || is_outside_region(self.face(face_under_circumcenter).center())
+                        {
                             continue;
                         }
                         legalize_edges_buffer.extend(
@@ -719,8 +1357,18 @@ where
 
                 if !is_encroaching {
                     // The circumcenter doesn't encroach any segment. Continue really inserting it.
+                    let location = match circumcenter_location {
+                        OnEdge(edge) => {
+                            SteinerVertexLocation::on_edge(self.directed_edge(edge), circumcenter)
+                        }
+                        OnFace(face) => {
+                            SteinerVertexLocation::on_face(self.face(face), circumcenter)
+                        }
+                        _ => unreachable!(),
+                    };
+                    let vertex = vertex_constructor(circumcenter, location);
                     let new_vertex = self
-                        .insert_with_hint(circumcenter.into(), locate_hint)
+                        .insert_with_hint(vertex, locate_hint)
                         .expect("Failed to insert circumcenter, likely due to loss of precision. Consider refining with fewer additional vertices.");
 
                     // Add all new and changed faces to the skinny candidate list
@@ -746,18 +1394,67 @@ where
         }
     }
 
+    /// Turns this triangulation into a *conforming* Delaunay triangulation.
+    ///
+    /// Splits constraint edges by inserting additional points until every edge of the
+    /// triangulation, including all constraint edges, fulfills the Delaunay property. Afterwards,
+    /// the triangulation is also a valid (unconstrained) [DelaunayTriangulation] of its vertices.
+    /// All input constraints remain present as chains of constraint edges. Split constraint edges
+    /// keep their [constraint ID](Self::constraint_id).
+    ///
+    /// In contrast to [refine](Self::refine), no angle or area criterion is applied. Only
+    /// constraint edges are split and no point is inserted elsewhere.
+    ///
+    /// Small input angles between constraint edges can require many additional vertices. The
+    /// number of additional vertices is limited to 10 times the number of vertices.
+    /// Use [RefinementResult::refinement_complete] to check if this limit has been reached.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, Triangulation};
+    ///
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>>::new();
+    /// cdt.add_constraint_edge(Point2::new(-4.0, 0.0), Point2::new(4.0, 0.0))?;
+    /// cdt.insert(Point2::new(0.0, 1.0))?;
+    /// cdt.insert(Point2::new(0.0, -1.0))?;
+    ///
+    /// let result = cdt.make_conforming();
+    /// assert!(result.refinement_complete);
+    /// // The constraint edge has been split
+    /// assert!(cdt.num_constraints() > 1);
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [DelaunayTriangulation]: crate::DelaunayTriangulation
+    #[doc(alias = "Conforming Delaunay Triangulation")]
+    pub fn make_conforming(&mut self) -> RefinementResult
+    where
+        V: From<Point2<<V as HasPosition>::Scalar>>,
+    {
+        let mut parameters =
+            RefinementParameters::new().with_angle_limit(AngleLimit::from_deg(0.0));
+        parameters.split_convex_hull = false;
+        self.refine(parameters)
+    }
+
     fn is_fixed_edge(edge: UndirectedEdgeHandle<V, DE, CdtEdge<UE>, F>) -> bool {
         edge.is_constraint_edge() || edge.is_part_of_convex_hull()
     }
 
-    fn resolve_encroachment(
+    #[allow(clippy::too_many_arguments)]
+    fn resolve_encroachment<C>(
         &mut self,
+        vertex_constructor: &mut C,
         encroached_segments_buffer: &mut VecDeque<FixedUndirectedEdgeHandle>,
         encroached_faces_buffer: &mut VecDeque<FixedFaceHandle<InnerTag>>,
         constraint_edge_map: &mut HashMap<FixedVertexHandle, [FixedVertexHandle; 2]>,
         encroached_edge: FixedUndirectedEdgeHandle,
         excluded_faces: &mut HashSet<FixedFaceHandle<InnerTag>>,
-    ) {
+        split_convex_hull: bool,
+    ) where
+        C: FnMut(Point2<V::Scalar>, SteinerVertexLocation<V, DE, CdtEdge<UE>, F>) -> V,
+    {
         // Resolves an encroachment by splitting the encroached edge. Since this reduces the diametral circle, this will
         // eventually get rid of the encroachment completely.
         //
@@ -825,11 +1522,19 @@ where
 
         let is_constraint_edge = segment.is_constraint_edge();
 
+        let vertex = vertex_constructor(
+            final_position,
+            SteinerVertexLocation::OnEdge {
+                edge: segment,
+                weights: [weight0, weight1],
+            },
+        );
+
         // Perform the actual split!
         let segment = segment.fix();
         let (v0, v1) = (v0.fix(), v1.fix());
 
-        let (new_vertex, [e1, e2]) = self.insert_on_edge(segment, final_position.into());
+        let (new_vertex, [e1, e2]) = self.insert_on_edge(segment, vertex);
 
         let original_vertices = v0_constraint_vertex
             .or(v1_constraint_vertex)
@@ -839,6 +1544,7 @@ where
         if is_constraint_edge {
             // Make sure to update the constraint edges count as required.
             self.handle_legal_edge_split([e1, e2]);
+            self.mark_steiner_vertex(new_vertex);
         }
 
         let (h1, h2) = (self.directed_edge(e1), self.directed_edge(e2));
@@ -871,7 +1577,9 @@ where
                 .out_edges()
                 .filter(|edge| !edge.is_outer_edge())
                 .map(|edge| edge.next().as_undirected())
-                .filter(|edge| Self::is_fixed_edge(*edge))
+                .filter(|edge| {
+                    edge.is_constraint_edge() || split_convex_hull && edge.is_part_of_convex_hull()
+                })
                 .map(|edge| edge.fix()),
         );
 
@@ -928,6 +1636,121 @@ where
     true
 }
 
+/// Evaluates the size function of [RefinementParameters], limited by their size gradation.
+struct SizeField<'a, S> {
+    size_function: &'a SizeFunction<S>,
+    gradation: Option<S>,
+    /// Caches the graded size at each vertex.
+    vertex_sizes: HashMap<FixedVertexHandle, S>,
+    /// Vertices whose size has been reduced after it was first evaluated. Their adjacent faces
+    /// need to be checked again.
+    reduced_vertices: Vec<FixedVertexHandle>,
+}
+
+impl<'a, S: SpadeNum + Float> SizeField<'a, S> {
+    fn new<V, DE, UE, F, L>(
+        size_function: &'a SizeFunction<S>,
+        gradation: Option<S>,
+        cdt: &ConstrainedDelaunayTriangulation<V, DE, UE, F, L>,
+    ) -> Self
+    where
+        V: HasPosition<Scalar = S>,
+        DE: Default,
+        UE: Default,
+        F: Default,
+        L: HintGenerator<S>,
+    {
+        let mut result = Self {
+            size_function,
+            gradation,
+            vertex_sizes: HashMap::new(),
+            reduced_vertices: Vec::new(),
+        };
+        // Grade the sizes of all initial vertices before any face is checked.
+        if let Some(gradation) = gradation {
+            for vertex in cdt.fixed_vertices() {
+                result.vertex_size(cdt, vertex, gradation);
+            }
+            result.reduced_vertices.clear();
+        }
+        result
+    }
+
+    /// Returns the maximum edge length for a face.
+    fn face_size<V, DE, UE, F, L>(
+        &mut self,
+        cdt: &ConstrainedDelaunayTriangulation<V, DE, UE, F, L>,
+        face: FaceHandle<InnerTag, V, DE, CdtEdge<UE>, F>,
+    ) -> S
+    where
+        V: HasPosition<Scalar = S>,
+        DE: Default,
+        UE: Default,
+        F: Default,
+        L: HintGenerator<S>,
+    {
+        let center = face.center();
+        let mut size = (self.size_function.0)(center);
+        if let Some(gradation) = self.gradation {
+            for vertex in face.vertices() {
+                let vertex_size = self.vertex_size(cdt, vertex.fix(), gradation);
+                size =
+                    size.min(vertex_size + gradation * vertex.position().distance_2(center).sqrt());
+            }
+        }
+        size
+    }
+
+    /// Returns the graded size at a vertex.
+    ///
+    /// The size is limited by the sizes of all neighbors that have been evaluated before.
+    /// Evaluating a vertex for the first time also limits the size of its evaluated neighbors.
+    fn vertex_size<V, DE, UE, F, L>(
+        &mut self,
+        cdt: &ConstrainedDelaunayTriangulation<V, DE, UE, F, L>,
+        vertex: FixedVertexHandle,
+        gradation: S,
+    ) -> S
+    where
+        V: HasPosition<Scalar = S>,
+        DE: Default,
+        UE: Default,
+        F: Default,
+        L: HintGenerator<S>,
+    {
+        if let Some(size) = self.vertex_sizes.get(&vertex) {
+            return *size;
+        }
+
+        let position = cdt.vertex(vertex).position();
+        let mut size = (self.size_function.0)(position);
+        for edge in cdt.vertex(vertex).out_edges() {
+            if let Some(neighbor_size) = self.vertex_sizes.get(&edge.to().fix()) {
+                size = size.min(*neighbor_size + gradation * edge.length_2().sqrt());
+            }
+        }
+        self.vertex_sizes.insert(vertex, size);
+
+        // Propagate the new size to all evaluated vertices that it limits.
+        let mut todo = alloc::vec![vertex];
+        while let Some(current) = todo.pop() {
+            let current_size = self.vertex_sizes[&current];
+            for edge in cdt.vertex(current).out_edges() {
+                let neighbor = edge.to().fix();
+                let limit = current_size + gradation * edge.length_2().sqrt();
+                if let Some(neighbor_size) = self.vertex_sizes.get_mut(&neighbor) {
+                    if limit < *neighbor_size {
+                        *neighbor_size = limit;
+                        todo.push(neighbor);
+                        self.reduced_vertices.push(neighbor);
+                    }
+                }
+            }
+        }
+        size
+    }
+}
+
 fn is_encroaching_edge<S: SpadeNum + Float>(
     edge_from: Point2<S>,
     edge_to: Point2<S>,
@@ -943,7 +1766,7 @@ fn nearest_power_of_two<S: Float + SpadeNum>(input: S) -> S {
     input.log2().round().exp2()
 }
 
-fn calculate_outer_faces<V: HasPosition, DE: Default, UE: Default, F: Default, L>(
+pub(crate) fn calculate_outer_faces<V: HasPosition, DE: Default, UE: Default, F: Default, L>(
     triangulation: &ConstrainedDelaunayTriangulation<V, DE, UE, F, L>,
 ) -> HashSet<FixedFaceHandle<InnerTag>>
 where
@@ -953,55 +1776,34 @@ where
         return HashSet::new();
     }
 
-    // Determine excluded faces by "peeling of" outer layers and adding them to an outer layer set.
-    // This needs to be done repeatedly to also get inner "holes" within the triangulation
-    let mut inner_faces = HashSet::new();
-    let mut outer_faces = HashSet::new();
-
-    let mut current_todo_list: Vec<_> =
-        triangulation.convex_hull().map(|edge| edge.rev()).collect();
-    let mut next_todo_list = Vec::new();
-
-    let mut return_outer_faces = true;
-
-    loop {
-        // Every iteration of the outer while loop will peel of the outmost layer and pre-populate the
-        // next, inner layer.
-        while let Some(next_edge) = current_todo_list.pop() {
-            let (list, face_set) = if next_edge.is_constraint_edge() {
-                // We're crossing a constraint edge - add that face to the *next* todo list
-                (&mut next_todo_list, &mut inner_faces)
+    // Determine excluded faces by "peeling of" outer layers: Every face is assigned the number of
+    // constraint edges that need to be crossed to reach it from the outside. Faces in even layers are
+    // outer faces. Deferring constraint crossings makes sure that each layer is fully peeled off
+    // before the next layer is entered. This also gets inner "holes" within the triangulation.
+    let layers = flood_fill_faces(
+        triangulation,
+        [(triangulation.outer_face().fix(), 0u32)],
+        |edge, layer| {
+            if edge.is_constraint_edge() {
+                Some(FloodFillStep::Deferred(layer + 1))
             } else {
-                (&mut current_todo_list, &mut outer_faces)
-            };
-
-            if let Some(inner) = next_edge.face().as_inner() {
-                if face_set.insert(inner.fix()) {
-                    list.push(next_edge.prev().rev());
-                    list.push(next_edge.next().rev());
-                }
+                Some(FloodFillStep::Immediate(layer))
             }
-        }
-
-        if next_todo_list.is_empty() {
-            break;
-        }
-        core::mem::swap(&mut inner_faces, &mut outer_faces);
-        core::mem::swap(&mut next_todo_list, &mut current_todo_list);
-
-        return_outer_faces = !return_outer_faces;
-    }
-
-    if return_outer_faces {
-        outer_faces
-    } else {
-        inner_faces
-    }
+        },
+    );
+
+    layers
+        .iter()
+        .enumerate()
+        .skip(1)
+        .filter(|(_, layer)| layer.is_some_and(|layer| layer % 2 == 0))
+        .map(|(index, _)| FixedFaceHandle::new(index))
+        .collect()
 }
 
 #[cfg(test)]
 mod test {
-    use super::HashSet;
+    use super::{HashSet, RefinementRegion, RegionBoundary, Vec};
 
     use crate::{
         test_utilities::{random_points_with_seed, SEED},
@@ -1050,6 +1852,419 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_make_conforming() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::bulk_load(random_points_with_seed(100, SEED))?;
+        // A long constraint edge and a sharp input angle
+        cdt.add_constraint_edge(Point2::new(-0.9, -0.2), Point2::new(0.9, 0.1))?;
+        cdt.add_constraint_edge(Point2::new(-0.5, 0.5), Point2::new(0.5, 0.3))?;
+        cdt.add_constraint_edge(Point2::new(-0.5, 0.5), Point2::new(0.5, 0.4))?;
+
+        let num_vertices = cdt.num_vertices();
+        let result = cdt.make_conforming();
+        assert!(result.refinement_complete);
+        assert!(cdt.num_vertices() > num_vertices);
+        cdt.cdt_sanity_check();
+
+        let dt = crate::DelaunayTriangulation::<Point2<f64>>::bulk_load(
+            cdt.vertices().map(|v| *v.data()).collect(),
+        )?;
+        for edge in cdt
+            .undirected_edges()
+            .filter(|edge| edge.is_constraint_edge())
+        {
+            let [from, to] = edge.positions();
+            let from = dt.locate_vertex(from).unwrap().fix();
+            let to = dt.locate_vertex(to).unwrap().fix();
+            assert!(dt.get_edge_from_neighbors(from, to).is_some());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_conforming_keeps_convex_hull() -> Result<(), InsertionError> {
+        let mut cdt = Cdt::new();
+        // The opposite vertex encroaches the hull edge, but hull edges are always Delaunay.
+        cdt.insert(Point2::new(-4.0, 0.0))?;
+        cdt.insert(Point2::new(4.0, 0.0))?;
+        cdt.insert(Point2::new(0.0, 1.0))?;
+        let num_vertices = cdt.num_vertices();
+
+        cdt.make_conforming();
+        assert_eq!(cdt.num_vertices(), num_vertices);
+        Ok(())
+    }
+
+    fn square_cdt(size: f64) -> Result<Cdt, InsertionError> {
+        let mut cdt = Cdt::new();
+        cdt.add_constraint_edges(
+            [(0.0, 0.0), (size, 0.0), (size, size), (0.0, size)].map(|(x, y)| Point2::new(x, y)),
+            true,
+        )?;
+        Ok(cdt)
+    }
+
+    fn longest_edge<V: crate::HasPosition<Scalar = f64>, DE, UE, F>(
+        face: crate::handles::FaceHandle<crate::handles::InnerTag, V, DE, UE, F>,
+    ) -> f64 {
+        face.adjacent_edges()
+            .map(|edge| edge.length_2().sqrt())
+            .into_iter()
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_max_allowed_edge_length() -> Result<(), InsertionError> {
+        // A long and thin strip
+        let mut cdt = Cdt::new();
+        cdt.add_constraint_edges(
+            [(0.0, 0.0), (20.0, 0.0), (20.0, 0.5), (0.0, 0.5)].map(|(x, y)| Point2::new(x, y)),
+            true,
+        )?;
+        let mut area_limited = cdt.clone();
+        area_limited.refine(RefinementParameters::new().with_max_allowed_area(0.5));
+        assert!(area_limited
+            .undirected_edges()
+            .any(|edge| edge.length_2() > 4.0));
+
+        let result = cdt.refine(
+            RefinementParameters::new()
+                .with_max_allowed_edge_length(1.0)
+                .with_max_additional_vertices(1000)
+                .exclude_outer_faces(true),
+        );
+        assert!(result.refinement_complete);
+        cdt.cdt_sanity_check_with_params(false);
+        for edge in cdt.undirected_edges() {
+            assert!(edge.length_2() <= 1.0);
+        }
+        assert!(cdt.num_constraints() >= 2 * 20 + 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_allowed_edge_length_keeps_constraint_edges() -> Result<(), InsertionError> {
+        let mut cdt = square_cdt(10.0)?;
+        // Prevents the constraint edges from being part of the convex hull
+        for (x, y) in [(-5.0, -5.0), (15.0, -5.0), (15.0, 15.0), (-5.0, 15.0)] {
+            cdt.insert(Point2::new(x, y))?;
+        }
+        let result = cdt.refine(
+            RefinementParameters::new()
+                .with_max_allowed_edge_length(2.0)
+                .with_max_additional_vertices(1000)
+                .keep_constraint_edges(),
+        );
+        assert!(result.refinement_complete);
+        cdt.cdt_sanity_check_with_params(false);
+        assert_eq!(cdt.num_constraints(), 4);
+        assert!(cdt.num_vertices() > 50);
+        Ok(())
+    }
+
+    fn grid_cdt() -> Result<Cdt, InsertionError> {
+        let mut vertices = Vec::new();
+        for x in 0..=4 {
+            for y in 0..=4 {
+                vertices.push(Point2::new(x as f64 * 5.0, y as f64 * 5.0));
+            }
+        }
+        Cdt::bulk_load(vertices)
+    }
+
+    #[test]
+    fn test_restrict_to_polygon() -> Result<(), InsertionError> {
+        let mut cdt = grid_cdt()?;
+        let num_initial_vertices = cdt.num_vertices();
+        let polygon = [(0.0, 0.0), (10.0, 0.0), (0.0, 10.0)].map(|(x, y)| Point2::new(x, y));
+        let result = cdt.refine(
+            RefinementParameters::new()
+                .restrict_to_polygon(polygon.to_vec())
+                .with_max_allowed_area(0.5)
+                .with_max_additional_vertices(1000),
+        );
+        assert!(result.refinement_complete);
+        cdt.cdt_sanity_check_with_params(false);
+        assert!(cdt.num_vertices() > num_initial_vertices + 20);
+
+        // New vertices are only inserted within the triangle and its adjacent faces
+        for vertex in cdt.vertices().skip(num_initial_vertices) {
+            let position = vertex.position();
+            assert!(position.x + position.y <= 15.0);
+        }
+        assert!(cdt.inner_faces().filter(|face| face.area() > 0.5).count() > 20);
+        Ok(())
+    }
+
+    #[test]
+    fn test_restrict_to_faces() -> Result<(), InsertionError> {
+        let mut cdt = grid_cdt()?;
+        let selected = cdt
+            .inner_faces()
+            .filter(|face| face.center().x > 15.0)
+            .map(|face| face.fix())
+            .collect::<Vec<_>>();
+        let num_excluded = cdt.num_inner_faces() - selected.len();
+
+        let result = cdt.refine(
+            RefinementParameters::new()
+                .restrict_to_faces(selected)
+                .with_max_allowed_area(1.0)
+                .with_max_additional_vertices(1000),
+        );
+        assert!(result.refinement_complete);
+        cdt.cdt_sanity_check_with_params(false);
+        assert!(result.excluded_faces.len() >= num_excluded);
+        for face in result.excluded_faces {
+            assert!(cdt.face(face).center().x < 16.0);
+        }
+        for face in cdt.inner_faces() {
+            if face.center().x > 16.0 {
+                assert!(face.area() <= 1.0);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_restrict_to_faces_where() -> Result<(), InsertionError> {
+        let mut cdt = grid_cdt()?;
+        let result = cdt.refine(
+            RefinementParameters::new()
+                .restrict_to_faces_where(&cdt, |face| face.center().y < 5.0)
+                .with_max_allowed_area(1.0)
+                .with_max_additional_vertices(1000),
+        );
+        assert!(result.refinement_complete);
+        cdt.cdt_sanity_check_with_params(false);
+        for face in result.excluded_faces {
+            assert!(cdt.face(face).center().y > 4.0);
+        }
+        for face in cdt.inner_faces() {
+            if face.center().y < 4.0 {
+                assert!(face.area() <= 1.0);
+            }
+        }
+        assert!(cdt.inner_faces().any(|face| face.area() > 1.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_region_boundary() {
+        // A comb shaped polygon with long teeth and a short horizontal segment
+        let mut polygon = alloc::vec![Point2::new(0.0, 0.0)];
+        for tooth in 0..10 {
+            let x = tooth as f64 * 2.0;
+            polygon.push(Point2::new(x + 1.0, 0.0));
+            polygon.push(Point2::new(x + 1.0, 20.0));
+            polygon.push(Point2::new(x + 2.0, 20.0));
+            polygon.push(Point2::new(x + 2.0, 0.0));
+        }
+        polygon.push(Point2::new(20.0, -1.0));
+        polygon.push(Point2::new(0.0, -1.0));
+
+        let boundary = RefinementRegion::Polygon(polygon).boundary(&Cdt::new());
+        assert!(boundary.bands.len() > 1);
+        let segments = boundary.bands.iter().flatten().copied().collect::<Vec<_>>();
+
+        let mut num_inside = 0;
+        for x in -2..90 {
+            for y in -4..90 {
+                let point = Point2::new(x as f64 * 0.25 + 0.1, y as f64 * 0.25 + 0.1);
+                let brute_force = RegionBoundary {
+                    min_y: boundary.min_y,
+                    max_y: boundary.max_y,
+                    band_height: 0.0,
+                    bands: alloc::vec![segments.clone()],
+                };
+                let expected = brute_force.contains(point);
+                assert_eq!(boundary.contains(point), expected);
+                if expected {
+                    num_inside += 1;
+                }
+            }
+        }
+        assert!(num_inside > 0);
+
+        let empty = RefinementRegion::Polygon(Vec::new()).boundary(&Cdt::new());
+        assert!(!empty.contains(Point2::new(0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_size_function() -> Result<(), InsertionError> {
+        let size_function = |p: Point2<f64>| 0.5 + p.y;
+        let mut cdt = square_cdt(10.0)?;
+        let result = cdt.refine(
+            RefinementParameters::new()
+                .with_size_function(size_function)
+                .with_max_additional_vertices(10000),
+        );
+        assert!(result.refinement_complete);
+        cdt.cdt_sanity_check_with_params(false);
+
+        let mut fine_faces = Vec::new();
+        let mut coarse_faces = Vec::new();
+        for face in cdt.inner_faces() {
+            assert!(longest_edge(face) <= size_function(face.center()));
+            if face.center().y < 2.0 {
+                fine_faces.push(face.area());
+            } else if face.center().y > 8.0 {
+                coarse_faces.push(face.area());
+            }
+        }
+        let average = |areas: &[f64]| areas.iter().sum::<f64>() / areas.len() as f64;
+        assert!(average(&fine_faces) * 10.0 < average(&coarse_faces));
+        Ok(())
+    }
+
+    #[test]
+    fn test_size_gradation() -> Result<(), InsertionError> {
+        // Only a small spot around the center vertex requires short edges. Without gradation,
+        // the spot is missed as it doesn't contain any face center.
+        let size_function = |p: Point2<f64>| {
+            if p.distance_2(Point2::new(10.0, 10.0)) < 1.0 {
+                0.3
+            } else {
+                100.0
+            }
+        };
+        let refine = |gradation: Option<f64>| -> Result<Cdt, InsertionError> {
+            let mut cdt = square_cdt(20.0)?;
+            cdt.insert(Point2::new(10.0, 10.0))?;
+            let mut params = RefinementParameters::new()
+                .with_size_function(size_function)
+                .with_max_additional_vertices(10000);
+            if let Some(gradation) = gradation {
+                params = params.with_size_gradation(gradation);
+            }
+            assert!(cdt.refine(params).refinement_complete);
+            cdt.cdt_sanity_check_with_params(false);
+            Ok(cdt)
+        };
+
+        let ungraded = refine(None)?;
+        let graded = refine(Some(0.3))?;
+        assert!(graded.num_vertices() > ungraded.num_vertices());
+
+        // Edge lengths grow slowly with the distance to the spot
+        for face in graded.inner_faces() {
+            assert!(longest_edge(face) <= size_function(face.center()));
+            let distance = face.center().distance_2(Point2::new(10.0, 10.0)).sqrt();
+            if distance < 4.0 {
+                assert!(longest_edge(face) < 3.0);
+            }
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct HeightVertex {
+        position: Point2<f64>,
+        height: f64,
+    }
+
+    impl crate::HasPosition for HeightVertex {
+        type Scalar = f64;
+
+        fn position(&self) -> Point2<f64> {
+            self.position
+        }
+    }
+
+    #[test]
+    fn test_refine_with_vertex_constructor() -> Result<(), InsertionError> {
+        let height = |position: Point2<f64>| 2.0 * position.x - 0.5 * position.y + 3.0;
+        let vertex = |x: f64, y: f64| {
+            let position = Point2::new(x, y);
+            HeightVertex {
+                position,
+                height: height(position),
+            }
+        };
+
+        let mut cdt = ConstrainedDelaunayTriangulation::<HeightVertex>::new();
+        cdt.add_constraint_edges(
+            [
+                vertex(0.0, 0.0),
+                vertex(20.0, 0.0),
+                vertex(20.0, 10.0),
+                vertex(0.0, 10.0),
+            ],
+            true,
+        )?;
+        cdt.add_constraint_edges([vertex(2.0, 5.0), vertex(18.0, 6.0)], false)?;
+        let num_initial_vertices = cdt.num_vertices();
+
+        let mut num_edge_splits = 0;
+        let mut num_face_splits = 0;
+        let result = cdt.refine_with_vertex_constructor(
+            RefinementParameters::new()
+                .with_max_allowed_area(2.0)
+                .with_max_additional_vertices(1000),
+            |position, location| {
+                match location {
+                    super::SteinerVertexLocation::OnFace { weights, .. } => {
+                        assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1.0e-10);
+                        num_face_splits += 1;
+                    }
+                    super::SteinerVertexLocation::OnEdge { edge, weights } => {
+                        assert!((weights[0] + weights[1] - 1.0).abs() < 1.0e-10);
+                        assert!(weights.iter().all(|weight| (0.0..=1.0).contains(weight)));
+                        if edge.is_constraint_edge() {
+                            num_edge_splits += 1;
+                        }
+                    }
+                }
+                HeightVertex {
+                    position,
+                    height: location.interpolate(|vertex| vertex.height),
+                }
+            },
+        );
+
+        assert!(result.refinement_complete);
+        assert!(num_edge_splits > 0);
+        assert!(num_face_splits > 0);
+        assert!(cdt.num_vertices() > num_initial_vertices);
+        cdt.cdt_sanity_check_with_params(false);
+
+        for vertex in cdt.vertices() {
+            let data = vertex.data();
+            assert!((data.height - height(data.position)).abs() < 1.0e-8);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_off_centers() -> Result<(), InsertionError> {
+        let refine = |use_off_centers: bool| -> Result<Cdt, InsertionError> {
+            let mut cdt = square_cdt(100.0)?;
+            // A few small features that require a strongly graded mesh
+            cdt.add_constraint_edge(Point2::new(30.0, 30.0), Point2::new(30.5, 30.2))?;
+            cdt.add_constraint_edge(Point2::new(70.0, 40.0), Point2::new(70.1, 40.6))?;
+            cdt.add_constraint_edge(Point2::new(45.0, 80.0), Point2::new(46.0, 80.0))?;
+            cdt.add_constraint_edge(Point2::new(1.0, 1.0), Point2::new(1.3, 1.0))?;
+
+            let params = RefinementParameters::new()
+                .with_max_additional_vertices(10000)
+                .use_off_centers(use_off_centers);
+            assert!(cdt.refine(params).refinement_complete);
+            cdt.cdt_sanity_check_with_params(false);
+            Ok(cdt)
+        };
+
+        let circumcenters = refine(false)?;
+        let off_centers = refine(true)?;
+        assert!(off_centers.num_vertices() < circumcenters.num_vertices());
+
+        for face in off_centers.inner_faces() {
+            let (_, length2) = face.shortest_edge();
+            let (_, radius2) = face.circumcircle();
+            assert!(radius2 / length2 <= 1.0 + 1.0e-10);
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_nearest_power_of_two() {
         use super::nearest_power_of_two;
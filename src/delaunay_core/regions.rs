@@ -0,0 +1,358 @@
+#[cfg(not(feature = "std"))]
+use hashbrown::HashSet;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+use alloc::vec::Vec;
+
+use num_traits::Float;
+
+use crate::cdt::ConstraintChains;
+use crate::flood_fill_iterator::{flood_fill_faces, FloodFillStep};
+use crate::{
+    ConstrainedDelaunayTriangulation, HasPosition, HintGenerator, InsertionError, Point2,
+    PositionInTriangulation, Triangulation,
+};
+
+use super::{FixedFaceHandle, FixedUndirectedEdgeHandle, InnerTag};
+
+/// A face type that stores a region label, e.g. a material ID or a land use class.
+///
+/// Faces need to implement this trait to allow assigning regions with
+/// [ConstrainedDelaunayTriangulation::assign_region] and
+/// [ConstrainedDelaunayTriangulation::add_region].
+///
+/// This is implemented for `Option<R>` which can be used directly as face type.
+pub trait HasRegion {
+    /// The region label type.
+    type Region;
+
+    /// Returns the region of this face or `None` if no region has been assigned.
+    fn region(&self) -> Option<&Self::Region>;
+
+    /// Sets the region of this face.
+    fn set_region(&mut self, region: Self::Region);
+}
+
+impl<R> HasRegion for Option<R> {
+    type Region = R;
+
+    fn region(&self) -> Option<&R> {
+        self.as_ref()
+    }
+
+    fn set_region(&mut self, region: R) {
+        *self = Some(region);
+    }
+}
+
+impl<V, DE, UE, F, L> ConstrainedDelaunayTriangulation<V, DE, UE, F, L>
+where
+    V: HasPosition,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+{
+    /// Returns all faces of the region that contains a seed point.
+    ///
+    /// A region is a set of inner faces that are connected without crossing a constraint edge.
+    ///
+    /// Returns an empty `Vec` if the seed lies outside of the convex hull, on a vertex or on a
+    /// constraint edge.
+    pub fn region_faces(
+        &self,
+        seed: Point2<<V as HasPosition>::Scalar>,
+    ) -> Vec<FixedFaceHandle<InnerTag>> {
+        let start = match self.locate(seed) {
+            PositionInTriangulation::OnFace(face) => face,
+            PositionInTriangulation::OnEdge(edge)
+                if !self.is_constraint_edge(edge.as_undirected()) =>
+            {
+                let edge = self.directed_edge(edge);
+                match edge.face().as_inner().or(edge.rev().face().as_inner()) {
+                    Some(face) => face.fix(),
+                    None => return Vec::new(),
+                }
+            }
+            _ => return Vec::new(),
+        };
+
+        self.connected_faces(alloc::vec![start], |edge| self.is_constraint_edge(edge))
+    }
+
+    /// Returns the region that has been assigned to a face.
+    ///
+    /// *See [ConstrainedDelaunayTriangulation::assign_region]*
+    pub fn face_region(&self, face: FixedFaceHandle<InnerTag>) -> Option<&F::Region>
+    where
+        F: HasRegion,
+    {
+        self.s().face_data(face).region()
+    }
+
+    /// Assigns a region to all faces that are connected to a seed point without crossing a
+    /// constraint edge.
+    ///
+    /// This is equivalent to the _regional attributes_ of Jonathan Shewchuk's `Triangle`.
+    /// Regions are not updated when the triangulation is modified afterwards. Faces created
+    /// later on use their default value.
+    ///
+    /// Returns the number of faces that were assigned. This is `0` if the seed lies outside of
+    /// the convex hull, on a vertex or on a constraint edge.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, Triangulation};
+    ///
+    /// // Faces store an optional land use class
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>, (), (), Option<&str>>::new();
+    /// let v0 = cdt.insert(Point2::new(0.0, 0.0))?;
+    /// cdt.insert(Point2::new(2.0, 0.0))?;
+    /// let v2 = cdt.insert(Point2::new(2.0, 1.0))?;
+    /// cdt.insert(Point2::new(0.0, 1.0))?;
+    /// // Split the rectangle diagonally
+    /// cdt.add_constraint(v0, v2);
+    ///
+    /// cdt.assign_region(Point2::new(1.5, 0.2), "field");
+    /// cdt.assign_region(Point2::new(0.5, 0.8), "forest");
+    ///
+    /// let face = cdt.locate(Point2::new(1.9, 0.1));
+    /// let face = match face {
+    ///     spade::PositionInTriangulation::OnFace(face) => face,
+    ///     _ => unreachable!(),
+    /// };
+    /// assert_eq!(cdt.face_region(face), Some(&"field"));
+    /// # Ok(()) }
+    /// ```
+    pub fn assign_region(
+        &mut self,
+        seed: Point2<<V as HasPosition>::Scalar>,
+        region: F::Region,
+    ) -> usize
+    where
+        F: HasRegion,
+        F::Region: Clone,
+    {
+        let faces = self.region_faces(seed);
+        for face in &faces {
+            self.face_data_mut(*face).set_region(region.clone());
+        }
+        faces.len()
+    }
+
+    /// Returns all inner faces that can be reached from the start faces without crossing a
+    /// barrier edge.
+    fn connected_faces(
+        &self,
+        start: Vec<FixedFaceHandle<InnerTag>>,
+        is_barrier: impl Fn(FixedUndirectedEdgeHandle) -> bool,
+    ) -> Vec<FixedFaceHandle<InnerTag>> {
+        let start = start
+            .into_iter()
+            .map(|face| (face.adjust_inner_outer(), ()));
+        let visited = flood_fill_faces(self, start, |edge, ()| {
+            (!edge.face().is_outer() && !is_barrier(edge.fix().as_undirected()))
+                .then_some(FloodFillStep::Immediate(()))
+        });
+
+        visited
+            .iter()
+            .enumerate()
+            .filter(|(_, visited)| visited.is_some())
+            .map(|(index, _)| FixedFaceHandle::new(index))
+            .collect()
+    }
+}
+
+impl<V, DE, UE, F, L> ConstrainedDelaunayTriangulation<V, DE, UE, F, L>
+where
+    V: HasPosition + From<Point2<<V as HasPosition>::Scalar>>,
+    DE: Default,
+    UE: Default,
+    F: Default + HasRegion,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+    <V as HasPosition>::Scalar: Float,
+{
+    /// Inserts a ring as closed loop of constraint edges and assigns a region to all faces
+    /// enclosed by it.
+    ///
+    /// In contrast to [ConstrainedDelaunayTriangulation::assign_region], the region may contain
+    /// other constraint edges. Any existing constraint edge that intersects the ring is split with
+    /// a vertex created by `V::from`. The ring must not intersect itself. Its vertices may be given
+    /// in clockwise or counterclockwise order.
+    ///
+    /// Returns the number of faces that were assigned or an error if any vertex has an invalid
+    /// position.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{ConstrainedDelaunayTriangulation, Point2, Triangulation};
+    ///
+    /// let mut cdt = ConstrainedDelaunayTriangulation::<Point2<f64>, (), (), Option<u32>>::new();
+    /// cdt.add_constraint_edge(Point2::new(-2.0, 0.0), Point2::new(2.0, 0.0))?;
+    ///
+    /// let ring = vec![
+    ///     Point2::new(-1.0, -1.0),
+    ///     Point2::new(1.0, -1.0),
+    ///     Point2::new(1.0, 1.0),
+    ///     Point2::new(-1.0, 1.0),
+    /// ];
+    /// cdt.add_region(ring, 7)?;
+    ///
+    /// let area: f64 = cdt
+    ///     .inner_faces()
+    ///     .filter(|face| cdt.face_region(face.fix()) == Some(&7))
+    ///     .map(|face| face.area())
+    ///     .sum();
+    /// assert_eq!(area, 4.0);
+    /// # Ok(()) }
+    /// ```
+    pub fn add_region(&mut self, ring: Vec<V>, region: F::Region) -> Result<usize, InsertionError>
+    where
+        F::Region: Clone,
+    {
+        let counterclockwise = super::polygon_fill::signed_area(&ring) >= 0.0;
+        let mut handles = Vec::with_capacity(ring.len());
+        for vertex in ring {
+            handles.push(self.insert(vertex)?);
+        }
+        if !counterclockwise {
+            handles.reverse();
+        }
+
+        // The ring's edges may be split by the ring itself. They are only resolved after the
+        // whole ring has been inserted.
+        let mut chains = ConstraintChains::new();
+        for (index, from) in handles.iter().copied().enumerate() {
+            let to = handles[(index + 1) % handles.len()];
+            self.add_constraint_and_split_into_chains(&mut chains, from, to, V::from);
+        }
+        let ring_edges = chains.into_edges(self).flatten().collect::<Vec<_>>();
+
+        let barrier = ring_edges
+            .iter()
+            .map(|edge| edge.as_undirected())
+            .collect::<HashSet<_>>();
+        let start = ring_edges
+            .iter()
+            .filter_map(|edge| self.directed_edge(*edge).face().as_inner())
+            .map(|face| face.fix())
+            .collect();
+
+        let faces = self.connected_faces(start, |edge| barrier.contains(&edge));
+        for face in &faces {
+            self.face_data_mut(*face).set_region(region.clone());
+        }
+        Ok(faces.len())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec;
+
+    use crate::{
+        ConstrainedDelaunayTriangulation, InsertionError, Point2, PositionInTriangulation,
+        Triangulation,
+    };
+
+    type Cdt = ConstrainedDelaunayTriangulation<Point2<f64>, (), (), Option<u32>>;
+
+    fn region_area(cdt: &Cdt, region: u32) -> f64 {
+        cdt.inner_faces()
+            .filter(|face| cdt.face_region(face.fix()) == Some(&region))
+            .map(|face| face.area())
+            .sum()
+    }
+
+    fn create_split_square() -> Result<Cdt, InsertionError> {
+        let mut cdt = Cdt::new();
+        cdt.add_constraint_edges(
+            [
+                Point2::new(0.0, 0.0),
+                Point2::new(4.0, 0.0),
+                Point2::new(4.0, 4.0),
+                Point2::new(0.0, 4.0),
+            ],
+            true,
+        )?;
+        cdt.add_constraint_edge(Point2::new(2.0, 0.0), Point2::new(2.0, 4.0))?;
+        for vertex in [(-2.0, 2.0), (6.0, 2.0), (1.0, 1.0), (3.0, 3.0)] {
+            cdt.insert(vertex.into())?;
+        }
+        Ok(cdt)
+    }
+
+    #[test]
+    fn test_assign_region() -> Result<(), InsertionError> {
+        let mut cdt = create_split_square()?;
+
+        assert!(cdt.assign_region(Point2::new(0.5, 3.0), 1) > 0);
+        assert!(cdt.assign_region(Point2::new(3.5, 0.5), 2) > 0);
+
+        assert_eq!(region_area(&cdt, 1), 8.0);
+        assert_eq!(region_area(&cdt, 2), 8.0);
+
+        let unassigned = cdt
+            .inner_faces()
+            .filter(|face| cdt.face_region(face.fix()).is_none())
+            .count();
+        assert_eq!(
+            unassigned,
+            cdt.region_faces(Point2::new(-1.9, 2.0)).len()
+                + cdt.region_faces(Point2::new(5.9, 2.0)).len()
+        );
+
+        // Reassigning overrides the previous region
+        cdt.assign_region(Point2::new(0.3, 3.5), 3);
+        assert_eq!(region_area(&cdt, 1), 0.0);
+        assert_eq!(region_area(&cdt, 3), 8.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ambiguous_seeds() -> Result<(), InsertionError> {
+        let mut cdt = create_split_square()?;
+
+        // Constraint edge
+        assert_eq!(cdt.assign_region(Point2::new(2.0, 1.0), 1), 0);
+        // Vertex
+        assert_eq!(cdt.assign_region(Point2::new(1.0, 1.0), 1), 0);
+        // Outside of the convex hull
+        assert_eq!(cdt.assign_region(Point2::new(10.0, 10.0), 1), 0);
+
+        // Non constraint edge
+        let PositionInTriangulation::OnEdge(edge) = cdt.locate(Point2::new(0.5, 0.5)) else {
+            panic!("Expected an edge");
+        };
+        assert!(!cdt.is_constraint_edge(edge.as_undirected()));
+        assert_eq!(cdt.assign_region(Point2::new(0.5, 0.5), 1), 4);
+        assert_eq!(region_area(&cdt, 1), 8.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_region() -> Result<(), InsertionError> {
+        let mut cdt = create_split_square()?;
+
+        // Crosses the square and its split line
+        let mut ring = vec![
+            Point2::new(1.0, -1.0),
+            Point2::new(5.0, -1.0),
+            Point2::new(5.0, 1.0),
+            Point2::new(1.0, 1.0),
+        ];
+        ring.reverse();
+        let num_faces = cdt.add_region(ring, 1)?;
+        cdt.cdt_sanity_check();
+
+        assert!(num_faces > 0);
+        assert_eq!(region_area(&cdt, 1), 8.0);
+        // The ring's interior consists of multiple constraint-separated parts
+        assert!(cdt.region_faces(Point2::new(1.5, 0.5)).len() < num_faces);
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use alloc::vec::Vec;
+
+use super::handles::{
+    FixedDirectedEdgeHandle, FixedFaceHandle, FixedUndirectedEdgeHandle, FixedVertexHandle,
+    InnerOuterMarker, PossiblyOuterTag,
+};
+
+/// Maps the fixed handles of a triangulation before a reordering operation to their new values.
+///
+/// Old handles that referred to removed elements are mapped to `None`.
+///
+/// *See [Triangulation::compact](crate::Triangulation::compact)*
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HandleRemapping {
+    pub(super) vertices: Vec<Option<FixedVertexHandle>>,
+    pub(super) undirected_edges: Vec<Option<FixedUndirectedEdgeHandle>>,
+    pub(super) faces: Vec<Option<FixedFaceHandle<PossiblyOuterTag>>>,
+}
+
+impl HandleRemapping {
+    /// Returns the new handle of a vertex.
+    ///
+    /// Returns `None` if the old handle did not refer to a vertex.
+    pub fn vertex(&self, old: FixedVertexHandle) -> Option<FixedVertexHandle> {
+        self.vertices.get(old.index()).copied().flatten()
+    }
+
+    /// Returns the new handle of an undirected edge.
+    ///
+    /// Returns `None` if the old handle did not refer to an edge.
+    pub fn undirected_edge(
+        &self,
+        old: FixedUndirectedEdgeHandle,
+    ) -> Option<FixedUndirectedEdgeHandle> {
+        self.undirected_edges.get(old.index()).copied().flatten()
+    }
+
+    /// Returns the new handle of a directed edge.
+    ///
+    /// The edge keeps its direction. Returns `None` if the old handle did not refer to an edge.
+    pub fn directed_edge(&self, old: FixedDirectedEdgeHandle) -> Option<FixedDirectedEdgeHandle> {
+        let new = self.undirected_edge(old.as_undirected())?;
+        Some(if old.is_normalized() {
+            new.normalized()
+        } else {
+            new.not_normalized()
+        })
+    }
+
+    /// Returns the new handle of a face.
+    ///
+    /// The outer face is never moved. Returns `None` if the old handle did not refer to a face.
+    pub fn face<InnerOuter: InnerOuterMarker>(
+        &self,
+        old: FixedFaceHandle<InnerOuter>,
+    ) -> Option<FixedFaceHandle<InnerOuter>> {
+        let new = self.faces.get(old.index()).copied().flatten()?;
+        Some(new.adjust_inner_outer())
+    }
+
+    /// Returns the remapping table of all vertex handles, indexed by the old handle's index.
+    pub fn vertices(&self) -> &[Option<FixedVertexHandle>] {
+        &self.vertices
+    }
+
+    /// Returns the remapping table of all undirected edge handles, indexed by the old handle's
+    /// index.
+    pub fn undirected_edges(&self) -> &[Option<FixedUndirectedEdgeHandle>] {
+        &self.undirected_edges
+    }
+
+    /// Returns the remapping table of all face handles, indexed by the old handle's index.
+    pub fn faces(&self) -> &[Option<FixedFaceHandle<PossiblyOuterTag>>] {
+        &self.faces
+    }
+}
@@ -7,7 +7,10 @@ use super::math;
 
 use crate::HintGenerator;
 use crate::Point2;
-use crate::{HasPosition, InsertionError, PositionInTriangulation, Triangulation};
+use crate::{
+    HasPosition, HasPositionMut, InsertionError, MoveVertexError, PositionInTriangulation,
+    Triangulation,
+};
 
 use alloc::vec::Vec;
 
@@ -170,7 +173,7 @@ pub trait TriangulationExt: Triangulation {
     fn insert_second_vertex(&mut self, vertex: Self::Vertex) -> InsertionResult {
         assert_eq!(self.num_vertices(), 1);
 
-        let first_vertex = FixedVertexHandle::new(0);
+        let first_vertex = self.s().fixed_vertices().next().unwrap();
         if self.vertex(first_vertex).position() == vertex.position() {
             self.s_mut().update_vertex(first_vertex, vertex);
             return InsertionResult::Updated(first_vertex);
@@ -371,14 +374,19 @@ pub trait TriangulationExt: Triangulation {
                 // edge     |    \  |
                 //          |     \ |
                 //         v1-------v3
-                let v2 = edge.rev().opposite_position();
-                let v3 = edge.opposite_position();
+                let v2 = edge.rev().opposite_vertex();
+                let v3 = edge.opposite_vertex();
 
                 if let (Some(v2), Some(v3)) = (v2, v3) {
-                    let v0 = edge.from().position();
-                    let v1 = edge.to().position();
-                    debug_assert!(math::is_ordered_ccw(v2, v1, v0));
-                    let should_flip = math::contained_in_circumference(v2, v1, v0, v3);
+                    let v0 = edge.from();
+                    let v1 = edge.to();
+                    debug_assert!(math::is_ordered_ccw(
+                        v2.position(),
+                        v1.position(),
+                        v0.position()
+                    ));
+                    let should_flip =
+                        self.contained_in_circumference(v2.fix(), v1.fix(), v0.fix(), v3.fix());
                     result |= should_flip;
 
                     if should_flip {
@@ -401,10 +409,12 @@ pub trait TriangulationExt: Triangulation {
     }
 
     fn validate_vertex_handle(&self, handle: FixedVertexHandle) -> FixedVertexHandle {
-        if handle.index() < self.num_vertices() {
+        if self.s().is_vertex_live(handle) {
             handle
         } else {
-            FixedVertexHandle::new(0)
+            // Only reached for outdated hints. Stable handles may leave gaps, hence the first
+            // vertex is not necessarily at index 0.
+            self.s().fixed_vertices().next().unwrap()
         }
     }
 
@@ -597,21 +607,122 @@ pub trait TriangulationExt: Triangulation {
         }
     }
 
+    /// Moves a vertex without changing the triangulation's topology, followed by a sequence of
+    /// edge flips that restores the Delaunay property.
+    ///
+    /// This is only possible if the new position lies strictly within the kernel of the vertex'
+    /// star (the polygon formed by its neighbors). Returns `false` and does nothing if this is not
+    /// the case or if the vertex is part of the convex hull.
+    fn try_move_vertex_locally(
+        &mut self,
+        vertex: FixedVertexHandle,
+        new_position: Point2<<Self::Vertex as HasPosition>::Scalar>,
+    ) -> bool
+    where
+        Self::Vertex: HasPositionMut,
+    {
+        let mut edges_to_legalize = Vec::new();
+        for edge in self.vertex(vertex).out_edges() {
+            if edge.is_outer_edge() {
+                return false;
+            }
+
+            let opposite_edge = edge.next();
+            if !opposite_edge.side_query(new_position).is_on_left_side() {
+                return false;
+            }
+            edges_to_legalize.push(edge.fix().as_undirected());
+            edges_to_legalize.push(opposite_edge.fix().as_undirected());
+        }
+
+        self.vertex_data_mut(vertex).set_position(new_position);
+        self.legalize_edges_after_removal(&mut edges_to_legalize, |_| false);
+        self.hint_generator().notify_vertex_lookup(vertex);
+        true
+    }
+
+    /// Moves a vertex by removing and re-inserting it.
+    ///
+    /// The re-inserted vertex is swapped back into its original place. This restores all vertex
+    /// handles, including checked handles, to their state before the removal.
+    fn move_vertex_by_reinsertion(
+        &mut self,
+        vertex: FixedVertexHandle,
+        new_position: Point2<<Self::Vertex as HasPosition>::Scalar>,
+    ) -> Result<(), InsertionError>
+    where
+        Self::Vertex: HasPositionMut,
+    {
+        // Removal may swap the last vertex into the removed slot. Insertion will then fill the
+        // next free slot. All of these slots need to keep their generation.
+        let last = FixedVertexHandle::new(self.s().num_vertex_slots() - 1);
+        let generation_of_vertex = self.s().vertex_generation(vertex);
+        let generation_of_last = self.s().vertex_generation(last);
+
+        let mut data = self.remove(vertex);
+        data.set_position(new_position);
+
+        let next = self.s().next_vertex_handle();
+        let generation_of_next = self.s().vertex_generation(next);
+
+        let new_handle = self.insert(data)?;
+        if new_handle != vertex {
+            self.swap_vertices_and_notify(vertex, new_handle);
+        }
+
+        // Order matters as some of these handles may be identical
+        self.s_mut().set_vertex_generation(next, generation_of_next);
+        self.s_mut().set_vertex_generation(last, generation_of_last);
+        self.s_mut()
+            .set_vertex_generation(vertex, generation_of_vertex);
+        Ok(())
+    }
+
+    /// Swaps the handles of two vertices and notifies the hint generator.
+    fn swap_vertices_and_notify(&mut self, v0: FixedVertexHandle, v1: FixedVertexHandle) {
+        self.s_mut().swap_vertices(v0, v1);
+        let [p0, p1] = [v0, v1].map(|v| self.s().get_vertex(v).map(|v| v.position()));
+        self.hint_generator_mut()
+            .notify_vertices_swapped(v0, p0, v1, p1);
+    }
+
+    fn check_position_is_free(
+        &self,
+        vertex: FixedVertexHandle,
+        position: Point2<<Self::Vertex as HasPosition>::Scalar>,
+    ) -> Result<(), MoveVertexError> {
+        match self.locate(position) {
+            PositionInTriangulation::OnVertex(other) if other != vertex => {
+                Err(MoveVertexError::OccupiedPosition(other))
+            }
+            _ => Ok(()),
+        }
+    }
+
     fn remove_and_notify(&mut self, vertex_to_remove: FixedVertexHandle) -> Self::Vertex {
         let position = self.vertex(vertex_to_remove).position();
         let removal_result = self.remove_core(vertex_to_remove);
+        self.notify_removal(vertex_to_remove, position, removal_result.swapped_in_vertex);
+        removal_result.removed_vertex
+    }
 
-        let swapped_in_point = removal_result
-            .swapped_in_vertex
-            .map(|_| self.vertex(vertex_to_remove).position());
-
-        self.hint_generator_mut().notify_vertex_removed(
-            swapped_in_point,
-            vertex_to_remove,
-            position,
-        );
+    /// Notifies the triangulation and its hint generator that a vertex has been removed.
+    fn notify_removal(
+        &mut self,
+        removed: FixedVertexHandle,
+        position: Point2<<Self::Vertex as HasPosition>::Scalar>,
+        swapped_in_vertex: Option<FixedVertexHandle>,
+    ) {
+        self.handle_vertex_removal(removed, swapped_in_vertex);
 
-        removal_result.removed_vertex
+        if self.s().has_stable_handles() {
+            self.hint_generator_mut()
+                .notify_vertex_slot_freed(removed, position);
+        } else {
+            let swapped_in_point = swapped_in_vertex.map(|_| self.vertex(removed).position());
+            self.hint_generator_mut()
+                .notify_vertex_removed(swapped_in_point, removed, position);
+        }
     }
 
     fn remove_core(&mut self, vertex_to_remove: FixedVertexHandle) -> RemovalResult<Self::Vertex> {
@@ -635,7 +746,7 @@ pub trait TriangulationExt: Triangulation {
             let mut isolation_result = self.isolate_convex_hull_vertex(convex_hull_edge);
 
             dcel_operations::cleanup_isolated_vertex(self.s_mut(), &mut isolation_result);
-            dcel_operations::swap_remove_vertex(self.s_mut(), vertex_to_remove)
+            dcel_operations::remove_vertex(self.s_mut(), vertex_to_remove)
         } else {
             let mut isolation_result = dcel_operations::isolate_vertex_and_fill_hole(
                 self.s_mut(),
@@ -648,7 +759,7 @@ pub trait TriangulationExt: Triangulation {
                 !isolation_result.is_new_edge(edge)
             });
             dcel_operations::cleanup_isolated_vertex(self.s_mut(), &mut isolation_result);
-            dcel_operations::swap_remove_vertex(self.s_mut(), vertex_to_remove)
+            dcel_operations::remove_vertex(self.s_mut(), vertex_to_remove)
         }
     }
 
@@ -683,6 +794,12 @@ pub trait TriangulationExt: Triangulation {
                     // the most recently added edge (edge2) with the point that was removed
                     let edge_to_flip = edge2.prev().fix().rev();
                     dcel_operations::flip_cw(self.s_mut(), edge_to_flip.as_undirected());
+                    // The flipped edge no longer connects to the removed vertex and must not keep
+                    // its data (e.g. a CDT's constraint flag).
+                    *self
+                        .s_mut()
+                        .undirected_edge_data_mut(edge_to_flip.as_undirected()) =
+                        Default::default();
                     convex_edges.pop();
                     convex_edges.pop();
                     convex_edges.push(edge_to_flip);
@@ -750,18 +867,22 @@ pub trait TriangulationExt: Triangulation {
             let e2 = edge.prev();
             let e4 = edge.rev().prev();
 
-            let from = edge.from().position();
-            let to = edge.to().position();
-            let left = edge.opposite_position();
-            let right = edge.rev().opposite_position();
+            let from = edge.from();
+            let to = edge.to();
+            let left = edge.opposite_vertex();
+            let right = edge.rev().opposite_vertex();
 
             let should_flip = match (left, right) {
                 (Some(left), Some(right)) => {
-                    math::contained_in_circumference(from, to, left, right)
+                    self.contained_in_circumference(from.fix(), to.fix(), left.fix(), right.fix())
                 }
                 // Handle special cases when evaluating edges next to the convex hull
-                (None, Some(right)) => math::is_ordered_ccw(right, from, to),
-                (Some(left), None) => math::is_ordered_ccw(left, to, from),
+                (None, Some(right)) => {
+                    math::is_ordered_ccw(right.position(), from.position(), to.position())
+                }
+                (Some(left), None) => {
+                    math::is_ordered_ccw(left.position(), to.position(), from.position())
+                }
                 (None, None) => {
                     panic!("Unexpected geometry. This is a bug in spade.")
                 }
@@ -851,15 +972,13 @@ pub trait TriangulationExt: Triangulation {
             let rev = edge.rev();
 
             if let (Some(edge_opposite), Some(rev_opposite)) =
-                (edge.opposite_position(), rev.opposite_position())
+                (edge.opposite_vertex(), rev.opposite_vertex())
             {
-                let from = edge.from().position();
-                let to = edge.to().position();
-                assert!(!math::contained_in_circumference(
-                    from,
-                    to,
-                    edge_opposite,
-                    rev_opposite
+                assert!(!self.contained_in_circumference(
+                    edge.from().fix(),
+                    edge.to().fix(),
+                    edge_opposite.fix(),
+                    rev_opposite.fix()
                 ))
             }
         }
@@ -873,7 +992,9 @@ mod test {
     use crate::PositionInTriangulation;
     use crate::TriangulationExt;
     use crate::{
-        handles::FixedVertexHandle, DelaunayTriangulation, InsertionError, Point2, Triangulation,
+        handles::{CheckedDirectedEdgeHandle, CheckedFaceHandle, FixedVertexHandle, InnerTag},
+        ConstrainedDelaunayTriangulation, DelaunayTriangulation, HasPosition,
+        HierarchyHintGenerator, InsertionError, MoveVertexError, Point2, Triangulation,
     };
     use rand::distr::Distribution;
     use rand::distr::Uniform;
@@ -881,6 +1002,9 @@ mod test {
 
     use alloc::{vec, vec::Vec};
 
+    type HierarchyTriangulation =
+        DelaunayTriangulation<Point2<f64>, (), (), (), HierarchyHintGenerator<f64>>;
+
     #[test]
     fn test_empty() {
         let d = DelaunayTriangulation::<Point2<f32>>::default();
@@ -1548,4 +1672,645 @@ mod test {
         triangulation.sanity_check();
         Ok(())
     }
+
+    #[test]
+    fn test_move_vertex() -> Result<(), MoveVertexError> {
+        let mut positions = random_points_with_seed(200, SEED);
+        let mut triangulation = DelaunayTriangulation::<_>::bulk_load_stable(positions.clone())?;
+
+        let mut rng = rand::rngs::StdRng::from_seed(*SEED2);
+        let small_offset = Uniform::new_inclusive(-0.01, 0.01).unwrap();
+        let large_offset = Uniform::new_inclusive(-1.0, 1.0).unwrap();
+        for iteration in 0..400 {
+            let vertex = FixedVertexHandle::new(rng.random_range(0..positions.len()));
+            let offset = if iteration % 4 == 0 {
+                large_offset
+            } else {
+                small_offset
+            };
+            let old_position = positions[vertex.index()];
+            let new_position = Point2::new(
+                old_position.x + offset.sample(&mut rng),
+                old_position.y + offset.sample(&mut rng),
+            );
+
+            if triangulation.locate_vertex(new_position).is_some() {
+                continue;
+            }
+
+            assert_eq!(triangulation.move_vertex(vertex, new_position)?, vertex);
+            positions[vertex.index()] = new_position;
+            triangulation.sanity_check();
+        }
+
+        assert_eq!(triangulation.num_vertices(), positions.len());
+        for (vertex, position) in triangulation.vertices().zip(positions) {
+            assert_eq!(vertex.position(), position);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_vertex_keeps_data() -> Result<(), MoveVertexError> {
+        let mut triangulation = DelaunayTriangulation::<_>::bulk_load_stable(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(0.0, 4.0),
+            Point2::new(4.0, 4.0),
+            Point2::new(1.0, 1.0),
+        ])?;
+
+        let inner = FixedVertexHandle::new(4);
+        // Moves within the vertex' star
+        triangulation.move_vertex(inner, Point2::new(2.5, 3.0))?;
+        triangulation.sanity_check();
+        assert_eq!(
+            triangulation.vertex(inner).position(),
+            Point2::new(2.5, 3.0)
+        );
+
+        // Moves the vertex out of the convex hull
+        triangulation.move_vertex(inner, Point2::new(8.0, 2.0))?;
+        triangulation.sanity_check();
+        assert_eq!(triangulation.convex_hull_size(), 5);
+
+        // Moves a convex hull vertex into the interior
+        let corner = FixedVertexHandle::new(3);
+        triangulation.move_vertex(corner, Point2::new(3.0, 2.0))?;
+        triangulation.sanity_check();
+        assert_eq!(triangulation.convex_hull_size(), 4);
+
+        let positions: Vec<_> = triangulation.vertices().map(|v| v.position()).collect();
+        assert_eq!(
+            positions,
+            vec![
+                Point2::new(0.0, 0.0),
+                Point2::new(4.0, 0.0),
+                Point2::new(0.0, 4.0),
+                Point2::new(3.0, 2.0),
+                Point2::new(8.0, 2.0),
+            ]
+        );
+
+        assert_eq!(
+            triangulation.move_vertex(corner, Point2::new(f64::NAN, 0.0)),
+            Err(MoveVertexError::InsertionError(InsertionError::NAN))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_vertex_onto_other_vertex() -> Result<(), InsertionError> {
+        let mut triangulation = DelaunayTriangulation::<_>::bulk_load_stable(vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(0.0, 4.0),
+        ])?;
+        assert_eq!(
+            triangulation.move_vertex(FixedVertexHandle::new(0), Point2::new(4.0, 0.0)),
+            Err(MoveVertexError::OccupiedPosition(FixedVertexHandle::new(1)))
+        );
+        triangulation.sanity_check();
+        assert_eq!(
+            triangulation.vertex(FixedVertexHandle::new(0)).position(),
+            Point2::new(0.0, 0.0)
+        );
+        Ok(())
+    }
+
+    fn check_stable_vertices<T: Triangulation<Vertex = Point2<f64>>>(
+        triangulation: &T,
+        vertices: &[(FixedVertexHandle, Point2<f64>)],
+    ) {
+        triangulation.sanity_check();
+        assert_eq!(triangulation.num_vertices(), vertices.len());
+        assert_eq!(triangulation.vertices().len(), vertices.len());
+        assert_eq!(triangulation.fixed_vertices().count(), vertices.len());
+        assert_eq!(
+            triangulation.undirected_edges().count(),
+            triangulation.num_undirected_edges()
+        );
+        assert_eq!(
+            triangulation.directed_edges().rev().count(),
+            triangulation.num_directed_edges()
+        );
+        assert_eq!(
+            triangulation.all_faces().count(),
+            triangulation.num_all_faces()
+        );
+        assert_eq!(
+            triangulation.fixed_inner_faces().len(),
+            triangulation.num_inner_faces()
+        );
+        for (handle, position) in vertices {
+            assert_eq!(triangulation.vertex(*handle).position(), *position);
+        }
+    }
+
+    #[test]
+    fn test_compact() -> Result<(), InsertionError> {
+        for stable_handles in [false, true] {
+            let mut d: HierarchyTriangulation =
+                HierarchyTriangulation::bulk_load_stable(random_points_with_seed(200, SEED))?;
+            d.set_stable_handles(stable_handles);
+            let mut rng = rand::rngs::StdRng::from_seed(*SEED2);
+            for _ in 0..50 {
+                let vertex = d
+                    .fixed_vertices()
+                    .nth(rng.random_range(0..d.num_vertices()));
+                d.remove(vertex.unwrap());
+            }
+
+            let vertices: Vec<_> = d.vertices().map(|v| (v.fix(), v.position())).collect();
+            let edges: Vec<_> = d
+                .directed_edges()
+                .map(|e| (e.fix(), e.positions()))
+                .collect();
+            let faces: Vec<_> = d.inner_faces().map(|f| (f.fix(), f.positions())).collect();
+            let checked_vertices: Vec<_> = d.vertices().map(|v| v.checked()).collect();
+
+            let remapping = d.compact();
+            d.sanity_check();
+            assert!(!d.s().has_removed_elements());
+            assert_eq!(
+                remapping.vertices().len(),
+                d.num_vertices() + 50 * stable_handles as usize
+            );
+
+            for (old, position) in vertices {
+                let new = remapping.vertex(old).unwrap();
+                assert_eq!(d.vertex(new).position(), position);
+            }
+            for (old, positions) in edges {
+                let new = remapping.directed_edge(old).unwrap();
+                assert_eq!(d.directed_edge(new).positions(), positions);
+            }
+            for (old, positions) in faces {
+                let new = remapping.face(old).unwrap();
+                assert_eq!(d.face(new).positions(), positions);
+            }
+            assert_eq!(
+                remapping.face(d.outer_face().fix()),
+                Some(d.outer_face().fix())
+            );
+
+            for checked in checked_vertices {
+                let new = remapping.vertex(checked.fix()).unwrap();
+                assert_eq!(d.try_vertex(checked).is_some(), new == checked.fix());
+            }
+
+            // The triangulation remains usable
+            for point in random_points_with_seed(50, SEED2) {
+                d.insert(point)?;
+            }
+            d.sanity_check();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_small() -> Result<(), InsertionError> {
+        let mut d = DelaunayTriangulation::<Point2<f64>>::new();
+        assert!(d.compact().vertices().is_empty());
+
+        let v0 = d.insert(Point2::new(0.0, 0.0))?;
+        let remapping = d.compact();
+        assert_eq!(remapping.vertex(v0), Some(v0));
+
+        d.insert(Point2::new(1.0, 0.0))?;
+        d.compact();
+        d.sanity_check();
+        d.shrink_to_fit();
+        d.sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_checked_handles() -> Result<(), InsertionError> {
+        let mut d =
+            DelaunayTriangulation::<_>::bulk_load_stable(random_points_with_seed(50, SEED))?;
+        let vertices: Vec<_> = d.vertices().map(|v| (v.checked(), v.position())).collect();
+        let edges: Vec<_> = d.directed_edges().map(|e| e.checked()).collect();
+        let faces: Vec<_> = d.inner_faces().map(|f| f.checked()).collect();
+
+        let removed = d.remove(FixedVertexHandle::from_index(10));
+        let swapped_in = FixedVertexHandle::from_index(49);
+        for (handle, position) in &vertices {
+            let vertex = d.try_vertex(*handle);
+            if *position == removed || handle.fix() == swapped_in {
+                assert!(vertex.is_none());
+            } else {
+                assert_eq!(vertex.unwrap().position(), *position);
+            }
+        }
+        let num_valid_edges = edges.iter().filter_map(|e| d.try_directed_edge(*e)).count();
+        assert!(num_valid_edges > 0 && num_valid_edges < edges.len());
+        let num_valid_faces = faces.iter().filter_map(|f| d.try_face(*f)).count();
+        assert!(num_valid_faces > 0 && num_valid_faces < faces.len());
+
+        // The removed index is valid again but refers to a different vertex
+        assert!(d.try_vertex(vertices[10].0).is_none());
+        assert!(d
+            .try_vertex(d.vertex(vertices[10].0.fix()).checked())
+            .is_some());
+
+        let outer_face = d.outer_face().checked();
+        d.clear();
+        assert!(d.try_vertex(vertices[0].0).is_none());
+        assert!(d.try_directed_edge(edges[0]).is_none());
+        assert!(d.try_face(outer_face).is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_checked_handles_with_stable_handles() -> Result<(), InsertionError> {
+        let mut d =
+            DelaunayTriangulation::<_>::bulk_load_stable(random_points_with_seed(50, SEED))?;
+        d.set_stable_handles(true);
+        let vertices: Vec<_> = d.vertices().map(|v| v.checked()).collect();
+
+        let removed_position = d.remove(vertices[10].fix());
+        for (index, vertex) in vertices.iter().enumerate() {
+            assert_eq!(d.try_vertex(*vertex).is_some(), index != 10);
+        }
+        let undirected_edges: Vec<_> = d
+            .undirected_edges()
+            .map(|e| (e.checked(), e.positions()))
+            .collect();
+
+        // Refill the gap
+        let new_vertex = d.insert(removed_position)?;
+        assert_eq!(new_vertex, vertices[10].fix());
+        assert!(d.try_vertex(vertices[10]).is_none());
+        assert!(d.try_vertex(d.vertex(new_vertex).checked()).is_some());
+        // Insertions only invalidate edges that have been flipped
+        let mut num_valid_edges = 0;
+        for (edge, positions) in &undirected_edges {
+            if let Some(edge) = d.try_undirected_edge(*edge) {
+                assert_eq!(edge.positions(), *positions);
+                num_valid_edges += 1;
+            }
+        }
+        assert!(num_valid_edges > undirected_edges.len() / 2);
+        Ok(())
+    }
+
+    type CheckedEdges = Vec<(CheckedDirectedEdgeHandle, [Point2<f64>; 2])>;
+    type CheckedFaces = Vec<(CheckedFaceHandle<InnerTag>, [Point2<f64>; 3])>;
+
+    fn sorted_positions(mut positions: [Point2<f64>; 3]) -> [Point2<f64>; 3] {
+        positions.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        positions
+    }
+
+    /// Checks that all checked handles either resolve to the element they were created for or to
+    /// `None`. Returns the number of valid handles.
+    fn check_checked_handles<T>(
+        t: &T,
+        edges: &[(CheckedDirectedEdgeHandle, [Point2<f64>; 2])],
+        faces: &[(CheckedFaceHandle<InnerTag>, [Point2<f64>; 3])],
+    ) -> usize
+    where
+        T: Triangulation,
+        T::Vertex: HasPosition<Scalar = f64>,
+    {
+        let mut num_valid = 0;
+        for (edge, positions) in edges {
+            if let Some(edge) = t.try_directed_edge(*edge) {
+                assert_eq!(edge.positions(), *positions);
+                num_valid += 1;
+            }
+        }
+        for (face, positions) in faces {
+            if let Some(face) = t.try_face(*face) {
+                assert_eq!(sorted_positions(face.positions()), *positions);
+                num_valid += 1;
+            }
+        }
+        num_valid
+    }
+
+    fn collect_checked_handles<T>(t: &T) -> (CheckedEdges, CheckedFaces)
+    where
+        T: Triangulation,
+        T::Vertex: HasPosition<Scalar = f64>,
+    {
+        let edges = t
+            .directed_edges()
+            .map(|edge| (edge.checked(), edge.positions()))
+            .collect();
+        let faces = t
+            .inner_faces()
+            .map(|face| (face.checked(), sorted_positions(face.positions())))
+            .collect();
+        (edges, faces)
+    }
+
+    #[test]
+    fn test_checked_handles_detect_changed_elements() -> Result<(), InsertionError> {
+        let mut d = DelaunayTriangulation::<Point2<f64>>::new();
+        for (x, y) in [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)] {
+            d.insert(Point2::new(x, y))?;
+        }
+        let (edges, faces) = collect_checked_handles(&d);
+
+        // Both insertions flip the diagonal and split both faces
+        d.insert(Point2::new(2.0, 3.9))?;
+        d.insert(Point2::new(2.1, 0.2))?;
+        check_checked_handles(&d, &edges, &faces);
+        for (face, _) in &faces {
+            assert!(d.try_face(*face).is_none());
+        }
+        let diagonals = edges
+            .iter()
+            .filter(|(_, [from, to])| from.x != to.x && from.y != to.y);
+        for (edge, _) in diagonals {
+            assert!(d.try_directed_edge(*edge).is_none());
+        }
+
+        for stable_handles in [false, true] {
+            let mut d = DelaunayTriangulation::<_>::bulk_load(random_points_with_seed(100, SEED))?;
+            d.set_stable_handles(stable_handles);
+            let mut rng = rand::rngs::StdRng::from_seed(*SEED2);
+            for _ in 0..20 {
+                let (edges, faces) = collect_checked_handles(&d);
+                for _ in 0..5 {
+                    if rng.random_bool(0.5) {
+                        // Also covers insertions outside of the convex hull
+                        d.insert(Point2::new(
+                            rng.random_range(-1.2..1.2),
+                            rng.random_range(-1.2..1.2),
+                        ))?;
+                    } else {
+                        let index = rng.random_range(0..d.num_vertices());
+                        let vertex = d.fixed_vertices().nth(index).unwrap();
+                        d.remove(vertex);
+                    }
+                }
+                let num_valid = check_checked_handles(&d, &edges, &faces);
+                assert!(num_valid > 0);
+            }
+        }
+
+        // Inserting constraints flips edges as well
+        let mut cdt =
+            ConstrainedDelaunayTriangulation::<_>::bulk_load(random_points_with_seed(100, SEED))?;
+        let mut rng = rand::rngs::StdRng::from_seed(*SEED2);
+        for _ in 0..20 {
+            let (edges, faces) = collect_checked_handles(&cdt);
+            let from = FixedVertexHandle::from_index(rng.random_range(0..cdt.num_vertices()));
+            let to = FixedVertexHandle::from_index(rng.random_range(0..cdt.num_vertices()));
+            if from != to
+                && !cdt
+                    .intersects_constraint(cdt.vertex(from).position(), cdt.vertex(to).position())
+            {
+                cdt.add_constraint(from, to);
+            }
+            check_checked_handles(&cdt, &edges, &faces);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_vertex_keeps_checked_handles() -> Result<(), MoveVertexError> {
+        for stable_handles in [false, true] {
+            let mut d =
+                DelaunayTriangulation::<_>::bulk_load_stable(random_points_with_seed(50, SEED))?;
+            d.set_stable_handles(stable_handles);
+            d.remove(FixedVertexHandle::from_index(20));
+            let vertices: Vec<_> = d.vertices().map(|v| v.checked()).collect();
+
+            // Moves the vertex outside of the convex hull which requires re-insertion
+            d.move_vertex(vertices[3].fix(), Point2::new(10.0, 10.0))?;
+            d.move_vertex(vertices[0].fix(), Point2::new(-10.0, 10.0))?;
+            for vertex in &vertices {
+                assert!(d.try_vertex(*vertex).is_some());
+            }
+            assert_eq!(
+                d.try_vertex(vertices[3]).unwrap().position(),
+                Point2::new(10.0, 10.0)
+            );
+            d.sanity_check();
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_stable_handles_removal_and_insertion() -> Result<(), InsertionError> {
+        let points = random_points_with_seed(300, SEED);
+        let mut d = DelaunayTriangulation::<_>::bulk_load_stable(points.clone())?;
+        d.set_stable_handles(true);
+        assert!(d.has_stable_handles());
+
+        let mut vertices: Vec<_> = d.fixed_vertices().zip(points).collect();
+        let mut rng = rand::rngs::StdRng::from_seed(*SEED2);
+        for step in 0..1000 {
+            if rng.random_bool(0.4) {
+                let position =
+                    Point2::new(rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0));
+                let handle = d.insert(position)?;
+                vertices.push((handle, position));
+            } else if !vertices.is_empty() {
+                let index = rng.random_range(0..vertices.len());
+                let (handle, position) = vertices.swap_remove(index);
+                assert_eq!(d.remove(handle), position);
+            }
+
+            if step % 100 == 0 {
+                check_stable_vertices(&d, &vertices);
+            }
+        }
+        check_stable_vertices(&d, &vertices);
+        assert!(d.s().has_removed_elements());
+        Ok(())
+    }
+
+    #[test]
+    fn test_stable_handles_iterator_nth() -> Result<(), InsertionError> {
+        fn check_nth<I>(iterator: impl Fn() -> I)
+        where
+            I: DoubleEndedIterator + ExactSizeIterator,
+            I::Item: PartialEq + Copy + core::fmt::Debug,
+        {
+            let expected: Vec<_> = iterator().collect();
+            assert_eq!(iterator().len(), expected.len());
+            for skip in [0, 1, 2, 5] {
+                for n in 0..expected.len() + 2 {
+                    let mut forward = iterator();
+                    forward.nth(skip);
+                    assert_eq!(forward.nth(n), expected.get(skip + 1 + n).copied());
+                    assert_eq!(forward.len(), expected.len().saturating_sub(skip + n + 2));
+                    assert_eq!(forward.next(), expected.get(skip + n + 2).copied());
+
+                    let mut backward = iterator();
+                    backward.nth_back(skip);
+                    let index = expected.len().checked_sub(skip + n + 2);
+                    assert_eq!(backward.nth_back(n), index.map(|index| expected[index]));
+                    assert_eq!(backward.len(), index.unwrap_or(0));
+                }
+            }
+        }
+
+        let mut d = DelaunayTriangulation::<_>::bulk_load(random_points_with_seed(40, SEED))?;
+        d.set_stable_handles(true);
+        for index in [3, 4, 5, 17, 30, 31] {
+            d.remove(FixedVertexHandle::from_index(index));
+        }
+        assert!(d.s().has_removed_elements());
+
+        check_nth(|| d.fixed_vertices());
+        check_nth(|| d.fixed_directed_edges());
+        check_nth(|| d.fixed_undirected_edges());
+        check_nth(|| d.fixed_all_faces());
+        check_nth(|| d.fixed_inner_faces());
+        check_nth(|| d.vertices());
+        check_nth(|| d.directed_edges());
+        Ok(())
+    }
+
+    #[test]
+    fn test_stable_handles_keep_edges_and_faces() -> Result<(), InsertionError> {
+        let mut d = DelaunayTriangulation::<_>::bulk_load(random_points_with_seed(100, SEED))?;
+        d.set_stable_handles(true);
+
+        let edges: Vec<_> = d
+            .directed_edges()
+            .map(|edge| (edge.fix(), edge.positions()))
+            .collect();
+        let faces: Vec<_> = d
+            .inner_faces()
+            .map(|face| (face.fix(), face.positions()))
+            .collect();
+
+        let to_remove = d.vertices().nth(50).unwrap();
+        let removed_position = to_remove.position();
+        let removed_edges: Vec<_> = to_remove.out_edges().map(|edge| edge.fix()).collect();
+        let to_remove = to_remove.fix();
+        d.remove(to_remove);
+
+        for (edge, positions) in edges {
+            if !positions.contains(&removed_position) {
+                assert_eq!(d.directed_edge(edge).positions(), positions);
+            }
+        }
+        for (face, positions) in faces {
+            if !positions.contains(&removed_position) {
+                assert_eq!(d.face(face).positions(), positions);
+            }
+        }
+
+        for edge in &removed_edges {
+            assert!(!d.s().is_undirected_edge_live(edge.as_undirected()));
+        }
+
+        // Gaps are refilled by subsequent insertions
+        let num_slots = d.s().num_vertex_slots();
+        assert_eq!(d.insert(removed_position)?, to_remove);
+        assert_eq!(d.s().num_vertex_slots(), num_slots);
+        d.sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_stable_handles_remove_until_empty() -> Result<(), InsertionError> {
+        let mut d = DelaunayTriangulation::<_>::new();
+        d.set_stable_handles(true);
+        for _ in 0..2 {
+            let mut vertices = Vec::new();
+            for position in random_points_with_seed(20, SEED)
+                .into_iter()
+                .chain([Point2::new(2.0, 2.0), Point2::new(3.0, 3.0)])
+            {
+                vertices.push((d.insert(position)?, position));
+            }
+
+            // Removes vertices from the front to also cover the degenerate cases
+            while !vertices.is_empty() {
+                let (handle, position) = vertices.remove(0);
+                assert_eq!(d.remove(handle), position);
+                check_stable_vertices(&d, &vertices);
+            }
+            assert_eq!(d.num_all_faces(), 1);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_stable_handles_on_line() -> Result<(), InsertionError> {
+        let mut d = DelaunayTriangulation::<_>::new();
+        d.set_stable_handles(true);
+        let mut vertices = Vec::new();
+        for x in [0.0, 4.0, 2.0, 1.0, 3.0] {
+            let position = Point2::new(x, x);
+            vertices.push((d.insert(position)?, position));
+        }
+
+        let (handle, _) = vertices.remove(2);
+        d.remove(handle);
+        check_stable_vertices(&d, &vertices);
+        let (handle, _) = vertices.remove(0);
+        d.remove(handle);
+        check_stable_vertices(&d, &vertices);
+
+        let position = Point2::new(-1.0, -1.0);
+        vertices.push((d.insert(position)?, position));
+        check_stable_vertices(&d, &vertices);
+        Ok(())
+    }
+
+    #[test]
+    fn test_disable_stable_handles() -> Result<(), InsertionError> {
+        let points = random_points_with_seed(200, SEED);
+        let mut d = DelaunayTriangulation::<_>::bulk_load(points)?;
+        d.set_stable_handles(true);
+        for index in (0..200).step_by(3) {
+            d.remove(FixedVertexHandle::from_index(index));
+        }
+        let mut positions: Vec<_> = d.vertices().map(|v| v.position()).collect();
+        let num_edges = d.num_undirected_edges();
+
+        d.set_stable_handles(false);
+        assert!(!d.has_stable_handles());
+        assert!(!d.s().has_removed_elements());
+        assert_eq!(d.s().num_vertex_slots(), d.num_vertices());
+        assert_eq!(d.s().num_undirected_edge_slots(), num_edges);
+        assert_eq!(d.s().num_face_slots(), d.num_all_faces());
+        d.sanity_check();
+
+        let mut new_positions: Vec<_> = d.vertices().map(|v| v.position()).collect();
+        let compare = |a: &Point2<f64>, b: &Point2<f64>| a.partial_cmp(b).unwrap();
+        positions.sort_by(compare);
+        new_positions.sort_by(compare);
+        assert_eq!(positions, new_positions);
+
+        d.remove(FixedVertexHandle::from_index(0));
+        d.sanity_check();
+        Ok(())
+    }
+
+    #[test]
+    fn test_stable_handles_with_hierarchy() -> Result<(), InsertionError> {
+        let points = random_points_with_seed(500, SEED);
+        let mut d = HierarchyTriangulation::bulk_load_stable(points.clone())?;
+        d.set_stable_handles(true);
+
+        let mut vertices: Vec<_> = d.fixed_vertices().zip(points).collect();
+        let mut rng = rand::rngs::StdRng::from_seed(*SEED2);
+        for _ in 0..1000 {
+            if rng.random() {
+                let position =
+                    Point2::new(rng.random_range(-1.0..1.0), rng.random_range(-1.0..1.0));
+                vertices.push((d.insert(position)?, position));
+            } else if !vertices.is_empty() {
+                let index = rng.random_range(0..vertices.len());
+                let (handle, position) = vertices.swap_remove(index);
+                assert_eq!(d.remove(handle), position);
+            }
+        }
+        check_stable_vertices(&d, &vertices);
+        for (handle, position) in &vertices {
+            assert_eq!(d.nearest_neighbor(*position).unwrap().fix(), *handle);
+        }
+        Ok(())
+    }
 }
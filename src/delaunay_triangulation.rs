@@ -226,6 +226,10 @@ use serde::{Deserialize, Serialize};
 /// }
 /// ```
 ///
+/// If only the cell geometry is needed,
+/// [voronoi_cells_clipped](crate::FloatTriangulation::voronoi_cells_clipped) returns closed
+/// polygons for all cells, clipped to a rectangle or convex polygon.
+///
 /// # Performance tuning
 ///
 /// Fine-tuning a Delaunay triangulation can be more tricky from time to time. However, some will *nearly always* be
@@ -354,6 +358,52 @@ where
         *result.hint_generator_mut() = L::initialize_from_triangulation(&result);
         Ok(result)
     }
+
+    /// Creates a new Delaunay triangulation by bulk loading vertices on multiple threads.
+    ///
+    /// This method is only available with the `rayon` feature. It uses a divide and conquer
+    /// algorithm whose sub problems are processed in parallel on rayon's global thread pool.
+    /// Prefer this method over [Triangulation::bulk_load] for very large inputs (millions of
+    /// vertices) if multiple cores are available. Parallel bulk loading requires more memory than
+    /// [Triangulation::bulk_load]. Inputs with more than `u32::MAX / 6` vertices are bulk loaded
+    /// sequentially.
+    ///
+    /// The resulting triangulation will be identical to the result of [Triangulation::bulk_load]
+    /// if no four vertices lie on a common circle. Otherwise, the Delaunay triangulation is not
+    /// unique and both methods may choose a different one. The order of vertices, edges and faces
+    /// is unspecified and will differ.
+    ///
+    /// # Duplicate handling
+    ///
+    /// If two vertices have the same position, only one of them will be included in the final
+    /// triangulation. It is undefined which of them is discarded.
+    ///
+    /// # Example
+    /// ```
+    /// # use spade::InsertionError;
+    /// use spade::{DelaunayTriangulation, Point2, Triangulation};
+    ///
+    /// # fn main() -> Result<(), InsertionError> {
+    /// let vertices = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(0.0, 1.0),
+    ///     Point2::new(1.0, 1.5),
+    /// ];
+    /// let triangulation = DelaunayTriangulation::<Point2<f64>>::bulk_load_parallel(vertices)?;
+    /// assert_eq!(triangulation.num_inner_faces(), 2);
+    /// # Ok(()) }
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn bulk_load_parallel(elements: Vec<V>) -> Result<Self, InsertionError>
+    where
+        V: Send + Sync,
+        V::Scalar: Send + Sync,
+    {
+        let mut result: Self = crate::delaunay_core::bulk_load_parallel(elements)?;
+        *result.hint_generator_mut() = L::initialize_from_triangulation(&result);
+        Ok(result)
+    }
 }
 
 impl<V, DE, UE, F, L> Default for DelaunayTriangulation<V, DE, UE, F, L>
@@ -445,7 +495,10 @@ where
 mod test {
     use crate::test_utilities::{random_points_with_seed, SEED};
 
-    use crate::{DelaunayTriangulation, InsertionError, Point2, Triangulation};
+    use crate::{
+        handles::FixedVertexHandle, CellBounds, DelaunayTriangulation, FloatTriangulation,
+        InsertionError, Point2, Triangulation,
+    };
 
     #[allow(unused)]
     #[cfg(feature = "serde")]
@@ -463,6 +516,86 @@ mod test {
         requires_serde::<DT<HierarchyHintGenerator<f64>>>();
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_json_format() -> Result<(), InsertionError> {
+        // Serialized by spade 2.13
+        let json = r#"
+            {"dcel":{"vertices":[{"data":{"x":0.0,"y":0.0},"out_edge":{"index":0,"ty":null,
+            "inner_outer":null}},{"data":{"x":1.0,"y":0.0},"out_edge":{"index":1,"ty":null,
+            "inner_outer":null}},{"data":{"x":0.0,"y":1.0},"out_edge":{"index":4,"ty":null,
+            "inner_outer":null}}],"faces":[{"adjacent_edge":{"index":5,"ty":null,"inner_outer":null},
+            "data":null},{"adjacent_edge":{"index":0,"ty":null,"inner_outer":null},"data":null}],
+            "edges":[{"entries":[{"next":{"index":2,"ty":null,"inner_outer":null},"prev":{"index":4,
+            "ty":null,"inner_outer":null},"face":{"index":1,"ty":null,"inner_outer":null},
+            "origin":{"index":0,"ty":null,"inner_outer":null}},{"next":{"index":5,"ty":null,
+            "inner_outer":null},"prev":{"index":3,"ty":null,"inner_outer":null},"face":{"index":0,
+            "ty":null,"inner_outer":null},"origin":{"index":1,"ty":null,"inner_outer":null}}],
+            "directed_data":[null,null],"undirected_data":null},{"entries":[{"next":{"index":4,"ty":null,
+            "inner_outer":null},"prev":{"index":0,"ty":null,"inner_outer":null},"face":{"index":1,
+            "ty":null,"inner_outer":null},"origin":{"index":1,"ty":null,"inner_outer":null}},
+            {"next":{"index":1,"ty":null,"inner_outer":null},"prev":{"index":5,"ty":null,
+            "inner_outer":null},"face":{"index":0,"ty":null,"inner_outer":null},"origin":{"index":2,
+            "ty":null,"inner_outer":null}}],"directed_data":[null,null],"undirected_data":null},
+            {"entries":[{"next":{"index":0,"ty":null,"inner_outer":null},"prev":{"index":2,"ty":null,
+            "inner_outer":null},"face":{"index":1,"ty":null,"inner_outer":null},"origin":{"index":2,
+            "ty":null,"inner_outer":null}},{"next":{"index":3,"ty":null,"inner_outer":null},
+            "prev":{"index":1,"ty":null,"inner_outer":null},"face":{"index":0,"ty":null,
+            "inner_outer":null},"origin":{"index":0,"ty":null,"inner_outer":null}}],
+            "directed_data":[null,null],"undirected_data":null}]},"hint_generator":{}}
+        "#
+        .split_whitespace()
+        .collect::<alloc::string::String>();
+
+        let mut triangulation = DelaunayTriangulation::<Point2<f64>>::new();
+        let v0 = triangulation.insert(Point2::new(0.0, 0.0))?;
+        triangulation.insert(Point2::new(1.0, 0.0))?;
+        triangulation.insert(Point2::new(0.0, 1.0))?;
+        assert_eq!(serde_json::to_string(&triangulation).unwrap(), json);
+
+        let deserialized: DelaunayTriangulation<Point2<f64>> = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+
+        // Removing a vertex invalidates checked handles. Their generations are serialized.
+        let checked_v0 = triangulation.vertex(v0).checked();
+        triangulation.remove(v0);
+        let json = serde_json::to_string(&triangulation).unwrap();
+        assert!(json.contains("generations"));
+        let deserialized: DelaunayTriangulation<Point2<f64>> = serde_json::from_str(&json).unwrap();
+        assert!(deserialized.try_vertex(checked_v0).is_none());
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), json);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_binary_format() -> Result<(), InsertionError> {
+        type Dt = DelaunayTriangulation<Point2<f64>>;
+
+        // Formats that aren't self-describing always contain the state of stable and checked
+        // handles, even if they haven't been used.
+        let mut triangulation = Dt::bulk_load(random_points_with_seed(20, SEED))?;
+        let bytes = bincode::serialize(&triangulation).unwrap();
+        let deserialized: Dt = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(bincode::serialize(&deserialized).unwrap(), bytes);
+
+        triangulation.set_stable_handles(true);
+        let v0 = FixedVertexHandle::from_index(0);
+        let checked_v1 = triangulation
+            .vertex(FixedVertexHandle::from_index(1))
+            .checked();
+        triangulation.remove(v0);
+        let bytes = bincode::serialize(&triangulation).unwrap();
+        let mut deserialized: Dt = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(bincode::serialize(&deserialized).unwrap(), bytes);
+        assert!(deserialized.has_stable_handles());
+        assert!(deserialized.try_vertex(checked_v1).is_some());
+
+        // The gap left by the removed vertex is reused
+        assert_eq!(deserialized.insert(Point2::new(0.5, 0.5))?, v0);
+        Ok(())
+    }
+
     #[test]
     fn test_nearest_neighbor() -> Result<(), InsertionError> {
         const SIZE: usize = 54;
@@ -500,4 +633,57 @@ mod test {
         // Just needs to compile
         DelaunayTriangulation::<Point2<f64>>::new().clone();
     }
+
+    #[test]
+    fn test_voronoi_cells_clipped() -> Result<(), InsertionError> {
+        let mut d = DelaunayTriangulation::<_>::bulk_load(random_points_with_seed(200, SEED))?;
+        // Leave gaps in the vertex indices
+        d.set_stable_handles(true);
+        for index in (0..200).step_by(7) {
+            d.remove(FixedVertexHandle::from_index(index));
+        }
+
+        // A hexagon with clockwise orientation
+        let hexagon = (0..6)
+            .rev()
+            .map(|i| {
+                let angle = core::f64::consts::PI / 3.0 * i as f64;
+                Point2::new(angle.cos() * 0.8, angle.sin() * 0.8)
+            })
+            .collect();
+        let rectangle = CellBounds::Rectangle {
+            lower: Point2::new(-0.5, -0.7),
+            upper: Point2::new(0.9, 0.6),
+        };
+        let hexagon_area = 1.5 * 3.0f64.sqrt() * 0.8 * 0.8;
+
+        for (bounds, expected_area) in [
+            (rectangle, 1.4 * 1.3),
+            (CellBounds::ConvexPolygon(hexagon), hexagon_area),
+        ] {
+            let cells = d.voronoi_cells_clipped(&bounds);
+            assert_eq!(cells.len(), d.num_vertices());
+
+            let mut total_area = 0.0;
+            for (cell, vertex) in cells.iter().zip(d.fixed_vertices()) {
+                assert_eq!(cell.vertex, vertex);
+                total_area += cell.area;
+
+                let Some(centroid) = cell.centroid else {
+                    assert!(cell.polygon.is_empty());
+                    continue;
+                };
+                // The centroid of a convex cell lies inside of it
+                let nearest = d.nearest_neighbor(centroid).unwrap();
+                let vertex = d.vertex(cell.vertex);
+                assert!(
+                    vertex.position().distance_2(centroid)
+                        - nearest.position().distance_2(centroid)
+                        < 1.0e-10
+                );
+            }
+            assert!((total_area - expected_area).abs() < 1.0e-10);
+        }
+        Ok(())
+    }
 }
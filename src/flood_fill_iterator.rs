@@ -9,7 +9,8 @@ use crate::delaunay_core::math;
 use crate::handles::VertexHandle;
 use crate::{
     handles::{
-        DirectedEdgeHandle, FixedDirectedEdgeHandle, FixedVertexHandle, UndirectedEdgeHandle,
+        DirectedEdgeHandle, FixedDirectedEdgeHandle, FixedFaceHandle, FixedVertexHandle,
+        PossiblyOuterTag, UndirectedEdgeHandle,
     },
     HasPosition, Point2, SpadeNum, Triangulation,
 };
@@ -233,7 +234,7 @@ where
         let initial_elements = if inner_iter.t.num_vertices() == 1 {
             // The flood fill iterator requires at least a single edge to work properly. We'll have
             // to special case triangulations that contain vertices but no edges.
-            smallvec![FixedVertexHandle::new(0)]
+            smallvec![inner_iter.t.s().fixed_vertices().next().unwrap()]
         } else {
             inner_iter.already_visited.iter().copied().collect()
         };
@@ -553,6 +554,73 @@ where
     }
 }
 
+/// Describes how a face flood fill crosses an edge, see [flood_fill_faces].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum FloodFillStep<S> {
+    /// The neighboring face is visited with the given state.
+    Immediate(S),
+    /// The neighboring face is visited with the given state after all faces that can be reached
+    /// with immediate steps have been visited.
+    Deferred(S),
+}
+
+/// Visits all faces that can be reached from some start faces by crossing their edges.
+///
+/// Every visited face is assigned a state. `step` is called for each edge that leads from a
+/// visited face into a neighboring face. It receives the edge (the neighbor is on its left side)
+/// and the state of the visited face and returns the neighbor's state or `None` if the edge must
+/// not be crossed. The convex hull leads from the outer face into the inner faces next to it.
+///
+/// Faces behind deferred steps are only visited once all faces reachable with immediate steps
+/// have been visited. This makes each face receive its state along a path with the least number
+/// of deferred steps.
+///
+/// Returns the state of all faces, indexed by their [index](crate::handles::FixedFaceHandle::index).
+/// Faces that have not been reached are `None`.
+pub(crate) fn flood_fill_faces<T, S>(
+    t: &T,
+    start: impl IntoIterator<Item = (FixedFaceHandle<PossiblyOuterTag>, S)>,
+    mut step: impl FnMut(
+        DirectedEdgeHandle<T::Vertex, T::DirectedEdge, T::UndirectedEdge, T::Face>,
+        S,
+    ) -> Option<FloodFillStep<S>>,
+) -> Vec<Option<S>>
+where
+    T: Triangulation,
+    S: Copy,
+{
+    let mut states = alloc::vec![None; t.s().num_face_slots()];
+    let mut todo: VecDeque<_> = start.into_iter().collect();
+
+    while let Some((face, state)) = todo.pop_front() {
+        if states[face.index()].is_some() {
+            continue;
+        }
+        states[face.index()] = Some(state);
+
+        let face = t.face(face);
+        let edges: SmallVec<[_; 3]> = if let Some(inner) = face.as_inner() {
+            inner.adjacent_edges().into_iter().collect()
+        } else {
+            t.convex_hull().collect()
+        };
+
+        for edge in edges {
+            let neighbor = edge.rev();
+            let neighbor_face = neighbor.face().fix();
+            if states[neighbor_face.index()].is_some() {
+                continue;
+            }
+            match step(neighbor, state) {
+                Some(FloodFillStep::Immediate(state)) => todo.push_front((neighbor_face, state)),
+                Some(FloodFillStep::Deferred(state)) => todo.push_back((neighbor_face, state)),
+                None => {}
+            }
+        }
+    }
+    states
+}
+
 #[cfg(test)]
 mod test {
 
@@ -10,6 +10,7 @@
 //! * Serde support with the `serde` feature.
 //! * `no_std` support with `default-features = false`
 //! * Natural neighbor interpolation: [NaturalNeighbor]
+//! * A 2D regular (weighted Delaunay) triangulation: [RegularTriangulation]
 //!
 //! # Cargo features
 //!
@@ -19,6 +20,8 @@
 //!  - `mint`: Enables rudimentary [mint](https://docs.rs/mint/latest/mint/) interoperability by
 //!    implementing the `From` and `Into` conversion traits between `spade::Point2` and
 //!    `mint::Point2`. Also implements [HasPosition] for `mint::Point2`.
+//!  - `rayon`: Enables parallel bulk loading of Delaunay triangulations with
+//!    `DelaunayTriangulation::bulk_load_parallel`.
 
 #![no_std]
 #![forbid(unsafe_code)]
@@ -38,21 +41,27 @@ mod delaunay_triangulation;
 mod flood_fill_iterator;
 mod intersection_iterator;
 mod point;
+mod regular_triangulation;
+mod streaming;
 
 mod triangulation;
 
-pub use crate::cdt::{CdtEdge, ConstrainedDelaunayTriangulation};
+pub use crate::cdt::{CdtEdge, ConstrainedDelaunayTriangulation, ConstraintError, SnapGrid};
 pub use crate::delaunay_triangulation::DelaunayTriangulation;
-pub use crate::point::{HasPosition, Point2, SpadeNum};
+pub use crate::point::{HasPosition, HasPositionMut, HasWeight, Point2, SpadeNum};
+pub use crate::regular_triangulation::RegularTriangulation;
+pub use crate::streaming::{FinalizedTriangle, StreamingTriangulation};
+pub use delaunay_core::HasRegion;
 
 pub use crate::delaunay_core::math::{
-    mitigate_underflow, validate_coordinate, validate_vertex, InsertionError, PointProjection,
-    MAX_ALLOWED_VALUE, MIN_ALLOWED_VALUE,
+    mitigate_underflow, validate_coordinate, validate_vertex, InsertionError, MoveVertexError,
+    PointProjection, MAX_ALLOWED_VALUE, MIN_ALLOWED_VALUE,
 };
 
 pub use delaunay_core::{
-    AngleLimit, HierarchyHintGenerator, HierarchyHintGeneratorWithBranchFactor, HintGenerator,
-    LastUsedVertexHintGenerator, RefinementParameters, RefinementResult,
+    AngleLimit, CellBounds, ClippedCell, FillRule, HandleRemapping, HierarchyHintGenerator,
+    HierarchyHintGeneratorWithBranchFactor, HintGenerator, LastUsedVertexHintGenerator,
+    RefinementParameters, RefinementResult, RelaxationResult, SteinerVertexLocation,
 };
 
 pub use crate::delaunay_core::interpolation::{Barycentric, NaturalNeighbor};
@@ -112,12 +121,32 @@ mod test_utilities;
 /// responsibility to make sure that fixed handles are not used anymore after a removal operation
 /// has taken place.
 ///
+/// Alternatively, [stable handles](Triangulation::set_stable_handles) can be enabled. Removals will
+/// then only invalidate the handles of removed elements.
+///
 /// Fixed handles also come in four variants, depending on which element they refer to:
 ///  * [FixedVertexHandle](handles::FixedVertexHandle)
 ///  * [FixedFaceHandle](handles::FixedFaceHandle)
 ///  * [FixedDirectedEdgeHandle](handles::FixedDirectedEdgeHandle)
 ///  * [FixedUndirectedEdgeHandle](handles::FixedUndirectedEdgeHandle)
 ///
+/// # Checked handles
+/// Fixed handles can be converted into _checked_ handles by calling `checked()` on their reference
+/// handle. A checked handle additionally stores a generation counter of its element. This allows
+/// to detect outdated handles: [Triangulation::try_vertex], [Triangulation::try_face],
+/// [Triangulation::try_directed_edge] and [Triangulation::try_undirected_edge] return `None`
+/// if the element has been removed or if its index has been reused by another element.
+///
+/// Edges and faces are identified by their vertices: Any operation that changes an edge's vertices
+/// (e.g. an edge flip) or a face's vertices (e.g. inserting a vertex into the face) invalidates the
+/// corresponding checked handles, even if the element's index is still in use.
+///
+/// Checked handles come in four variants as well:
+///  * [CheckedVertexHandle](handles::CheckedVertexHandle)
+///  * [CheckedFaceHandle](handles::CheckedFaceHandle)
+///  * [CheckedDirectedEdgeHandle](handles::CheckedDirectedEdgeHandle)
+///  * [CheckedUndirectedEdgeHandle](handles::CheckedUndirectedEdgeHandle)
+///
 /// # Retrieving handles by iteration
 ///
 /// The [Triangulation] trait defines iterators for all handle types:
@@ -198,8 +227,10 @@ mod test_utilities;
 /// ```
 pub mod handles {
     pub use crate::delaunay_core::{
-        DirectedEdgeHandle, DirectedVoronoiEdge, FaceHandle, FixedDirectedEdgeHandle,
-        FixedFaceHandle, FixedUndirectedEdgeHandle, FixedVertexHandle, InnerTag, PossiblyOuterTag,
+        CheckedDirectedEdgeHandle, CheckedFaceHandle, CheckedUndirectedEdgeHandle,
+        CheckedVertexHandle, DirectedEdgeHandle, DirectedPowerEdge, DirectedVoronoiEdge,
+        FaceHandle, FixedDirectedEdgeHandle, FixedFaceHandle, FixedUndirectedEdgeHandle,
+        FixedVertexHandle, InnerTag, PossiblyOuterTag, PowerCell, PowerVertex,
         UndirectedEdgeHandle, UndirectedVoronoiEdge, VertexHandle, VoronoiFace, VoronoiVertex,
         OUTER_FACE,
     };
@@ -208,16 +239,25 @@ pub mod handles {
 /// Iterators over various elements of Delaunay triangulations.
 pub mod iterators {
     pub use crate::delaunay_core::iterators::{
-        DirectedEdgeIterator, DirectedVoronoiEdgeIterator, FaceIterator, FixedDirectedEdgeIterator,
-        FixedFaceIterator, FixedInnerFaceIterator, FixedUndirectedEdgeIterator,
-        FixedVertexIterator, InnerFaceIterator, UndirectedEdgeIterator,
-        UndirectedVoronoiEdgeIterator, VertexIterator, VoronoiFaceIterator,
+        DirectedEdgeIterator, DirectedPowerEdgeIterator, DirectedVoronoiEdgeIterator, FaceIterator,
+        FixedDirectedEdgeIterator, FixedFaceIterator, FixedInnerFaceIterator,
+        FixedUndirectedEdgeIterator, FixedVertexIterator, InnerFaceIterator, PowerCellIterator,
+        UndirectedEdgeIterator, UndirectedVoronoiEdgeIterator, VertexIterator, VoronoiFaceIterator,
     };
     pub use crate::flood_fill_iterator::{
         CircleMetric, EdgesInShapeIterator, RectangleMetric, VerticesInShapeIterator,
     };
 }
 
+/// Boolean operations (union, intersection, difference and xor) on sets of polygons.
+///
+/// *See [boolean_operation](boolean::boolean_operation)*
+pub mod boolean {
+    pub use crate::delaunay_core::{
+        boolean_operation, BooleanOperation, BooleanResult, PolygonSet,
+    };
+}
+
 /// Internals that must be published due to technical reasons. This is not the place you are
 /// looking for. A change to these items is not considered to be a breaking change.
 pub mod internals {
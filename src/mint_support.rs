@@ -1,4 +1,4 @@
-use crate::{HasPosition, SpadeNum};
+use crate::{HasPosition, HasPositionMut, SpadeNum};
 
 impl<S> HasPosition for mint::Point2<S>
 where
@@ -11,6 +11,15 @@ where
     }
 }
 
+impl<S> HasPositionMut for mint::Point2<S>
+where
+    S: SpadeNum,
+{
+    fn set_position(&mut self, position: crate::Point2<Self::Scalar>) {
+        *self = position.into();
+    }
+}
+
 impl<S> From<mint::Point2<S>> for crate::Point2<S> {
     fn from(value: mint::Point2<S>) -> Self {
         crate::Point2::new(value.x, value.y)
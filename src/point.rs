@@ -131,6 +131,35 @@ pub trait HasPosition {
     fn position(&self) -> Point2<Self::Scalar>;
 }
 
+/// An object with a position that can be changed.
+///
+/// This is required for operations that relocate the vertices of an existing triangulation,
+/// e.g. [DelaunayTriangulation::lloyd_relax](crate::DelaunayTriangulation::lloyd_relax).
+/// These operations keep the vertex' remaining data intact.
+pub trait HasPositionMut: HasPosition {
+    /// Sets the position of this object.
+    ///
+    /// **Note**: This method is only called by triangulation methods that update the triangulation
+    /// accordingly. It must not be used to move vertices that are part of a triangulation - see
+    /// [HasPosition::position].
+    fn set_position(&mut self, position: Point2<Self::Scalar>);
+}
+
+/// An object with a position and a weight.
+///
+/// Vertices need to implement this trait to allow being inserted into a
+/// [RegularTriangulation](crate::RegularTriangulation).
+pub trait HasWeight: HasPosition {
+    /// Returns the weight of this object.
+    ///
+    /// The weight can be interpreted as the squared radius of a circle centered at the object's
+    /// position. Larger weights will claim larger cells of the power diagram.
+    ///
+    /// **Note**: Just like the position, the weight must not change once the object has been
+    /// inserted into a triangulation.
+    fn weight(&self) -> Self::Scalar;
+}
+
 impl<S: SpadeNum> HasPosition for Point2<S> {
     type Scalar = S;
 
@@ -138,3 +167,9 @@ impl<S: SpadeNum> HasPosition for Point2<S> {
         *self
     }
 }
+
+impl<S: SpadeNum> HasPositionMut for Point2<S> {
+    fn set_position(&mut self, position: Point2<S>) {
+        *self = position;
+    }
+}
@@ -0,0 +1,1233 @@
+use super::delaunay_core::Dcel;
+use crate::delaunay_core::{dcel_operations, hilbert_sort, math};
+use crate::{
+    handles::{FixedVertexHandle, VertexHandle},
+    iterators::{DirectedPowerEdgeIterator, PowerCellIterator},
+    HandleRemapping, HasPosition, HasPositionMut, HasWeight, HintGenerator, InsertionError,
+    LastUsedVertexHintGenerator, MoveVertexError, Point2, PositionInTriangulation, Triangulation,
+    TriangulationExt,
+};
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A two-dimensional [regular triangulation](https://en.wikipedia.org/wiki/Weighted_Voronoi_diagram),
+/// also known as *weighted Delaunay triangulation*.
+///
+/// A regular triangulation generalizes a [Delaunay triangulation](crate::DelaunayTriangulation)
+/// by assigning a *weight* to each vertex. The weight can be thought of as the squared radius of a
+/// circle centered at the vertex. Instead of requiring empty circumcircles, a regular triangulation
+/// requires that no vertex is closer to the *orthogonal circle* of any triangle than that triangle's
+/// own vertices when measured with the *power distance* `|p - v|² - weight(v)`.
+///
+/// Its dual graph is the *power diagram* (also called *Laguerre diagram*).
+/// If all vertices share the same weight, the regular triangulation is equal to the
+/// Delaunay triangulation of the same vertex set.
+///
+/// Vertices need to implement the [HasWeight] trait.
+///
+/// # Redundant vertices
+///
+/// In contrast to Delaunay triangulations, a vertex can be *redundant*: If its weight is small
+/// compared to the weights of its neighbors, its power cell becomes empty and the vertex will not be
+/// part of the triangulation. Inserting a vertex can also render vertices redundant that were
+/// inserted before.
+///
+/// Redundant vertices are not discarded. They can be inspected with
+/// [redundant_vertices](RegularTriangulation::redundant_vertices) and will be reconsidered once the
+/// vertex that covers them is removed.
+///
+/// Inserting a redundant vertex returns the handle of the vertex whose power cell contains the
+/// redundant vertex' position. Inserting a vertex at the position of an existing vertex replaces the
+/// existing vertex only if the new vertex has a larger weight - otherwise, the new vertex is
+/// redundant.
+///
+/// # Handle invalidation
+///
+/// Any insertion can invalidate vertex, edge and face handles as vertices that become redundant
+/// are removed from the triangulation.
+///
+/// # Example
+/// ```
+/// use spade::{HasPosition, HasWeight, Point2, RegularTriangulation, Triangulation};
+///
+/// # fn main() -> Result<(), spade::InsertionError> {
+/// struct WeightedPoint {
+///     position: Point2<f64>,
+///     weight: f64,
+/// }
+///
+/// impl HasPosition for WeightedPoint {
+///     type Scalar = f64;
+///
+///     fn position(&self) -> Point2<f64> {
+///         self.position
+///     }
+/// }
+///
+/// impl HasWeight for WeightedPoint {
+///     fn weight(&self) -> f64 {
+///         self.weight
+///     }
+/// }
+///
+/// let mut triangulation = RegularTriangulation::<WeightedPoint>::new();
+/// for (x, y) in [(0.0, 0.0), (2.0, 0.0), (0.0, 2.0), (2.0, 2.0)] {
+///     triangulation.insert(WeightedPoint { position: Point2::new(x, y), weight: 0.0 })?;
+/// }
+///
+/// // A vertex with a small weight in the center is covered by its neighbors...
+/// triangulation.insert(WeightedPoint { position: Point2::new(1.0, 1.0), weight: -2.0 })?;
+/// assert_eq!(triangulation.num_vertices(), 4);
+/// assert_eq!(triangulation.redundant_vertices().len(), 1);
+///
+/// // ...while a vertex with a large weight is part of the triangulation.
+/// triangulation.insert(WeightedPoint { position: Point2::new(1.0, 1.5), weight: 1.0 })?;
+/// assert_eq!(triangulation.num_vertices(), 5);
+/// # Ok(()) }
+/// ```
+///
+/// # Power diagram
+///
+/// The power diagram can be extracted with [power_cells](RegularTriangulation::power_cells) and
+/// [directed_power_edges](RegularTriangulation::directed_power_edges). Its handles work like the
+/// Voronoi handles of a Delaunay triangulation:
+///
+/// ```
+/// # use spade::{HasPosition, HasWeight, Point2, RegularTriangulation, Triangulation};
+/// # struct WeightedPoint { position: Point2<f64>, weight: f64 }
+/// # impl HasPosition for WeightedPoint {
+/// #     type Scalar = f64;
+/// #     fn position(&self) -> Point2<f64> { self.position }
+/// # }
+/// # impl HasWeight for WeightedPoint {
+/// #     fn weight(&self) -> f64 { self.weight }
+/// # }
+/// # fn main() -> Result<(), spade::InsertionError> {
+/// let mut triangulation = RegularTriangulation::<WeightedPoint>::new();
+/// for (x, y, weight) in [(0.0, 0.0, 1.0), (4.0, 0.0, 0.0), (0.0, 4.0, 0.0), (4.0, 4.0, 2.0)] {
+///     triangulation.insert(WeightedPoint { position: Point2::new(x, y), weight })?;
+/// }
+///
+/// let (lower, upper) = (Point2::new(-2.0, -2.0), Point2::new(6.0, 6.0));
+/// let mut total_area = 0.0;
+/// for cell in triangulation.power_cells() {
+///     for vertex in cell.vertices() {
+///         // Inner power vertices are the orthocenters of the triangulation's faces
+///         let _position: Option<Point2<f64>> = vertex.position();
+///     }
+///     total_area += cell.clipped_area(lower, upper);
+/// }
+/// assert!((total_area - 64.0).abs() < 1.0e-10);
+/// # Ok(()) }
+/// ```
+///
+/// # Numerical robustness
+///
+/// The predicate deciding whether a vertex lies within a triangle's orthogonal circle is exact,
+/// assuming that no intermediate result overflows or underflows. It first uses regular floating
+/// point arithmetic and falls back to slower exact arithmetic only for nearly degenerate inputs.
+/// See [crate::Triangulation] for more information on exact predicates.
+#[doc(alias = "Power diagram")]
+#[doc(alias = "Weighted Delaunay")]
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde")
+)]
+pub struct RegularTriangulation<V, DE = (), UE = (), F = (), L = LastUsedVertexHintGenerator>
+where
+    V: HasWeight,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+{
+    pub(crate) dcel: Dcel<V, DE, UE, F>,
+    pub(crate) hint_generator: L,
+    redundant_vertices: Vec<V>,
+    /// The vertices of the triangle, edge or vertex covering each redundant vertex. A redundant
+    /// vertex remains redundant as long as all of its covering vertices are present.
+    covering_vertices: Vec<[FixedVertexHandle; 3]>,
+    /// Maps each vertex index to the indices of the redundant vertices it covers.
+    covered: Vec<Vec<usize>>,
+}
+
+/// Describes how a vertex relates to an existing regular triangulation.
+enum RegularLocation {
+    /// The vertex is part of the regular triangulation.
+    Regular,
+    /// The vertex is covered by the power cell of another vertex. Also contains the vertices of
+    /// the triangle, edge or vertex that covers the vertex.
+    Redundant(FixedVertexHandle, [FixedVertexHandle; 3]),
+    /// The vertex has the same position but a larger weight than an existing vertex.
+    Replaces(FixedVertexHandle),
+}
+
+impl<V, DE, UE, F, L> RegularTriangulation<V, DE, UE, F, L>
+where
+    V: HasWeight,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+{
+    /// Returns all vertices that have been inserted but are not part of the triangulation.
+    ///
+    /// *See [RegularTriangulation#redundant-vertices]*
+    pub fn redundant_vertices(&self) -> &[V] {
+        &self.redundant_vertices
+    }
+
+    /// An iterator visiting all cells of the power diagram.
+    ///
+    /// Every cell belongs to the vertex with the same index. Redundant vertices have no cell.
+    ///
+    /// The iterator type is [PowerCell](crate::handles::PowerCell).
+    pub fn power_cells(&self) -> PowerCellIterator<'_, V, DE, UE, F> {
+        PowerCellIterator::new(&self.dcel)
+    }
+
+    /// An iterator visiting all directed edges of the power diagram.
+    ///
+    /// The iterator type is [DirectedPowerEdge](crate::handles::DirectedPowerEdge).
+    pub fn directed_power_edges(&self) -> DirectedPowerEdgeIterator<'_, V, DE, UE, F> {
+        DirectedPowerEdgeIterator::new(&self.dcel)
+    }
+
+    /// Returns the vertex with the smallest power distance to a position.
+    ///
+    /// The power distance between a vertex `v` and a position `p` is defined as
+    /// `|p - v|² - weight(v)`. The returned vertex is the vertex whose power cell contains `p`.
+    ///
+    /// Returns `None` if the triangulation is empty.
+    pub fn nearest_power_vertex(
+        &self,
+        position: Point2<<V as HasPosition>::Scalar>,
+    ) -> Option<VertexHandle<'_, V, DE, UE, F>> {
+        if self.num_vertices() == 0 {
+            return None;
+        }
+
+        let hint = self.hint_generator().get_hint(position);
+        let hint = self.validate_vertex_handle(hint);
+        let vertex = self.walk_to_nearest_power_vertex(hint, position);
+        self.hint_generator().notify_vertex_lookup(vertex);
+        Some(self.vertex(vertex))
+    }
+
+    fn walk_to_nearest_power_vertex(
+        &self,
+        start: FixedVertexHandle,
+        position: Point2<<V as HasPosition>::Scalar>,
+    ) -> FixedVertexHandle {
+        let power_distance = |vertex: &V| vertex.position().distance_2(position) - vertex.weight();
+
+        let mut current = self.vertex(start);
+        let mut current_distance = power_distance(current.data());
+        loop {
+            let mut closer = None;
+            for neighbor in current.out_edges().map(|edge| edge.to()) {
+                let neighbor_distance = power_distance(neighbor.data());
+                if neighbor_distance < current_distance {
+                    current_distance = neighbor_distance;
+                    closer = Some(neighbor);
+                }
+            }
+
+            match closer {
+                Some(closer) => current = closer,
+                None => return current.fix(),
+            }
+        }
+    }
+
+    fn locate_regular(&self, vertex: &V, hint: Option<FixedVertexHandle>) -> RegularLocation {
+        let position = vertex.position();
+        let weight = vertex.weight();
+
+        let redundant_if = |is_redundant: bool, covering_vertices: [FixedVertexHandle; 3]| {
+            if is_redundant {
+                let nearest = self.walk_to_nearest_power_vertex(covering_vertices[0], position);
+                RegularLocation::Redundant(nearest, covering_vertices)
+            } else {
+                RegularLocation::Regular
+            }
+        };
+
+        match self.locate_with_hint_option_core(position, hint) {
+            PositionInTriangulation::OnVertex(existing) => {
+                if weight > self.vertex(existing).data().weight() {
+                    RegularLocation::Replaces(existing)
+                } else {
+                    RegularLocation::Redundant(existing, [existing; 3])
+                }
+            }
+            PositionInTriangulation::OnEdge(edge) => {
+                let [from, to] = self.directed_edge(edge).vertices();
+                let is_redundant = math::is_redundant_on_segment(
+                    from.position(),
+                    to.position(),
+                    position,
+                    [from.data().weight(), to.data().weight(), weight],
+                );
+                redundant_if(is_redundant, [from.fix(), to.fix(), to.fix()])
+            }
+            PositionInTriangulation::OnFace(face) => {
+                let [v0, v1, v2] = self.face(face).vertices();
+                let is_redundant = !math::contained_in_power_circle(
+                    v0.position(),
+                    v1.position(),
+                    v2.position(),
+                    position,
+                    [
+                        v0.data().weight(),
+                        v1.data().weight(),
+                        v2.data().weight(),
+                        weight,
+                    ],
+                );
+                redundant_if(is_redundant, [v0.fix(), v1.fix(), v2.fix()])
+            }
+            PositionInTriangulation::OutsideOfConvexHull(_)
+            | PositionInTriangulation::NoTriangulation => RegularLocation::Regular,
+        }
+    }
+
+    fn insert_regular(
+        &mut self,
+        vertex: V,
+        hint: Option<FixedVertexHandle>,
+    ) -> Result<FixedVertexHandle, InsertionError> {
+        math::validate_vertex(&vertex)?;
+        math::validate_coordinate(vertex.weight())?;
+
+        match self.locate_regular(&vertex, hint) {
+            RegularLocation::Regular => {
+                let new_vertex = self.insert_with_hint_option(vertex, hint)?;
+                Ok(self.remove_redundant_neighbors(new_vertex))
+            }
+            RegularLocation::Redundant(nearest, covering_vertices) => {
+                self.push_redundant(vertex, covering_vertices);
+                Ok(nearest)
+            }
+            RegularLocation::Replaces(existing) => {
+                let replaced = core::mem::replace(self.vertex_data_mut(existing), vertex);
+                self.push_redundant(replaced, [existing; 3]);
+                // Increasing a vertex' weight can only invalidate the edges opposite to it
+                self.legalize_vertex(existing);
+                Ok(self.remove_redundant_neighbors(existing))
+            }
+        }
+    }
+
+    /// Removes all neighbors of a vertex that became redundant after it was inserted.
+    ///
+    /// Every redundant neighbor can be reduced to a degree 3 vertex by flipping. Since these flips
+    /// have already been applied by the regular edge legalization, it suffices to look for
+    /// neighbors with degree 3 (or degree 2 if all vertices are collinear).
+    ///
+    /// Returns the (possibly changed) handle of the given vertex.
+    fn remove_redundant_neighbors(&mut self, mut vertex: FixedVertexHandle) -> FixedVertexHandle {
+        // Vertices covered by a removed neighbor remain redundant but need to be located again
+        let mut uncovered = Vec::new();
+        loop {
+            let redundant_neighbor = self
+                .vertex(vertex)
+                .out_edges()
+                .map(|edge| edge.to().fix())
+                .find(|neighbor| self.is_redundant_vertex(*neighbor));
+
+            let Some(redundant_neighbor) = redundant_neighbor else {
+                break;
+            };
+
+            uncovered.extend(self.take_covered(redundant_neighbor));
+            let mut covering_vertices = [vertex; 3];
+            for (covering, neighbor) in covering_vertices
+                .iter_mut()
+                .zip(self.vertex(redundant_neighbor).out_edges())
+            {
+                *covering = neighbor.to().fix();
+            }
+
+            let position = self.vertex(redundant_neighbor).position();
+            let removal_result = self.remove_core(redundant_neighbor);
+            self.notify_removal(
+                redundant_neighbor,
+                position,
+                removal_result.swapped_in_vertex,
+            );
+
+            if let Some(swapped_in) = removal_result.swapped_in_vertex {
+                for covering in &mut covering_vertices {
+                    if *covering == swapped_in {
+                        *covering = redundant_neighbor;
+                    }
+                }
+            }
+            if removal_result.swapped_in_vertex == Some(vertex) {
+                vertex = redundant_neighbor;
+            }
+            self.push_redundant(removal_result.removed_vertex, covering_vertices);
+
+            if !self.all_vertices_on_line() {
+                self.legalize_vertex(vertex);
+            }
+        }
+
+        for redundant in uncovered {
+            self.insert_regular(redundant, None)
+                .expect("Redundant vertices have been validated before. This is a bug in spade.");
+        }
+        vertex
+    }
+
+    fn is_redundant_vertex(&self, vertex: FixedVertexHandle) -> bool {
+        let vertex = self.vertex(vertex);
+        let neighbors = vertex
+            .out_edges()
+            .map(|edge| edge.to())
+            .collect::<smallvec::SmallVec<[_; 4]>>();
+
+        if self.all_vertices_on_line() {
+            if let [from, to] = *neighbors {
+                return math::is_redundant_on_segment(
+                    from.position(),
+                    to.position(),
+                    vertex.position(),
+                    [
+                        from.data().weight(),
+                        to.data().weight(),
+                        vertex.data().weight(),
+                    ],
+                );
+            }
+            return false;
+        }
+
+        if vertex.out_edges().any(|edge| edge.is_outer_edge()) {
+            return false;
+        }
+
+        if let [v0, v1, v2] = *neighbors {
+            debug_assert!(math::is_ordered_ccw(
+                v0.position(),
+                v1.position(),
+                v2.position()
+            ));
+            !math::contained_in_power_circle(
+                v0.position(),
+                v1.position(),
+                v2.position(),
+                vertex.position(),
+                [
+                    v0.data().weight(),
+                    v1.data().weight(),
+                    v2.data().weight(),
+                    vertex.data().weight(),
+                ],
+            )
+        } else {
+            false
+        }
+    }
+
+    /// Reduces the degree of an inner vertex to three by flipping its adjacent edges.
+    ///
+    /// The fan triangulation used when removing vertices from Delaunay triangulations relies on
+    /// the flip algorithm to resolve any invalid triangles. This does not work for regular
+    /// triangulations as some illegal edges cannot be flipped. Instead, the removed vertex'
+    /// neighborhood is shrunk by repeatedly cutting off the ear whose lifted plane passes lowest
+    /// below the lifted vertex. Such an ear is always part of the final triangulation.
+    ///
+    /// For more details, refer to
+    /// Olivier Devillers. On Deletion in Delaunay Triangulations.
+    /// <https://doi.org/10.1142/S0218195902000773>
+    fn flip_to_degree_three(&mut self, vertex: FixedVertexHandle) {
+        loop {
+            let handle = self.vertex(vertex);
+            if handle.out_edges().nth(3).is_none() {
+                return;
+            }
+
+            let mut best_ear = None;
+            for edge in handle.out_edges() {
+                let prev = edge.cw().to();
+                let ear = edge.to();
+                let next = edge.ccw().to();
+                let [p0, p1, p2] = [prev, ear, next].map(|v| v.position());
+
+                let is_convex = math::side_query(p0, p1, p2).is_on_left_side();
+                if !is_convex || !math::side_query(p0, p2, handle.position()).is_on_left_side() {
+                    continue;
+                }
+
+                let [q0, q1, q2] = [p0, p1, p2].map(|p| p.to_f64());
+                let area = q1.sub(q0).x * q2.sub(q0).y - q1.sub(q0).y * q2.sub(q0).x;
+                let priority = math::power_circle_determinant(
+                    p0,
+                    p1,
+                    p2,
+                    handle.position(),
+                    [prev, ear, next, handle].map(|v| v.data().weight()),
+                ) / area;
+
+                let is_better = match best_ear {
+                    Some((_, best_priority)) => priority < best_priority,
+                    None => true,
+                };
+                if is_better {
+                    best_ear = Some((edge.fix(), priority));
+                }
+            }
+
+            let (edge, _) = best_ear.expect("Failed to find an ear. This is a bug in spade.");
+            dcel_operations::flip_cw(self.s_mut(), edge.as_undirected());
+        }
+    }
+
+    fn covered_mut(&mut self, vertex: FixedVertexHandle) -> &mut Vec<usize> {
+        if self.covered.len() <= vertex.index() {
+            self.covered.resize_with(vertex.index() + 1, Vec::new);
+        }
+        &mut self.covered[vertex.index()]
+    }
+
+    fn push_redundant(&mut self, vertex: V, covering_vertices: [FixedVertexHandle; 3]) {
+        let index = self.redundant_vertices.len();
+        self.redundant_vertices.push(vertex);
+        self.covering_vertices.push(covering_vertices);
+        for covering in distinct(covering_vertices) {
+            self.covered_mut(covering).push(index);
+        }
+    }
+
+    /// Removes and returns all redundant vertices covered by the given vertex.
+    fn take_covered(&mut self, vertex: FixedVertexHandle) -> Vec<V> {
+        let mut indices = core::mem::take(self.covered_mut(vertex));
+        // Removing from the back ensures that swap_remove only moves vertices that are kept
+        indices.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut result = Vec::with_capacity(indices.len());
+        for index in indices {
+            let last = self.redundant_vertices.len() - 1;
+            for covering in distinct(self.covering_vertices[index]) {
+                if covering != vertex {
+                    self.covered[covering.index()].retain(|other| *other != index);
+                }
+            }
+
+            result.push(self.redundant_vertices.swap_remove(index));
+            self.covering_vertices.swap_remove(index);
+
+            if index != last {
+                for covering in distinct(self.covering_vertices[index]) {
+                    for other in &mut self.covered[covering.index()] {
+                        if *other == last {
+                            *other = index;
+                        }
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+/// Iterates over the distinct vertices of a covering triangle, edge or vertex.
+fn distinct(covering_vertices: [FixedVertexHandle; 3]) -> impl Iterator<Item = FixedVertexHandle> {
+    covering_vertices
+        .into_iter()
+        .enumerate()
+        .filter(move |(index, vertex)| !covering_vertices[..*index].contains(vertex))
+        .map(|(_, vertex)| vertex)
+}
+
+impl<V, DE, UE, F, L> Default for RegularTriangulation<V, DE, UE, F, L>
+where
+    V: HasWeight,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+{
+    fn default() -> Self {
+        Self {
+            dcel: Default::default(),
+            hint_generator: Default::default(),
+            redundant_vertices: Vec::new(),
+            covering_vertices: Vec::new(),
+            covered: Vec::new(),
+        }
+    }
+}
+
+impl<V, DE, UE, F, L> Triangulation for RegularTriangulation<V, DE, UE, F, L>
+where
+    V: HasWeight,
+    DE: Default,
+    UE: Default,
+    F: Default,
+    L: HintGenerator<<V as HasPosition>::Scalar>,
+{
+    type Vertex = V;
+    type DirectedEdge = DE;
+    type UndirectedEdge = UE;
+    type Face = F;
+    type HintGenerator = L;
+
+    fn s(&self) -> &Dcel<V, DE, UE, F> {
+        &self.dcel
+    }
+
+    fn s_mut(&mut self) -> &mut Dcel<V, DE, UE, F> {
+        &mut self.dcel
+    }
+
+    fn contained_in_circumference(
+        &self,
+        v1: FixedVertexHandle,
+        v2: FixedVertexHandle,
+        v3: FixedVertexHandle,
+        p: FixedVertexHandle,
+    ) -> bool {
+        let [v1, v2, v3, p] = [v1, v2, v3, p].map(|v| self.vertex(v));
+        let [p1, p2, p3, pp] = [v1, v2, v3, p].map(|v| v.position());
+
+        if !math::contained_in_power_circle(
+            p1,
+            p2,
+            p3,
+            pp,
+            [v1, v2, v3, p].map(|v| v.data().weight()),
+        ) {
+            return false;
+        }
+
+        // In contrast to Delaunay triangulations, an illegal edge may be adjacent to a non-convex
+        // quad. Flipping is only possible if `p` lies on the left side of the two triangle edges
+        // which it doesn't share with the triangle.
+        [(p1, p2), (p2, p3), (p3, p1)]
+            .into_iter()
+            .filter(|(from, to)| math::side_query(*from, *to, pp).is_on_left_side())
+            .count()
+            == 2
+    }
+
+    fn handle_vertex_removal(
+        &mut self,
+        removed: FixedVertexHandle,
+        swapped_in: Option<FixedVertexHandle>,
+    ) {
+        debug_assert!(self
+            .covered
+            .get(removed.index())
+            .into_iter()
+            .all(Vec::is_empty));
+
+        let Some(swapped_in) = swapped_in else {
+            return;
+        };
+        let moved = core::mem::take(self.covered_mut(swapped_in));
+        for index in &moved {
+            for covering in &mut self.covering_vertices[*index] {
+                if *covering == swapped_in {
+                    *covering = removed;
+                }
+            }
+        }
+        *self.covered_mut(removed) = moved;
+    }
+
+    fn handle_vertex_remapping(&mut self, remapping: &HandleRemapping) {
+        self.covered.clear();
+        for index in 0..self.covering_vertices.len() {
+            let covering_vertices = self.covering_vertices[index].map(|covering| {
+                remapping
+                    .vertex(covering)
+                    .expect("Covering vertices cannot be removed. This is a bug in spade.")
+            });
+            self.covering_vertices[index] = covering_vertices;
+            for covering in distinct(covering_vertices) {
+                self.covered_mut(covering).push(index);
+            }
+        }
+    }
+
+    fn hint_generator(&self) -> &Self::HintGenerator {
+        &self.hint_generator
+    }
+
+    fn hint_generator_mut(&mut self) -> &mut Self::HintGenerator {
+        &mut self.hint_generator
+    }
+
+    fn from_parts(
+        dcel: Dcel<Self::Vertex, Self::DirectedEdge, Self::UndirectedEdge, Self::Face>,
+        hint_generator: Self::HintGenerator,
+        num_constraints: usize,
+    ) -> Self {
+        assert_eq!(num_constraints, 0);
+        Self {
+            dcel,
+            hint_generator,
+            redundant_vertices: Vec::new(),
+            covering_vertices: Vec::new(),
+            covered: Vec::new(),
+        }
+    }
+
+    fn into_parts(
+        self,
+    ) -> (
+        Dcel<Self::Vertex, Self::DirectedEdge, Self::UndirectedEdge, Self::Face>,
+        Self::HintGenerator,
+        usize,
+    ) {
+        (self.dcel, self.hint_generator, 0)
+    }
+
+    fn clear(&mut self) {
+        self.s_mut().clear();
+        self.redundant_vertices.clear();
+        self.covering_vertices.clear();
+        self.covered.clear();
+        let new_hint_generator = HintGenerator::initialize_from_triangulation(self);
+        *self.hint_generator_mut() = new_hint_generator;
+    }
+
+    /// Creates a new regular triangulation populated with some vertices.
+    ///
+    /// Vertices are sorted along a space filling curve and then inserted incrementally.
+    fn bulk_load(mut elements: Vec<Self::Vertex>) -> Result<Self, InsertionError> {
+        for element in &elements {
+            math::validate_vertex(element)?;
+            math::validate_coordinate(element.weight())?;
+        }
+
+        hilbert_sort(&mut elements);
+
+        let mut result =
+            Self::with_capacity(elements.len(), elements.len() * 3, elements.len() * 2);
+        let mut hint = None;
+        for element in elements {
+            hint = Some(result.insert_regular(element, hint)?);
+        }
+        *result.hint_generator_mut() = Self::HintGenerator::initialize_from_triangulation(&result);
+        Ok(result)
+    }
+
+    fn insert(&mut self, vertex: Self::Vertex) -> Result<FixedVertexHandle, InsertionError> {
+        self.insert_regular(vertex, None)
+    }
+
+    fn insert_with_hint(
+        &mut self,
+        vertex: Self::Vertex,
+        hint: FixedVertexHandle,
+    ) -> Result<FixedVertexHandle, InsertionError> {
+        self.insert_regular(vertex, Some(hint))
+    }
+
+    fn remove(&mut self, vertex: FixedVertexHandle) -> Self::Vertex {
+        let is_inner_vertex = !self.all_vertices_on_line()
+            && !self
+                .vertex(vertex)
+                .out_edges()
+                .any(|edge| edge.is_outer_edge());
+        if is_inner_vertex {
+            self.flip_to_degree_three(vertex);
+        }
+
+        let uncovered = self.take_covered(vertex);
+        let result = self.remove_and_notify(vertex);
+        for redundant in uncovered {
+            self.insert_regular(redundant, None)
+                .expect("Redundant vertices have been validated before. This is a bug in spade.");
+        }
+        result
+    }
+
+    fn locate_and_remove(
+        &mut self,
+        point: Point2<<Self::Vertex as HasPosition>::Scalar>,
+    ) -> Option<Self::Vertex> {
+        match self.locate_with_hint_option_core(point, None) {
+            PositionInTriangulation::OnVertex(handle) => Some(self.remove(handle)),
+            _ => None,
+        }
+    }
+
+    /// Moves a vertex to a new position.
+    ///
+    /// In contrast to other triangulations, the vertex is always removed and re-inserted. Moving
+    /// a vertex can turn other vertices redundant (or not redundant anymore). The returned handle
+    /// may therefore differ from `vertex`. If the moved vertex becomes redundant, the handle of
+    /// the vertex covering it is returned.
+    ///
+    /// *See [Triangulation::move_vertex]*
+    ///
+    /// # Handle invalidation
+    /// This method will invalidate all vertex, edge and face handles.
+    fn move_vertex(
+        &mut self,
+        vertex: FixedVertexHandle,
+        new_position: Point2<<Self::Vertex as HasPosition>::Scalar>,
+    ) -> Result<FixedVertexHandle, MoveVertexError>
+    where
+        Self::Vertex: HasPositionMut,
+    {
+        math::validate_vertex(&new_position)?;
+        self.check_position_is_free(vertex, new_position)?;
+        let mut data = self.remove(vertex);
+        data.set_position(new_position);
+        Ok(self.insert(data)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::delaunay_core::math;
+    use crate::test_utilities::{random_points_with_seed, SEED, SEED2};
+    use crate::{
+        DelaunayTriangulation, HasPosition, HasPositionMut, HasWeight, InsertionError,
+        MoveVertexError, Point2, PositionInTriangulation, RegularTriangulation, Triangulation,
+        TriangulationExt,
+    };
+
+    use alloc::vec::Vec;
+    use rand::distr::{Distribution, Uniform};
+    use rand::SeedableRng;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct WeightedPoint {
+        position: Point2<f64>,
+        weight: f64,
+    }
+
+    impl WeightedPoint {
+        fn new(x: f64, y: f64, weight: f64) -> Self {
+            Self {
+                position: Point2::new(x, y),
+                weight,
+            }
+        }
+    }
+
+    impl HasPosition for WeightedPoint {
+        type Scalar = f64;
+
+        fn position(&self) -> Point2<f64> {
+            self.position
+        }
+    }
+
+    impl HasWeight for WeightedPoint {
+        fn weight(&self) -> f64 {
+            self.weight
+        }
+    }
+
+    impl HasPositionMut for WeightedPoint {
+        fn set_position(&mut self, position: Point2<f64>) {
+            self.position = position;
+        }
+    }
+
+    type Rt = RegularTriangulation<WeightedPoint>;
+
+    fn random_weighted_points(size: usize, seed: &[u8; 32]) -> Vec<WeightedPoint> {
+        let mut rng = rand::rngs::StdRng::from_seed(*seed);
+        let range = Uniform::new(0.0, 0.01).unwrap();
+        random_points_with_seed(size, seed)
+            .into_iter()
+            .map(|p| WeightedPoint {
+                position: p,
+                weight: range.sample(&mut rng),
+            })
+            .collect()
+    }
+
+    fn check_regularity(triangulation: &Rt) {
+        triangulation.basic_sanity_check(true);
+
+        for edge in triangulation.undirected_edges() {
+            let edge = edge.as_directed();
+            if let (Some(left), Some(right)) =
+                (edge.opposite_vertex(), edge.rev().opposite_vertex())
+            {
+                let [from, to] = edge.vertices();
+                assert!(!math::contained_in_power_circle(
+                    from.position(),
+                    to.position(),
+                    left.position(),
+                    right.position(),
+                    [from, to, left, right].map(|v| v.data().weight()),
+                ));
+            }
+        }
+
+        for redundant in triangulation.redundant_vertices() {
+            let position = redundant.position();
+            match triangulation.locate(position) {
+                PositionInTriangulation::OnVertex(vertex) => {
+                    assert!(triangulation.vertex(vertex).data().weight() >= redundant.weight())
+                }
+                PositionInTriangulation::OnEdge(edge) => {
+                    let [from, to] = triangulation.directed_edge(edge).vertices();
+                    assert!(math::is_redundant_on_segment(
+                        from.position(),
+                        to.position(),
+                        position,
+                        [from.data().weight(), to.data().weight(), redundant.weight()],
+                    ));
+                }
+                PositionInTriangulation::OnFace(face) => {
+                    let [v0, v1, v2] = triangulation.face(face).vertices();
+                    assert!(!math::contained_in_power_circle(
+                        v0.position(),
+                        v1.position(),
+                        v2.position(),
+                        position,
+                        [
+                            v0.data().weight(),
+                            v1.data().weight(),
+                            v2.data().weight(),
+                            redundant.weight(),
+                        ],
+                    ));
+                }
+                _ => panic!("Redundant vertices must lie within the convex hull"),
+            }
+        }
+
+        assert_eq!(
+            triangulation.covering_vertices.len(),
+            triangulation.redundant_vertices.len()
+        );
+        for (index, covering_vertices) in triangulation.covering_vertices.iter().enumerate() {
+            for covering in covering_vertices {
+                assert!(triangulation.s().is_vertex_live(*covering));
+                assert!(triangulation.covered[covering.index()].contains(&index));
+            }
+        }
+        let num_covered = triangulation.covered.iter().map(Vec::len).sum::<usize>();
+        let expected = triangulation
+            .covering_vertices
+            .iter()
+            .map(|covering| super::distinct(*covering).count())
+            .sum::<usize>();
+        assert_eq!(num_covered, expected);
+    }
+
+    #[test]
+    fn test_equal_weights_match_delaunay() -> Result<(), InsertionError> {
+        let points = random_points_with_seed(200, SEED);
+        let mut regular = Rt::new();
+        for p in &points {
+            regular.insert(WeightedPoint::new(p.x, p.y, 2.0))?;
+        }
+        let delaunay = DelaunayTriangulation::<_>::bulk_load(points)?;
+
+        check_regularity(&regular);
+        assert!(regular.redundant_vertices().is_empty());
+        assert_eq!(regular.num_vertices(), delaunay.num_vertices());
+        assert_eq!(regular.num_inner_faces(), delaunay.num_inner_faces());
+
+        for edge in delaunay.undirected_edges() {
+            let [from, to] = edge.positions();
+            let from = regular.locate_vertex(from).unwrap().fix();
+            let to = regular.locate_vertex(to).unwrap().fix();
+            assert!(regular.get_edge_from_neighbors(from, to).is_some());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_redundant_vertex() -> Result<(), InsertionError> {
+        let mut triangulation = Rt::new();
+        for (x, y) in [(0.0, 0.0), (2.0, 0.0), (0.0, 2.0), (2.0, 2.0)] {
+            triangulation.insert(WeightedPoint::new(x, y, 0.0))?;
+        }
+
+        let covering = triangulation.insert(WeightedPoint::new(0.5, 0.5, -2.0))?;
+        assert_eq!(triangulation.num_vertices(), 4);
+        assert_eq!(triangulation.redundant_vertices().len(), 1);
+        assert_eq!(
+            triangulation.vertex(covering).position(),
+            Point2::new(0.0, 0.0)
+        );
+
+        // Same position but smaller weight
+        triangulation.insert(WeightedPoint::new(2.0, 2.0, -1.0))?;
+        assert_eq!(triangulation.num_vertices(), 4);
+        assert_eq!(triangulation.redundant_vertices().len(), 2);
+
+        // Same position and larger weight
+        let replaced = triangulation.insert(WeightedPoint::new(2.0, 2.0, 1.0))?;
+        assert_eq!(triangulation.vertex(replaced).data().weight(), 1.0);
+        assert_eq!(triangulation.num_vertices(), 4);
+        assert_eq!(triangulation.redundant_vertices().len(), 3);
+        check_regularity(&triangulation);
+        Ok(())
+    }
+
+    #[test]
+    fn test_vertex_becomes_redundant() -> Result<(), InsertionError> {
+        let mut triangulation = Rt::new();
+        for (x, y) in [(0.0, 0.0), (4.0, 0.0), (0.0, 4.0), (4.0, 4.0), (2.0, 2.0)] {
+            triangulation.insert(WeightedPoint::new(x, y, 0.0))?;
+        }
+        assert_eq!(triangulation.num_vertices(), 5);
+
+        let heavy = triangulation.insert(WeightedPoint::new(2.1, 2.0, 4.0))?;
+        assert_eq!(triangulation.num_vertices(), 5);
+        assert_eq!(triangulation.redundant_vertices().len(), 1);
+        assert_eq!(
+            triangulation.redundant_vertices()[0].position(),
+            Point2::new(2.0, 2.0)
+        );
+        assert_eq!(
+            triangulation.vertex(heavy).position(),
+            Point2::new(2.1, 2.0)
+        );
+        check_regularity(&triangulation);
+
+        // Removing the heavy vertex reveals the redundant vertex again
+        triangulation.remove(heavy);
+        assert_eq!(triangulation.num_vertices(), 5);
+        assert!(triangulation.redundant_vertices().is_empty());
+        check_regularity(&triangulation);
+        Ok(())
+    }
+
+    #[test]
+    fn test_collinear_redundant_vertices() -> Result<(), InsertionError> {
+        let mut triangulation = Rt::new();
+        for x in [0.0, 1.0, 2.0, 3.0] {
+            triangulation.insert(WeightedPoint::new(x, 0.0, 0.0))?;
+        }
+        triangulation.insert(WeightedPoint::new(1.5, 0.0, 2.0))?;
+        assert!(triangulation.all_vertices_on_line());
+        assert_eq!(triangulation.num_vertices(), 3);
+        assert_eq!(triangulation.redundant_vertices().len(), 2);
+        triangulation.basic_sanity_check(true);
+        Ok(())
+    }
+
+    #[test]
+    fn test_random_insertion() -> Result<(), InsertionError> {
+        let mut triangulation = Rt::new();
+        for vertex in random_weighted_points(1000, SEED) {
+            triangulation.insert(vertex)?;
+        }
+        assert!(!triangulation.redundant_vertices().is_empty());
+        assert_eq!(
+            triangulation.num_vertices() + triangulation.redundant_vertices().len(),
+            1000
+        );
+        check_regularity(&triangulation);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bulk_load() -> Result<(), InsertionError> {
+        let vertices = random_weighted_points(1000, SEED2);
+        let bulk_loaded = Rt::bulk_load(vertices.clone())?;
+        check_regularity(&bulk_loaded);
+
+        let mut incremental = Rt::new();
+        for vertex in vertices {
+            incremental.insert(vertex)?;
+        }
+        assert_eq!(bulk_loaded.num_vertices(), incremental.num_vertices());
+        assert_eq!(bulk_loaded.num_inner_faces(), incremental.num_inner_faces());
+        Ok(())
+    }
+
+    #[test]
+    fn test_random_removal() -> Result<(), InsertionError> {
+        let mut triangulation = Rt::bulk_load(random_weighted_points(300, SEED))?;
+        let total = triangulation.num_vertices() + triangulation.redundant_vertices().len();
+        let mut removed = 0;
+        while triangulation.num_vertices() > 100 {
+            let vertex = crate::handles::FixedVertexHandle::from_index(
+                (removed * 7) % triangulation.num_vertices(),
+            );
+            triangulation.remove(vertex);
+            removed += 1;
+            check_regularity(&triangulation);
+        }
+        assert_eq!(
+            triangulation.num_vertices() + triangulation.redundant_vertices().len() + removed,
+            total
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_removal_with_stable_handles() -> Result<(), InsertionError> {
+        let mut triangulation = Rt::bulk_load(random_weighted_points(300, SEED))?;
+        triangulation.set_stable_handles(true);
+        let total = triangulation.num_vertices() + triangulation.redundant_vertices().len();
+        let mut removed = 0;
+        let vertices = triangulation.fixed_vertices().collect::<Vec<_>>();
+        for vertex in vertices.into_iter().step_by(3) {
+            if triangulation.s().is_vertex_live(vertex) {
+                triangulation.remove(vertex);
+                removed += 1;
+                check_regularity(&triangulation);
+            }
+        }
+
+        triangulation.compact();
+        check_regularity(&triangulation);
+        triangulation.set_stable_handles(false);
+        while triangulation.num_vertices() > 50 {
+            triangulation.remove(crate::handles::FixedVertexHandle::from_index(0));
+            removed += 1;
+            check_regularity(&triangulation);
+        }
+        assert_eq!(
+            triangulation.num_vertices() + triangulation.redundant_vertices().len() + removed,
+            total
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_vertex() -> Result<(), MoveVertexError> {
+        let mut triangulation = Rt::bulk_load(random_weighted_points(100, SEED))?;
+        let total = triangulation.num_vertices() + triangulation.redundant_vertices().len();
+        let mut rng = rand::rngs::StdRng::from_seed(*SEED2);
+        let range = Uniform::new(-1.0, 1.0).unwrap();
+        for index in 0..50 {
+            let vertex = crate::handles::FixedVertexHandle::from_index(
+                (index * 7) % triangulation.num_vertices(),
+            );
+            let new_position = Point2::new(range.sample(&mut rng), range.sample(&mut rng));
+            let mut expected = *triangulation.vertex(vertex).data();
+            expected.position = new_position;
+            triangulation.move_vertex(vertex, new_position)?;
+            assert!(
+                triangulation.vertices().any(|v| *v.data() == expected)
+                    || triangulation.redundant_vertices().contains(&expected)
+            );
+            check_regularity(&triangulation);
+        }
+        assert_eq!(
+            triangulation.num_vertices() + triangulation.redundant_vertices().len(),
+            total
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_weight() {
+        let mut triangulation = Rt::new();
+        assert_eq!(
+            triangulation.insert(WeightedPoint::new(0.0, 0.0, f64::NAN)),
+            Err(InsertionError::NAN)
+        );
+        assert_eq!(triangulation.num_vertices(), 0);
+    }
+
+    #[test]
+    fn test_nearest_power_vertex() -> Result<(), InsertionError> {
+        let vertices = random_weighted_points(100, SEED);
+        let triangulation = Rt::bulk_load(vertices.clone())?;
+        for query in random_points_with_seed(100, SEED2) {
+            let nearest = triangulation.nearest_power_vertex(query).unwrap();
+            let power = |v: &WeightedPoint| v.position.distance_2(query) - v.weight;
+            let expected = vertices.iter().map(power).fold(f64::INFINITY, f64::min);
+            assert_eq!(power(nearest.data()), expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_power_cells() -> Result<(), InsertionError> {
+        let triangulation = Rt::bulk_load(random_weighted_points(300, SEED))?;
+        let (lower, upper) = (Point2::new(-0.8, -0.9), Point2::new(0.7, 0.6));
+
+        let mut total_area = 0.0;
+        let mut num_bounded = 0;
+        for cell in triangulation.power_cells() {
+            let vertex = cell.as_delaunay_vertex();
+            let polygon = cell.clipped_polygon(lower, upper);
+            for point in &polygon {
+                // Every point of the cell must be closest to the cell's vertex
+                let nearest = triangulation.nearest_power_vertex(*point).unwrap();
+                let power = |v: &WeightedPoint| v.position.distance_2(*point) - v.weight;
+                assert!(power(vertex.data()) - power(nearest.data()) < 1.0e-10);
+            }
+            total_area += cell.clipped_area(lower, upper);
+
+            if let Some(area) = cell.area() {
+                num_bounded += 1;
+                let huge = Point2::new(1.0e3, 1.0e3);
+                let clipped_area = cell.clipped_area(huge.mul(-1.0), huge);
+                assert!((area - clipped_area).abs() < 1.0e-10);
+            }
+            assert_eq!(
+                cell.is_bounded(),
+                cell.vertices().all(|v| v.position().is_some())
+            );
+        }
+        assert!(num_bounded > 0);
+        assert!((total_area - 1.5 * 1.5).abs() < 1.0e-10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_power_cells_with_swapped_corners() -> Result<(), InsertionError> {
+        let triangulation = Rt::bulk_load(random_weighted_points(50, SEED))?;
+        let (lower, upper) = (Point2::new(-1.0, -1.0), Point2::new(3.0, 1.0));
+        for cell in triangulation.power_cells() {
+            let expected = cell.clipped_polygon(lower, upper);
+            for (lower, upper) in [
+                (upper, lower),
+                (Point2::new(3.0, -1.0), Point2::new(-1.0, 1.0)),
+                (Point2::new(-1.0, 1.0), Point2::new(3.0, -1.0)),
+            ] {
+                assert_eq!(cell.clipped_polygon(lower, upper), expected);
+            }
+            for [p0, p1, p2] in expected.windows(3).map(|w| [w[0], w[1], w[2]]) {
+                assert!(!math::side_query(p0, p1, p2).is_on_right_side());
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_power_vertices() -> Result<(), InsertionError> {
+        let triangulation = Rt::bulk_load(random_weighted_points(100, SEED2))?;
+        for face in triangulation.inner_faces() {
+            let center = face.orthocenter();
+            let [d0, d1, d2] = face
+                .vertices()
+                .map(|v| v.position().distance_2(center) - v.data().weight());
+            assert!((d0 - d1).abs() < 1.0e-10);
+            assert!((d0 - d2).abs() < 1.0e-10);
+            assert!((d0 - face.orthocircle().1).abs() < 1.0e-10);
+        }
+
+        for edge in triangulation.directed_power_edges() {
+            assert!(edge.rev().rev() == edge);
+            assert_eq!(
+                edge.face().as_delaunay_vertex(),
+                edge.as_delaunay_edge().from()
+            );
+            assert!(edge.next().prev() == edge);
+            if let Some(face) = edge.from().as_delaunay_face() {
+                assert!(edge.from().out_edges().unwrap().contains(&edge));
+                assert_eq!(edge.from().position(), Some(face.orthocenter()));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,686 @@
+use alloc::vec::Vec;
+
+use num_traits::{Float, NumCast, ToPrimitive, Zero};
+
+use crate::delaunay_core::{hilbert_sort, math};
+use crate::handles::{CheckedFaceHandle, FixedFaceHandle, FixedVertexHandle, InnerTag};
+use crate::{
+    validate_vertex, ConstrainedDelaunayTriangulation, HasPosition, InsertionError, Point2,
+    Triangulation,
+};
+
+/// A triangle of a [StreamingTriangulation] that will not change anymore.
+///
+/// *See [StreamingTriangulation::finalize_cell]*
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FinalizedTriangle<'a, V> {
+    /// The triangle's vertices in counterclockwise order.
+    pub vertices: [&'a V; 3],
+    /// The insertion indices of the triangle's vertices.
+    ///
+    /// Vertices are numbered consecutively across all inserted chunks, starting at 0.
+    pub indices: [usize; 3],
+}
+
+/// The vertex type of the internal triangulation, remembering the insertion index.
+#[derive(Debug, Clone)]
+struct StreamingVertex<V> {
+    data: V,
+    index: usize,
+}
+
+impl<V: HasPosition> HasPosition for StreamingVertex<V> {
+    type Scalar = V::Scalar;
+
+    fn position(&self) -> Point2<Self::Scalar> {
+        self.data.position()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum FaceState {
+    /// Fills the gap left behind by removed vertices. Not part of the streamed triangulation.
+    ///
+    /// This is the default as faces created by vertex removal are initialized with it.
+    #[default]
+    Filler,
+    /// Part of the Delaunay triangulation of all inserted vertices but not final yet.
+    Active,
+    /// Final and already reported. Kept until all of its vertices can be removed.
+    Emitted,
+}
+
+/// Triangulates point sets that are too large to be kept in memory at once.
+///
+/// The input domain is an axis-aligned rectangle that is subdivided into a grid of cells.
+/// Vertices are inserted in chunks, e.g. one chunk per tile of a large terrain data set.
+/// Once all vertices of a cell have been inserted, the cell is _finalized_. No vertices may be
+/// inserted into a finalized cell anymore. This follows the finalization tags of Isenburg et al.
+/// ("Streaming Computation of Delaunay Triangulations", 2006).
+///
+/// A triangle is final if its circumcircle lies within finalized cells - no vertex inserted later
+/// can be contained in it. Final triangles are reported once and their vertices are removed from
+/// memory as soon as all of their adjacent triangles have been reported. The boundary of the
+/// removed area is kept as constraint edges to prevent later insertions from "looking through" it.
+/// Only the triangles along the front between finalized and non-finalized cells need to be kept
+/// in memory.
+///
+/// The reported triangles are exactly the triangles of the Delaunay triangulation of all inserted
+/// vertices. Vertices with identical positions are merged (the later vertex replaces the earlier
+/// one).
+///
+/// # Example
+/// ```
+/// # fn main() -> Result<(), spade::InsertionError> {
+/// use spade::{DelaunayTriangulation, Point2, StreamingTriangulation, Triangulation};
+///
+/// let mut streaming = StreamingTriangulation::new(Point2::new(0.0, 0.0), Point2::new(2.0, 1.0), 2, 1);
+///
+/// let mut triangles = Vec::new();
+/// // Tile 0 covers [0, 1] x [0, 1]
+/// let tile0 = vec![
+///     Point2::new(0.0, 0.0),
+///     Point2::new(0.9, 0.1),
+///     Point2::new(0.1, 0.9),
+///     Point2::new(0.5, 0.4),
+/// ];
+/// streaming.insert_chunk(tile0.clone())?;
+/// streaming.finalize_cell([0, 0], |triangle| triangles.push(triangle.indices));
+///
+/// // Tile 1 covers [1, 2] x [0, 1]
+/// let tile1 = vec![Point2::new(1.5, 0.5), Point2::new(2.0, 1.0)];
+/// streaming.insert_chunk(tile1.clone())?;
+/// streaming.finish(|triangle| triangles.push(triangle.indices));
+///
+/// let all_vertices = [tile0, tile1].concat();
+/// let triangulation = DelaunayTriangulation::<_>::bulk_load(all_vertices)?;
+/// assert_eq!(triangles.len(), triangulation.num_inner_faces());
+/// assert_eq!(streaming.num_vertices(), 0);
+/// # Ok(()) }
+/// ```
+pub struct StreamingTriangulation<V>
+where
+    V: HasPosition,
+    V::Scalar: Float,
+{
+    triangulation: ConstrainedDelaunayTriangulation<StreamingVertex<V>, (), (), FaceState>,
+    lower: Point2<V::Scalar>,
+    upper: Point2<V::Scalar>,
+    num_cells: [usize; 2],
+    finalized: Vec<bool>,
+    num_finalized: usize,
+    num_inserted: usize,
+    /// Active faces waiting for a cell to be finalized, indexed by the cell.
+    ///
+    /// Each active face waits for one cell that overlaps its circumcircle and hasn't been
+    /// finalized yet. Only those faces need to be checked again once that cell gets finalized.
+    /// Entries may be outdated - they are validated when the cell is finalized.
+    waiting_faces: Vec<Vec<CheckedFaceHandle<InnerTag>>>,
+}
+
+impl<V> StreamingTriangulation<V>
+where
+    V: HasPosition,
+    V::Scalar: Float,
+{
+    /// Creates a new streaming triangulation covering the rectangle between `lower` and `upper`.
+    ///
+    /// The rectangle is subdivided into `columns` x `rows` equally sized cells.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rectangle is empty or if `columns` or `rows` is zero.
+    pub fn new(
+        lower: Point2<V::Scalar>,
+        upper: Point2<V::Scalar>,
+        columns: usize,
+        rows: usize,
+    ) -> Self {
+        assert!(
+            lower.x < upper.x && lower.y < upper.y,
+            "The streaming bounds must not be empty"
+        );
+        assert!(columns > 0 && rows > 0, "The cell grid must not be empty");
+
+        let mut triangulation = ConstrainedDelaunayTriangulation::new();
+        triangulation.set_stable_handles(true);
+
+        Self {
+            triangulation,
+            lower,
+            upper,
+            num_cells: [columns, rows],
+            finalized: alloc::vec![false; columns * rows],
+            num_finalized: 0,
+            num_inserted: 0,
+            waiting_faces: alloc::vec![Vec::new(); columns * rows],
+        }
+    }
+
+    /// Returns the number of vertices that are currently kept in memory.
+    pub fn num_vertices(&self) -> usize {
+        self.triangulation.num_vertices()
+    }
+
+    /// Returns the total number of vertices that have been inserted so far.
+    pub fn num_inserted(&self) -> usize {
+        self.num_inserted
+    }
+
+    /// Returns the number of columns and rows of the cell grid.
+    pub fn num_cells(&self) -> [usize; 2] {
+        self.num_cells
+    }
+
+    /// Returns the cell (column and row) that contains a given position.
+    ///
+    /// Returns `None` if the position lies outside of the streaming bounds. Positions on the
+    /// border between two cells belong to the cell with the larger index.
+    pub fn cell_of(&self, position: Point2<V::Scalar>) -> Option<[usize; 2]> {
+        let contained = position.x >= self.lower.x
+            && position.x <= self.upper.x
+            && position.y >= self.lower.y
+            && position.y <= self.upper.y;
+
+        contained.then(|| {
+            [
+                self.cell_index(position.x, 0),
+                self.cell_index(position.y, 1),
+            ]
+        })
+    }
+
+    /// Returns `true` if a cell has already been finalized.
+    pub fn is_finalized(&self, cell: [usize; 2]) -> bool {
+        self.finalized[self.flat_index(cell)]
+    }
+
+    /// Inserts a chunk of vertices.
+    ///
+    /// The first chunk is bulk loaded. Vertices of subsequent chunks are sorted along a Hilbert
+    /// curve and inserted with the previous vertex as hint.
+    ///
+    /// Returns an error if any vertex has an invalid position. No vertex will be inserted in
+    /// this case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any vertex lies outside of the streaming bounds or within a finalized cell.
+    pub fn insert_chunk(&mut self, chunk: Vec<V>) -> Result<(), InsertionError> {
+        for vertex in &chunk {
+            validate_vertex(vertex)?;
+            let cell = self
+                .cell_of(vertex.position())
+                .expect("Vertex lies outside of the streaming bounds");
+            assert!(
+                !self.is_finalized(cell),
+                "Cannot insert a vertex into a finalized cell"
+            );
+        }
+
+        let first_index = self.num_inserted;
+        self.num_inserted += chunk.len();
+        let mut chunk = chunk
+            .into_iter()
+            .enumerate()
+            .map(|(offset, data)| StreamingVertex {
+                data,
+                index: first_index + offset,
+            })
+            .collect::<Vec<_>>();
+
+        if self.triangulation.num_vertices() == 0 {
+            self.triangulation = ConstrainedDelaunayTriangulation::bulk_load(chunk)?;
+            self.triangulation.set_stable_handles(true);
+            for face in self.triangulation.fixed_inner_faces() {
+                *self.triangulation.face_data_mut(face) = FaceState::Active;
+                self.wait_for_cell(face);
+            }
+            return Ok(());
+        }
+
+        hilbert_sort(&mut chunk);
+        let mut hint = None;
+        for vertex in chunk {
+            let handle = match hint {
+                Some(hint) => self.triangulation.insert_with_hint(vertex, hint)?,
+                None => self.triangulation.insert(vertex)?,
+            };
+            self.activate_new_faces(handle);
+            hint = Some(handle);
+        }
+        Ok(())
+    }
+
+    /// Finalizes a cell.
+    ///
+    /// All triangles that became final are passed to `on_triangle`. Vertices whose adjacent
+    /// triangles are all final are removed afterwards.
+    ///
+    /// Finalizing a cell twice has no effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the cell lies outside of the cell grid.
+    pub fn finalize_cell(
+        &mut self,
+        cell: [usize; 2],
+        on_triangle: impl FnMut(FinalizedTriangle<V>),
+    ) {
+        let flat_index = self.flat_index(cell);
+        if self.finalized[flat_index] {
+            return;
+        }
+        self.finalized[flat_index] = true;
+        self.num_finalized += 1;
+
+        let candidates = core::mem::take(&mut self.waiting_faces[flat_index]);
+        self.emit_final_triangles(candidates, on_triangle);
+    }
+
+    /// Finalizes all remaining cells.
+    ///
+    /// All triangles that have not been reported yet are passed to `on_triangle`. The
+    /// triangulation is empty afterwards.
+    pub fn finish(&mut self, on_triangle: impl FnMut(FinalizedTriangle<V>)) {
+        self.finalized.fill(true);
+        self.num_finalized = self.finalized.len();
+        self.waiting_faces.iter_mut().for_each(Vec::clear);
+        let candidates = self
+            .triangulation
+            .inner_faces()
+            .map(|face| face.checked())
+            .collect();
+        self.emit_final_triangles(candidates, on_triangle);
+        self.triangulation = ConstrainedDelaunayTriangulation::new();
+        self.triangulation.set_stable_handles(true);
+    }
+
+    fn flat_index(&self, cell: [usize; 2]) -> usize {
+        let [column, row] = cell;
+        assert!(
+            column < self.num_cells[0] && row < self.num_cells[1],
+            "Cell lies outside of the cell grid"
+        );
+        row * self.num_cells[0] + column
+    }
+
+    /// Returns the cell index of a coordinate along the x (`axis == 0`) or y (`axis == 1`) axis.
+    ///
+    /// Coordinates outside of the bounds are clamped to the first or last cell.
+    fn cell_index(&self, coordinate: V::Scalar, axis: usize) -> usize {
+        let (lower, upper) = if axis == 0 {
+            (self.lower.x, self.upper.x)
+        } else {
+            (self.lower.y, self.upper.y)
+        };
+        let count = self.num_cells[axis];
+        let relative = (coordinate - lower) / (upper - lower);
+        let index: V::Scalar = (relative * <V::Scalar as NumCast>::from(count).unwrap()).floor();
+        if index <= V::Scalar::zero() {
+            0
+        } else {
+            index.to_usize().unwrap_or(usize::MAX).min(count - 1)
+        }
+    }
+
+    /// Returns a cell that hasn't been finalized yet and that overlaps a given circle.
+    ///
+    /// Returns `None` if no vertex can be inserted into the circle anymore. The area outside of
+    /// the streaming bounds counts as finalized.
+    ///
+    /// The check is conservative: It tests all cells overlapping the circle's bounding box.
+    /// Additionally, the circle is enlarged by a small margin that covers the rounding errors of
+    /// its center, its radius and of the cell index calculation. Reporting a triangle too late is
+    /// harmless while reporting it too early could miss a vertex that is inserted later.
+    fn blocking_cell(&self, center: Point2<V::Scalar>, radius_2: V::Scalar) -> Option<usize> {
+        if self.num_finalized == self.finalized.len() {
+            return None;
+        }
+        let radius = radius_2.sqrt();
+        if !radius.is_finite() || !center.x.is_finite() || !center.y.is_finite() {
+            return self.finalized.iter().position(|is_finalized| !is_finalized);
+        }
+
+        let extent = (self.upper.x - self.lower.x).max(self.upper.y - self.lower.y);
+        let scale = radius + center.x.abs().max(center.y.abs()) + extent;
+        let radius = radius + scale * V::Scalar::epsilon().sqrt();
+        let min = Point2::new(center.x - radius, center.y - radius);
+        let max = Point2::new(center.x + radius, center.y + radius);
+
+        if max.x < self.lower.x
+            || max.y < self.lower.y
+            || min.x > self.upper.x
+            || min.y > self.upper.y
+        {
+            return None;
+        }
+
+        let columns = self.cell_index(min.x, 0)..=self.cell_index(max.x, 0);
+        let rows = self.cell_index(min.y, 1)..=self.cell_index(max.y, 1);
+        rows.into_iter().find_map(|row| {
+            columns
+                .clone()
+                .map(|column| row * self.num_cells[0] + column)
+                .find(|index| !self.finalized[*index])
+        })
+    }
+
+    /// Returns `true` if no vertex will ever be inserted beyond a convex hull edge.
+    fn is_hull_edge_final(&self, from: Point2<V::Scalar>, to: Point2<V::Scalar>) -> bool {
+        let [columns, rows] = self.num_cells;
+        let cell_size = Point2::new(
+            (self.upper.x - self.lower.x) / NumCast::from(columns).unwrap(),
+            (self.upper.y - self.lower.y) / NumCast::from(rows).unwrap(),
+        );
+        let corner = |column: usize, row: usize| {
+            Point2::new(
+                self.lower.x + cell_size.x * NumCast::from(column).unwrap(),
+                self.lower.y + cell_size.y * NumCast::from(row).unwrap(),
+            )
+        };
+
+        (0..rows).all(|row| {
+            (0..columns).all(|column| {
+                self.finalized[row * columns + column]
+                    || [(0, 0), (1, 0), (0, 1), (1, 1)]
+                        .into_iter()
+                        .all(|(dx, dy)| {
+                            // The outer face lies to the left of the edge
+                            !math::side_query(from, to, corner(column + dx, row + dy))
+                                .is_on_left_side()
+                        })
+            })
+        })
+    }
+
+    /// Marks the faces created by inserting a vertex as part of the streamed triangulation.
+    ///
+    /// Insertion only creates faces adjacent to the inserted vertex. Faces that were flipped
+    /// keep their `Active` state but need to wait for the cells overlapping their new
+    /// circumcircle.
+    fn activate_new_faces(&mut self, vertex: FixedVertexHandle) {
+        let new_faces = self
+            .triangulation
+            .vertex(vertex)
+            .out_edges()
+            .filter_map(|edge| edge.face().as_inner())
+            .filter(|face| *face.data() != FaceState::Emitted)
+            .map(|face| face.fix())
+            .collect::<Vec<_>>();
+
+        for face in new_faces {
+            *self.triangulation.face_data_mut(face) = FaceState::Active;
+            self.wait_for_cell(face);
+        }
+    }
+
+    /// Registers an active face at a cell that needs to be finalized before the face can become
+    /// final.
+    ///
+    /// Returns `false` if the face is final already.
+    fn wait_for_cell(&mut self, face: FixedFaceHandle<InnerTag>) -> bool {
+        let face = self.triangulation.face(face);
+        let (center, radius_2) = face.circumcircle();
+        match self.blocking_cell(center, radius_2) {
+            Some(cell) => {
+                let face = face.checked();
+                self.waiting_faces[cell].push(face);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reports all candidate faces that became final and removes vertices that aren't needed
+    /// anymore.
+    ///
+    /// Candidates that are still active but not final yet wait for another cell.
+    fn emit_final_triangles(
+        &mut self,
+        mut candidates: Vec<CheckedFaceHandle<InnerTag>>,
+        mut on_triangle: impl FnMut(FinalizedTriangle<V>),
+    ) {
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        let mut final_faces = Vec::new();
+        for face in candidates {
+            let is_active = self
+                .triangulation
+                .try_face(face)
+                .is_some_and(|face| *face.data() == FaceState::Active);
+            if is_active && !self.wait_for_cell(face.fix()) {
+                final_faces.push(face.fix());
+            }
+        }
+
+        let mut candidates = Vec::with_capacity(final_faces.len() * 3);
+        for face in &final_faces {
+            let face = self.triangulation.face(*face);
+            let [v0, v1, v2] = face.vertices();
+            on_triangle(FinalizedTriangle {
+                vertices: [&v0.data().data, &v1.data().data, &v2.data().data],
+                indices: [v0.data().index, v1.data().index, v2.data().index],
+            });
+            candidates.extend([v0.fix(), v1.fix(), v2.fix()]);
+        }
+
+        for face in final_faces {
+            *self.triangulation.face_data_mut(face) = FaceState::Emitted;
+        }
+
+        // Convex hull vertices may also become removable by finalizing a cell beyond the hull.
+        candidates.extend(
+            self.triangulation
+                .convex_hull()
+                .map(|edge| edge.from().fix()),
+        );
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        for vertex in candidates {
+            if self.is_vertex_final(vertex) {
+                self.remove_final_vertex(vertex);
+            }
+        }
+    }
+
+    fn is_vertex_final(&self, vertex: FixedVertexHandle) -> bool {
+        let vertex = self.triangulation.vertex(vertex);
+        let mut is_on_hull = false;
+        for edge in vertex.out_edges() {
+            match edge.face().as_inner() {
+                Some(face) => {
+                    if *face.data() == FaceState::Active {
+                        return false;
+                    }
+                }
+                None => is_on_hull = true,
+            }
+        }
+
+        if !is_on_hull {
+            // Isolated vertices (e.g. of an all collinear triangulation) are never final.
+            return vertex.out_edges().next().is_some();
+        }
+
+        vertex.out_edges().all(|edge| {
+            if edge.is_outer_edge() {
+                self.is_hull_edge_final(edge.from().position(), edge.to().position())
+            } else if edge.rev().is_outer_edge() {
+                self.is_hull_edge_final(edge.to().position(), edge.from().position())
+            } else {
+                true
+            }
+        })
+    }
+
+    fn remove_final_vertex(&mut self, vertex: FixedVertexHandle) {
+        // The link edges border the area that will be re-triangulated with filler faces.
+        let link = self
+            .triangulation
+            .vertex(vertex)
+            .out_edges()
+            .filter(|edge| !edge.is_outer_edge())
+            .map(|edge| {
+                let link_edge = edge.next();
+                (link_edge.from().fix(), link_edge.to().fix())
+            })
+            .collect::<Vec<_>>();
+
+        self.triangulation.remove(vertex);
+
+        for (from, to) in link {
+            if self
+                .triangulation
+                .get_edge_from_neighbors(from, to)
+                .is_some()
+            {
+                self.triangulation.add_constraint(from, to);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::vec::Vec;
+
+    use crate::test_utilities::{random_points_in_range, SEED, SEED2};
+    use crate::{DelaunayTriangulation, InsertionError, Point2, Triangulation};
+
+    use super::StreamingTriangulation;
+
+    fn sorted_triangle(mut indices: [usize; 3]) -> [usize; 3] {
+        indices.sort_unstable();
+        indices
+    }
+
+    fn reference_triangles(vertices: &[Point2<f64>]) -> Result<Vec<[usize; 3]>, InsertionError> {
+        let mut reference = DelaunayTriangulation::<Point2<f64>>::new();
+        for vertex in vertices {
+            reference.insert(*vertex)?;
+        }
+        let mut result = reference
+            .inner_faces()
+            .map(|face| sorted_triangle(face.vertices().map(|v| v.fix().index())))
+            .collect::<Vec<_>>();
+        result.sort_unstable();
+        Ok(result)
+    }
+
+    #[test]
+    fn test_streaming_matches_delaunay_triangulation() -> Result<(), InsertionError> {
+        const COLUMNS: usize = 6;
+        const ROWS: usize = 5;
+        let mut streaming = StreamingTriangulation::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(6.0, 5.0),
+            COLUMNS,
+            ROWS,
+        );
+
+        let mut vertices = Vec::new();
+        let mut triangles = Vec::new();
+        let mut max_vertices = 0;
+        for row in 0..ROWS {
+            for column in 0..COLUMNS {
+                let mut seed = *SEED;
+                seed[0] = row as u8;
+                seed[1] = column as u8;
+                let chunk = random_points_in_range(0.5, 60, &seed)
+                    .into_iter()
+                    .map(|p| Point2::new(p.x + 0.5 + column as f64, p.y + 0.5 + row as f64))
+                    .collect::<Vec<_>>();
+                vertices.extend(chunk.iter().copied());
+                streaming.insert_chunk(chunk)?;
+                max_vertices = max_vertices.max(streaming.num_vertices());
+
+                streaming.finalize_cell([column, row], |triangle| {
+                    for (vertex, index) in triangle.vertices.iter().zip(triangle.indices) {
+                        assert_eq!(**vertex, vertices[index]);
+                    }
+                    triangles.push(sorted_triangle(triangle.indices));
+                });
+            }
+        }
+        streaming.finish(|triangle| triangles.push(sorted_triangle(triangle.indices)));
+
+        assert_eq!(streaming.num_inserted(), vertices.len());
+        assert_eq!(streaming.num_vertices(), 0);
+        // Finished rows must have been freed
+        assert!(max_vertices < vertices.len() / 2);
+
+        triangles.sort_unstable();
+        assert_eq!(triangles, reference_triangles(&vertices)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_streaming_single_chunk() -> Result<(), InsertionError> {
+        let vertices = random_points_in_range(1.0, 200, SEED2);
+        let mut streaming =
+            StreamingTriangulation::new(Point2::new(-1.0, -1.0), Point2::new(1.0, 1.0), 3, 3);
+        streaming.insert_chunk(vertices.clone())?;
+
+        let mut triangles = Vec::new();
+        streaming.finish(|triangle| triangles.push(sorted_triangle(triangle.indices)));
+        triangles.sort_unstable();
+        assert_eq!(triangles, reference_triangles(&vertices)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cell_of() {
+        let streaming = StreamingTriangulation::<Point2<f64>>::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 2.0),
+            4,
+            2,
+        );
+        assert_eq!(streaming.cell_of(Point2::new(0.0, 0.0)), Some([0, 0]));
+        assert_eq!(streaming.cell_of(Point2::new(1.0, 0.5)), Some([1, 0]));
+        assert_eq!(streaming.cell_of(Point2::new(3.5, 1.5)), Some([3, 1]));
+        assert_eq!(streaming.cell_of(Point2::new(4.0, 2.0)), Some([3, 1]));
+        assert_eq!(streaming.cell_of(Point2::new(4.1, 2.0)), None);
+        assert_eq!(streaming.cell_of(Point2::new(-0.1, 1.0)), None);
+    }
+
+    #[test]
+    fn test_blocking_cell_with_rounding_errors() {
+        let mut streaming = StreamingTriangulation::<Point2<f64>>::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 1.0),
+            2,
+            1,
+        );
+        streaming.finalize_cell([0, 0], |_| {});
+
+        let center = Point2::new(0.5, 0.5);
+        assert_eq!(streaming.blocking_cell(center, 0.4 * 0.4), None);
+        // Touches the right cell up to a rounding error
+        let radius = 0.5 - f64::EPSILON;
+        assert_eq!(streaming.blocking_cell(center, radius * radius), Some(1));
+        assert_eq!(streaming.blocking_cell(center, f64::INFINITY), Some(1));
+        assert_eq!(
+            streaming.blocking_cell(Point2::new(f64::NAN, 0.5), 0.01),
+            Some(1)
+        );
+        // Lies outside of the streaming bounds
+        assert_eq!(streaming.blocking_cell(Point2::new(3.0, 0.5), 0.25), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_insert_into_finalized_cell() {
+        let mut streaming = StreamingTriangulation::<Point2<f64>>::new(
+            Point2::new(0.0, 0.0),
+            Point2::new(2.0, 2.0),
+            2,
+            2,
+        );
+        streaming.finalize_cell([0, 0], |_| {});
+        let _ = streaming.insert_chunk(alloc::vec![Point2::new(0.5, 0.5)]);
+    }
+}
@@ -25,3 +25,13 @@ pub fn random_points_in_range(range: f64, size: usize, seed: &[u8; 32]) -> Vec<P
 pub fn random_points_with_seed(size: usize, seed: &[u8; 32]) -> Vec<Point2<f64>> {
     random_points_in_range(1.0, size, seed)
 }
+
+/// Returns a counterclockwise square between `(min, min)` and `(max, max)`.
+pub fn square(min: f64, max: f64) -> Vec<Point2<f64>> {
+    alloc::vec![
+        Point2::new(min, min),
+        Point2::new(max, min),
+        Point2::new(max, max),
+        Point2::new(min, max),
+    ]
+}
@@ -1,5 +1,8 @@
-use num_traits::Float;
+use num_traits::{Float, Zero};
 
+use crate::delaunay_core::clip_power_cell;
+use crate::delaunay_core::dcel_operations;
+use crate::delaunay_core::hilbert_sort_by_key;
 use crate::delaunay_core::iterators::HullIterator;
 use crate::delaunay_core::InnerOuterMarker;
 use crate::flood_fill_iterator::CircleMetric;
@@ -9,9 +12,13 @@ use crate::flood_fill_iterator::RectangleMetric;
 use crate::flood_fill_iterator::VerticesInShapeIterator;
 use crate::iterators::*;
 use crate::Barycentric;
-use crate::HintGenerator;
 use crate::{delaunay_core::Dcel, handles::*};
-use crate::{HasPosition, InsertionError, Point2, TriangulationExt};
+use crate::{
+    validate_vertex, HasPosition, HasPositionMut, InsertionError, MoveVertexError, Point2,
+    TriangulationExt,
+};
+use crate::{CellBounds, ClippedCell};
+use crate::{HandleRemapping, HintGenerator};
 
 use alloc::vec::Vec;
 
@@ -75,6 +82,40 @@ pub trait Triangulation: Default {
     #[doc(hidden)]
     fn handle_legal_edge_split(&mut self, _: [FixedDirectedEdgeHandle; 2]) {}
 
+    /// Called after a vertex has been removed. `swapped_in` contains the previous handle of the
+    /// vertex that has been moved into the removed vertex' slot, if any.
+    #[doc(hidden)]
+    fn handle_vertex_removal(
+        &mut self,
+        _: FixedVertexHandle,
+        _swapped_in: Option<FixedVertexHandle>,
+    ) {
+    }
+
+    /// Called after all vertices have been reordered.
+    #[doc(hidden)]
+    fn handle_vertex_remapping(&mut self, _: &HandleRemapping) {}
+
+    /// Returns `true` if `p` violates the empty circle property of the triangle `(v1, v2, v3)`.
+    ///
+    /// The triangle's vertices must be ordered counterclockwise. This is the predicate used to
+    /// decide whether an edge must be flipped.
+    #[doc(hidden)]
+    fn contained_in_circumference(
+        &self,
+        v1: FixedVertexHandle,
+        v2: FixedVertexHandle,
+        v3: FixedVertexHandle,
+        p: FixedVertexHandle,
+    ) -> bool {
+        crate::delaunay_core::math::contained_in_circumference(
+            self.vertex(v1).position(),
+            self.vertex(v2).position(),
+            self.vertex(v3).position(),
+            self.vertex(p).position(),
+        )
+    }
+
     #[doc(hidden)]
     fn hint_generator(&self) -> &Self::HintGenerator;
 
@@ -149,6 +190,121 @@ pub trait Triangulation: Default {
         *self.hint_generator_mut() = new_hint_generator;
     }
 
+    /// Enables or disables stable handles.
+    ///
+    /// By default, removing a vertex moves the last vertex, edge or face into the slot of the
+    /// removed element to keep the internal storage dense. This invalidates fixed handles of
+    /// elements that have not been removed.
+    ///
+    /// With stable handles enabled, removed elements leave a gap instead. Fixed handles of all
+    /// remaining elements stay valid and keep referring to the same element - even across
+    /// removals. This is useful if fixed handles are stored in external data structures for a
+    /// longer time. Gaps are reused by subsequent insertions. Fixed handles of removed elements
+    /// must not be used anymore as they may refer to a newly inserted element.
+    ///
+    /// Stable handles are disabled by default. Enabling them is cheap. Leaving gaps may slightly
+    /// degrade the performance of iteration and of the
+    /// [HierarchyHintGenerator](crate::HierarchyHintGenerator) until the gaps are refilled.
+    ///
+    /// Stable handles are *not* preserved by operations that rebuild the triangulation, e.g.
+    /// [bulk_load](Triangulation::bulk_load).
+    ///
+    /// # Handle invalidation
+    /// Disabling stable handles closes all gaps and will invalidate all vertex, edge and face
+    /// handles. Enabling stable handles does not invalidate any handle.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{DelaunayTriangulation, Point2, Triangulation};
+    ///
+    /// let mut triangulation = DelaunayTriangulation::<Point2<f64>>::new();
+    /// triangulation.set_stable_handles(true);
+    ///
+    /// let v0 = triangulation.insert(Point2::new(0.0, 0.0))?;
+    /// let v1 = triangulation.insert(Point2::new(1.0, 0.0))?;
+    /// let v2 = triangulation.insert(Point2::new(0.0, 1.0))?;
+    ///
+    /// triangulation.remove(v0);
+    /// // v1 and v2 still refer to the same vertices
+    /// assert_eq!(triangulation.vertex(v1).position(), Point2::new(1.0, 0.0));
+    /// assert_eq!(triangulation.vertex(v2).position(), Point2::new(0.0, 1.0));
+    /// # Ok(()) }
+    /// ```
+    fn set_stable_handles(&mut self, stable_handles: bool) {
+        if self.has_stable_handles() == stable_handles {
+            return;
+        }
+        let swapped_vertices = dcel_operations::set_stable_handles(self.s_mut(), stable_handles);
+        for (gap, swapped_in) in swapped_vertices {
+            self.handle_vertex_removal(gap, Some(swapped_in));
+        }
+        if !stable_handles {
+            let new_hint_generator = HintGenerator::initialize_from_triangulation(self);
+            *self.hint_generator_mut() = new_hint_generator;
+        }
+    }
+
+    /// Returns `true` if stable handles are enabled.
+    ///
+    /// *See [set_stable_handles](Triangulation::set_stable_handles)*
+    fn has_stable_handles(&self) -> bool {
+        self.s().has_stable_handles()
+    }
+
+    /// Reorders all vertices, edges and faces for better memory locality.
+    ///
+    /// Vertices are sorted along a [Hilbert curve](https://en.wikipedia.org/wiki/Hilbert_curve).
+    /// Edges and faces are placed close to their adjacent vertices. Gaps left by removed elements
+    /// (see [set_stable_handles](Triangulation::set_stable_handles)) are closed. This can speed up
+    /// traversals and lookups after many insertions and removals have degraded the
+    /// triangulation's memory layout.
+    ///
+    /// Runs in `O(n log n)` time.
+    ///
+    /// # Handle invalidation
+    /// This method will invalidate all vertex, edge and face handles. The returned
+    /// [HandleRemapping] maps old fixed handles to their new values.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{DelaunayTriangulation, Point2, Triangulation};
+    ///
+    /// let mut triangulation = DelaunayTriangulation::<Point2<f64>>::new();
+    /// let v0 = triangulation.insert(Point2::new(0.0, 0.0))?;
+    /// let v1 = triangulation.insert(Point2::new(1.0, 0.0))?;
+    /// let v2 = triangulation.insert(Point2::new(0.0, 1.0))?;
+    ///
+    /// let remapping = triangulation.compact();
+    /// let new_v1 = remapping.vertex(v1).unwrap();
+    /// assert_eq!(triangulation.vertex(new_v1).position(), Point2::new(1.0, 0.0));
+    /// # Ok(()) }
+    /// ```
+    fn compact(&mut self) -> HandleRemapping {
+        let mut vertex_order: Vec<_> = self.fixed_vertices().collect();
+        hilbert_sort_by_key(&mut vertex_order, |vertex| {
+            self.vertex(*vertex).position().to_f64()
+        });
+
+        let remapping = dcel_operations::reorder(self.s_mut(), &vertex_order);
+        self.handle_vertex_remapping(&remapping);
+        let new_hint_generator = HintGenerator::initialize_from_triangulation(self);
+        *self.hint_generator_mut() = new_hint_generator;
+        remapping
+    }
+
+    /// Shrinks the capacity of the triangulation's internal storage as much as possible.
+    ///
+    /// This does not close any gaps left by removed elements. Call
+    /// [compact](Triangulation::compact) first to release their memory as well.
+    ///
+    /// # Handle invalidation
+    /// This method does not invalidate any handle.
+    fn shrink_to_fit(&mut self) {
+        self.s_mut().shrink_to_fit();
+    }
+
     /// Creates a new triangulation populated with some vertices.
     ///
     /// This will usually be more efficient than inserting the elements sequentially by calling
@@ -235,6 +391,116 @@ pub trait Triangulation: Default {
         UndirectedEdgeHandle::new(self.s(), handle)
     }
 
+    /// Converts a checked vertex handle to a reference vertex handle.
+    ///
+    /// Returns `None` if the vertex has been removed or if the handle has been invalidated
+    /// otherwise, e.g. because another vertex was moved to its index. In contrast,
+    /// [vertex](Triangulation::vertex) might silently return a different vertex or panic.
+    ///
+    /// Checked handles are created by calling `checked()` on a reference handle.
+    ///
+    /// *See also the [handles](crate::handles) module for more information.*
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// use spade::{DelaunayTriangulation, Point2, Triangulation};
+    ///
+    /// let mut triangulation = DelaunayTriangulation::<Point2<f64>>::new();
+    /// let v0 = triangulation.insert(Point2::new(0.0, 0.0))?;
+    /// let v1 = triangulation.insert(Point2::new(1.0, 0.0))?;
+    ///
+    /// let checked_v0 = triangulation.vertex(v0).checked();
+    /// let checked_v1 = triangulation.vertex(v1).checked();
+    ///
+    /// triangulation.remove(v0);
+    /// assert!(triangulation.try_vertex(checked_v0).is_none());
+    /// // v1 has been moved to index 0. Its checked handle has been invalidated as well.
+    /// assert!(triangulation.try_vertex(checked_v1).is_none());
+    ///
+    /// // Checked handles of the remaining vertex can be recreated
+    /// let checked_v1 = triangulation.vertices().next().unwrap().checked();
+    /// assert!(triangulation.try_vertex(checked_v1).is_some());
+    /// # Ok(()) }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn try_vertex(
+        &self,
+        handle: CheckedVertexHandle,
+    ) -> Option<VertexHandle<'_, Self::Vertex, Self::DirectedEdge, Self::UndirectedEdge, Self::Face>>
+    {
+        self.s()
+            .is_checked_handle_valid(handle)
+            .then(|| self.vertex(handle.fix()))
+    }
+
+    /// Converts a checked face handle to a reference face handle.
+    ///
+    /// Returns `None` if the face has been removed or if the handle has been invalidated
+    /// otherwise.
+    ///
+    /// *See [try_vertex](Triangulation::try_vertex) for more information.*
+    #[allow(clippy::type_complexity)]
+    fn try_face<InnerOuter: InnerOuterMarker>(
+        &self,
+        handle: CheckedFaceHandle<InnerOuter>,
+    ) -> Option<
+        FaceHandle<
+            '_,
+            InnerOuter,
+            Self::Vertex,
+            Self::DirectedEdge,
+            Self::UndirectedEdge,
+            Self::Face,
+        >,
+    > {
+        self.s()
+            .is_checked_handle_valid(handle)
+            .then(|| self.face(handle.fix()))
+    }
+
+    /// Converts a checked directed edge handle to a reference directed edge handle.
+    ///
+    /// Returns `None` if the edge has been removed or if the handle has been invalidated
+    /// otherwise.
+    ///
+    /// *See [try_vertex](Triangulation::try_vertex) for more information.*
+    #[allow(clippy::type_complexity)]
+    fn try_directed_edge(
+        &self,
+        handle: CheckedDirectedEdgeHandle,
+    ) -> Option<
+        DirectedEdgeHandle<'_, Self::Vertex, Self::DirectedEdge, Self::UndirectedEdge, Self::Face>,
+    > {
+        self.s()
+            .is_checked_handle_valid(handle)
+            .then(|| self.directed_edge(handle.fix()))
+    }
+
+    /// Converts a checked undirected edge handle to a reference undirected edge handle.
+    ///
+    /// Returns `None` if the edge has been removed or if the handle has been invalidated
+    /// otherwise.
+    ///
+    /// *See [try_vertex](Triangulation::try_vertex) for more information.*
+    #[allow(clippy::type_complexity)]
+    fn try_undirected_edge(
+        &self,
+        handle: CheckedUndirectedEdgeHandle,
+    ) -> Option<
+        UndirectedEdgeHandle<
+            '_,
+            Self::Vertex,
+            Self::DirectedEdge,
+            Self::UndirectedEdge,
+            Self::Face,
+        >,
+    > {
+        self.s()
+            .is_checked_handle_valid(handle)
+            .then(|| self.undirected_edge(handle.fix()))
+    }
+
     /// Returns a mutable reference ot the associated data of an undirected edge.
     fn undirected_edge_data_mut(
         &mut self,
@@ -484,7 +750,8 @@ pub trait Triangulation: Default {
     ///
     /// # Handle invalidation
     /// This method will invalidate all vertex, edge and face handles
-    /// upon successful removal.
+    /// upon successful removal. *See [remove](Triangulation::remove) for how this is affected by
+    /// stable handles.*
     ///
     /// # Panics
     ///
@@ -507,11 +774,75 @@ pub trait Triangulation: Default {
     /// removed vertex (the number of its outgoing edges).
     ///
     /// # Handle invalidation
-    /// This method will invalidate all vertex, edge and face handles.
+    /// This method will invalidate all vertex, edge and face handles. If
+    /// [stable handles](Triangulation::set_stable_handles) are enabled, only the handles of the
+    /// removed vertex and its adjacent edges and faces are invalidated.
     fn remove(&mut self, vertex: FixedVertexHandle) -> Self::Vertex {
         self.remove_and_notify(vertex)
     }
 
+    /// Moves a vertex to a new position.
+    ///
+    /// In contrast to removing and re-inserting the vertex, this keeps all vertex handles valid
+    /// and retains the vertex' data - only its position is updated with
+    /// [HasPositionMut::set_position].
+    ///
+    /// If the new position lies within the polygon formed by the vertex' neighbors, the vertex is
+    /// moved in place and the Delaunay property is restored locally by flipping edges. This
+    /// operation's run time is then proportional to the number of flipped edges. Otherwise (or if
+    /// the vertex is part of the convex hull), the vertex is removed and re-inserted internally.
+    ///
+    /// Returns the vertex handle of the moved vertex. This is always equal to `vertex` unless the
+    /// triangulation is a [RegularTriangulation](crate::RegularTriangulation).
+    ///
+    /// Returns an error if the new position is not valid (see [crate::validate_coordinate]) or if
+    /// another vertex is already located at `new_position`
+    /// ([MoveVertexError::OccupiedPosition]). The triangulation remains unchanged in this case.
+    ///
+    /// # Handle invalidation
+    /// Vertex handles remain valid. Edge and face handles may be invalidated.
+    ///
+    /// # Example
+    /// ```
+    /// # fn main() -> Result<(), spade::MoveVertexError> {
+    /// use spade::{DelaunayTriangulation, Point2, Triangulation};
+    ///
+    /// let mut triangulation = DelaunayTriangulation::<Point2<f64>>::new();
+    /// let v0 = triangulation.insert(Point2::new(0.0, 0.0))?;
+    /// let v1 = triangulation.insert(Point2::new(2.0, 0.0))?;
+    /// let v2 = triangulation.insert(Point2::new(1.0, 2.0))?;
+    /// let v3 = triangulation.insert(Point2::new(1.0, 0.5))?;
+    ///
+    /// triangulation.move_vertex(v3, Point2::new(1.2, 0.8))?;
+    /// assert_eq!(triangulation.vertex(v3).position(), Point2::new(1.2, 0.8));
+    ///
+    /// // Moving a vertex out of the convex hull works as well
+    /// triangulation.move_vertex(v0, Point2::new(-5.0, 0.0))?;
+    /// assert_eq!(triangulation.vertex(v0).position(), Point2::new(-5.0, 0.0));
+    /// assert_eq!(triangulation.vertex(v1).position(), Point2::new(2.0, 0.0));
+    /// assert_eq!(triangulation.vertex(v2).position(), Point2::new(1.0, 2.0));
+    /// # Ok(()) }
+    /// ```
+    fn move_vertex(
+        &mut self,
+        vertex: FixedVertexHandle,
+        new_position: Point2<<Self::Vertex as HasPosition>::Scalar>,
+    ) -> Result<FixedVertexHandle, MoveVertexError>
+    where
+        Self::Vertex: HasPositionMut,
+    {
+        validate_vertex(&new_position)?;
+        if self.vertex(vertex).position() == new_position
+            || self.try_move_vertex_locally(vertex, new_position)
+        {
+            return Ok(vertex);
+        }
+
+        self.check_position_is_free(vertex, new_position)?;
+        self.move_vertex_by_reinsertion(vertex, new_position)?;
+        Ok(vertex)
+    }
+
     /// Inserts a new vertex into the triangulation.
     ///
     /// This operation runs in O(log(n)) on average when using a tree
@@ -556,14 +887,14 @@ pub trait Triangulation: Default {
     ///
     /// The iterator type is [FixedUndirectedEdgeHandle].
     fn fixed_undirected_edges(&self) -> FixedUndirectedEdgeIterator {
-        FixedUndirectedEdgeIterator::new(self.num_undirected_edges())
+        FixedUndirectedEdgeIterator::new(self.s())
     }
 
     /// An iterator visiting all directed edges.
     ///
     /// The iterator type is [FixedDirectedEdgeHandle].
     fn fixed_directed_edges(&self) -> FixedDirectedEdgeIterator {
-        FixedDirectedEdgeIterator::new(self.num_directed_edges())
+        FixedDirectedEdgeIterator::new(self.s())
     }
 
     /// An iterator visiting all faces.
@@ -573,14 +904,14 @@ pub trait Triangulation: Default {
     ///
     /// The iterator type is [FixedFaceHandle<PossiblyOuterTag, ...>](FixedFaceHandle).
     fn fixed_all_faces(&self) -> FixedFaceIterator {
-        FixedFaceIterator::new(self.num_all_faces())
+        FixedFaceIterator::new(self.s())
     }
 
     /// An iterator visiting all inner faces of the triangulation.
     ///
     /// The iterator type is [FixedFaceHandle<InnerTag, ...>](FixedFaceHandle).
     fn fixed_inner_faces(&self) -> FixedInnerFaceIterator {
-        let mut result = FixedInnerFaceIterator::new(self.num_all_faces());
+        let mut result = FixedInnerFaceIterator::new(self.s());
         result.next();
         result
     }
@@ -747,6 +1078,68 @@ where
         VerticesInShapeIterator::new(FloodFillIterator::new(self, distance_metric, center))
     }
 
+    /// Returns the Voronoi cell of every vertex, clipped to a convex area.
+    ///
+    /// In contrast to [voronoi_faces](Triangulation::voronoi_faces), all returned cells are closed
+    /// polygons - cells of vertices on the convex hull are cut off at the area's boundary.
+    /// Each cell also contains its area and centroid.
+    ///
+    /// The returned `Vec` contains one cell per vertex in the order of
+    /// [vertices](Triangulation::vertices). Use [ClippedCell::vertex] to look up a cell's vertex -
+    /// a cell's position in the `Vec` does not match its vertex index if
+    /// [stable handles](Triangulation::set_stable_handles) are enabled. The polygon of a cell is
+    /// empty if the cell doesn't overlap the clipping area.
+    ///
+    /// The cells are computed from the triangulation's adjacency information. They will only match
+    /// the true Voronoi diagram for a [DelaunayTriangulation](crate::DelaunayTriangulation). For
+    /// weighted cells, see [PowerCell](crate::handles::PowerCell).
+    ///
+    /// # Example
+    /// ```
+    /// use spade::{CellBounds, DelaunayTriangulation, FloatTriangulation, Point2, Triangulation};
+    ///
+    /// # fn main() -> Result<(), spade::InsertionError> {
+    /// let mut triangulation = DelaunayTriangulation::<Point2<f64>>::new();
+    /// triangulation.insert(Point2::new(0.0, 0.0))?;
+    /// triangulation.insert(Point2::new(2.0, 0.0))?;
+    /// triangulation.insert(Point2::new(1.0, 2.0))?;
+    ///
+    /// let bounds = CellBounds::Rectangle {
+    ///     lower: Point2::new(-1.0, -1.0),
+    ///     upper: Point2::new(3.0, 3.0),
+    /// };
+    ///
+    /// let cells = triangulation.voronoi_cells_clipped(&bounds);
+    /// assert_eq!(cells.len(), 3);
+    ///
+    /// let total_area: f64 = cells.iter().map(|cell| cell.area).sum();
+    /// assert!((total_area - 16.0).abs() < 1.0e-10);
+    ///
+    /// for cell in &cells {
+    ///     let centroid = cell.centroid.unwrap();
+    ///     println!("Cell of {:?}: centroid {:?}", cell.vertex, centroid);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    fn voronoi_cells_clipped(
+        &self,
+        bounds: &CellBounds<<Self::Vertex as HasPosition>::Scalar>,
+    ) -> Vec<ClippedCell<<Self::Vertex as HasPosition>::Scalar>> {
+        let bounds = bounds.to_ccw_polygon();
+        let zero = <Self::Vertex as HasPosition>::Scalar::zero();
+        self.vertices()
+            .map(|vertex| {
+                let polygon = clip_power_cell(
+                    &bounds,
+                    vertex.position(),
+                    zero,
+                    vertex.out_edges().map(|edge| (edge.to().position(), zero)),
+                );
+                ClippedCell::new(vertex.fix(), polygon)
+            })
+            .collect()
+    }
+
     /// Used for barycentric interpolation on this triangulation. Refer to the documentation of
     /// [Barycentric] and [crate::NaturalNeighbor] for more information.
     ///